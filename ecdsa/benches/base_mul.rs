@@ -0,0 +1,122 @@
+//! Benchmark for overriding `CurveArithmetic::mul_base` with a precomputed
+//! table.
+//!
+//! Signs with two otherwise identical curve types built on the `p256` crate's
+//! arithmetic (see the `common` module): one using the default `k×𝑮`
+//! multiplication, and one using a stub 4-bit fixed-window table of multiples
+//! of the generator.
+//!
+//! Run with `cargo bench --features hazmat`.
+
+#[macro_use]
+mod common;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ecdsa::hazmat::{sign_prehashed, CurveArithmetic};
+use elliptic_curve::{
+    consts::U32,
+    subtle::{ConditionallySelectable, ConstantTimeEq, CtOption},
+    ElementBytes, FromBytes,
+};
+use p256::ProjectivePoint;
+use sha2::{Digest, Sha256};
+
+/// Window width of the precomputed table in bits
+const WINDOW: usize = 4;
+
+/// Number of windows in a 256-bit scalar
+const WINDOWS: usize = 256 / WINDOW;
+
+thread_local! {
+    /// Table where entry `[i][j]` is `j × 2^(WINDOW × i) × 𝑮`
+    static TABLE: Vec<[ProjectivePoint; 1 << WINDOW]> = {
+        let mut base = ProjectivePoint::generator();
+
+        (0..WINDOWS)
+            .map(|_| {
+                let mut row = [ProjectivePoint::default(); 1 << WINDOW];
+
+                for j in 1..row.len() {
+                    row[j] = row[j - 1] + &base;
+                }
+
+                for _ in 0..WINDOW {
+                    base = base.double();
+                }
+
+                row
+            })
+            .collect()
+    };
+}
+
+impl_curve!(
+    precomputed,
+    p256::NistP256,
+    crate::common::P256_ORDER,
+    mul_base = crate::mul_base_precomputed
+);
+
+/// Compute `k×𝑮` using [`TABLE`]
+fn mul_base_precomputed(k: &precomputed::Scalar) -> CtOption<precomputed::Point> {
+    let bytes: ElementBytes<precomputed::Curve> = (*k).into();
+
+    TABLE.with(|table| {
+        let mut result = ProjectivePoint::default();
+
+        for (i, row) in table.iter().enumerate() {
+            // Windows are numbered from the least significant end
+            let byte = bytes[bytes.len() - 1 - i / 2];
+            let digit = (byte >> (WINDOW * (i % 2))) & 0xf;
+            let mut entry = ProjectivePoint::default();
+
+            for (j, point) in row.iter().enumerate() {
+                entry.conditional_assign(point, (j as u8).ct_eq(&digit));
+            }
+
+            result += entry;
+        }
+
+        precomputed::Point::from_projective(result)
+    })
+}
+
+/// Sign the given prehashed message with a fixed key and nonce
+fn sign<C>(prehash: &ElementBytes<C>) -> Vec<u8>
+where
+    C: CurveArithmetic<ElementSize = U32>,
+{
+    let secret_scalar = C::Scalar::from_bytes(&Sha256::digest(b"secret key")).unwrap();
+    let k = C::Scalar::from_bytes(&Sha256::digest(b"nonce")).unwrap();
+
+    sign_prehashed::<C>(&secret_scalar, &k, prehash)
+        .unwrap()
+        .as_ref()
+        .to_vec()
+}
+
+fn bench_base_mul(c: &mut Criterion) {
+    let prehash = Sha256::digest(b"sample");
+
+    // Check both curve types compute the same signature, which also builds
+    // the table so its construction isn't included in the timings
+    assert_eq!(
+        sign::<common::p256::Curve>(&prehash),
+        sign::<precomputed::Curve>(&prehash)
+    );
+
+    let mut group = c.benchmark_group("sign_prehashed");
+
+    group.bench_function("default", |b| {
+        b.iter(|| sign::<common::p256::Curve>(black_box(&prehash)))
+    });
+
+    group.bench_function("precomputed", |b| {
+        b.iter(|| sign::<precomputed::Curve>(black_box(&prehash)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_base_mul);
+criterion_main!(benches);
@@ -0,0 +1,152 @@
+//! Benchmark for batch verification of signatures.
+//!
+//! Compares verifying `BATCH_SIZE` recoverable signatures from distinct keys
+//! one at a time and as a batch using `BatchVerifier`, and `BATCH_SIZE`
+//! signatures from the same key one at a time and using the shared key fast
+//! path of `VerifyingKey::verify_batch`. Uses a curve type built on the
+//! `p256` crate's arithmetic (see the `common` module).
+//!
+//! Run with `cargo bench --features rand,recoverable,verifier`.
+
+mod common;
+
+use common::p256::Scalar;
+use criterion::{criterion_group, criterion_main, Criterion};
+use ecdsa::{
+    hazmat::{sign_prehashed, CurveArithmetic},
+    recoverable,
+    verifier::batch::BatchVerifier,
+    VerifyingKey,
+};
+use elliptic_curve::{
+    rand_core::{self, CryptoRng, RngCore},
+    weierstrass::{point::UncompressedPoint, public_key::PublicKey},
+    FromBytes,
+};
+use sha2::{Digest, Sha256};
+
+/// Number of signatures in each batch
+const BATCH_SIZE: u8 = 64;
+
+/// Curve type using the `p256` crate's arithmetic
+type Bench = common::p256::Curve;
+
+/// Deterministic RNG for benchmarking (NOT cryptographically secure!)
+struct BenchRng(u64);
+
+impl RngCore for BenchRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        // xorshift64
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for BenchRng {}
+
+/// Sign `msg` with the given secret scalar and an arbitrary distinct nonce
+fn sign(
+    secret_scalar: &Scalar,
+    public_key: &PublicKey<Bench>,
+    msg: &[u8],
+    nonce: u64,
+) -> recoverable::Signature<Bench> {
+    let k = Scalar(p256::Scalar::from(nonce));
+    let signature = sign_prehashed::<Bench>(secret_scalar, &k, &Sha256::digest(msg)).unwrap();
+    recoverable::Signature::from_trial_recovery(public_key, msg, &signature).unwrap()
+}
+
+fn bench_batch_verify(c: &mut Criterion) {
+    let messages = (0..BATCH_SIZE).map(|i| vec![i; 32]).collect::<Vec<_>>();
+
+    // Signatures from distinct keys
+    let distinct_keys = messages
+        .iter()
+        .enumerate()
+        .map(|(i, msg)| {
+            let secret_scalar = Scalar::from_bytes(&Sha256::digest(&[i as u8])).unwrap();
+            let public_key = Bench::mul_base(&secret_scalar).unwrap();
+            let public_key = PublicKey::from(UncompressedPoint::from(public_key));
+            let signature = sign(&secret_scalar, &public_key, msg, i as u64 + 1);
+            let verifying_key = VerifyingKey::from_public_key(public_key).unwrap();
+            (public_key, verifying_key, msg.as_slice(), signature)
+        })
+        .collect::<Vec<_>>();
+
+    // Signatures from a single key
+    let secret_scalar = Scalar::from_bytes(&Sha256::digest(b"secret key")).unwrap();
+    let public_key = Bench::mul_base(&secret_scalar).unwrap();
+    let public_key = PublicKey::from(UncompressedPoint::from(public_key));
+    let verifying_key = VerifyingKey::from_public_key(public_key).unwrap();
+
+    let batch = messages
+        .iter()
+        .enumerate()
+        .map(|(i, msg)| {
+            let signature = sign(&secret_scalar, &public_key, msg, i as u64 + 1);
+            (msg.as_slice(), signature)
+        })
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group(format!("batch_verify ({} signatures)", BATCH_SIZE));
+
+    group.bench_function("individual", |b| {
+        b.iter(|| {
+            for (_, verifying_key, msg, signature) in &distinct_keys {
+                signature::Verifier::verify(verifying_key, msg, &signature.signature()).unwrap();
+            }
+        })
+    });
+
+    group.bench_function("BatchVerifier", |b| {
+        b.iter(|| {
+            let mut verifier = BatchVerifier::<Bench>::new();
+
+            for (public_key, _, msg, signature) in &distinct_keys {
+                verifier.queue(public_key, msg, signature).unwrap();
+            }
+
+            verifier.verify(BenchRng(42)).unwrap();
+        })
+    });
+
+    group.bench_function("individual (same key)", |b| {
+        b.iter(|| {
+            for (msg, signature) in &batch {
+                signature::Verifier::verify(&verifying_key, msg, &signature.signature()).unwrap();
+            }
+        })
+    });
+
+    group.bench_function("verify_batch (same key)", |b| {
+        b.iter(|| verifying_key.verify_batch(&batch, BenchRng(42)).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    // Each iteration verifies a whole batch, so take fewer samples
+    config = Criterion::default().sample_size(10);
+    targets = bench_batch_verify
+}
+criterion_main!(benches);
@@ -0,0 +1,273 @@
+//! Curve types shared by the benchmarks.
+//!
+//! This crate's traits can't be impl'd on the `p256` and `k256` crates' types
+//! from outside of it, so each curve type here wraps the arithmetic of one of
+//! those crates in newtypes.
+
+#![allow(dead_code)]
+
+use hex_literal::hex;
+
+/// Order of the NIST P-256 group
+pub const P256_ORDER: [u8; 32] =
+    hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551");
+
+/// Order of the secp256k1 group
+pub const K256_ORDER: [u8; 32] =
+    hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141");
+
+/// Define a module containing a curve type built on the arithmetic of the
+/// given crate, whose curve type is `$inner`.
+///
+/// If `mul_base` is given, `k×𝑮` is computed by calling the given function
+/// rather than using the default `CurveArithmetic::mul_base`.
+macro_rules! impl_curve {
+    ($name:ident, $krate:ident::$inner:ident, $order:expr) => {
+        impl_curve!(@curve $name, $krate::$inner, $order, {});
+    };
+    ($name:ident, $krate:ident::$inner:ident, $order:expr, mul_base = $mul_base:path) => {
+        impl_curve!(@curve $name, $krate::$inner, $order, {
+            fn mul_base(k: &Scalar) -> CtOption<Point> {
+                $mul_base(k)
+            }
+        });
+    };
+    (@curve $name:ident, $krate:ident::$inner:ident, $order:expr, { $($mul_base:tt)* }) => {
+        pub mod $name {
+            use ecdsa::{CurveOrder, NormalizeLow};
+            use elliptic_curve::{
+                consts::U32,
+                point::Generator,
+                scalar::NonZeroScalar,
+                subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
+                weierstrass::{
+                    point::UncompressedPoint,
+                    public_key::{FromPublicKey, PublicKey},
+                },
+                Arithmetic, ElementBytes, FromBytes,
+            };
+            use std::ops::Mul;
+
+            #[cfg(feature = "hazmat")]
+            use ecdsa::{
+                hazmat::{verify_prehashed, CurveArithmetic, DigestPrimitive, VerifyPrimitive},
+                Error, Signature,
+            };
+
+            /// Curve type using the arithmetic of the crate of the same name
+            #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+            pub struct Curve;
+
+            impl elliptic_curve::Curve for Curve {
+                type ElementSize = U32;
+            }
+
+            impl elliptic_curve::weierstrass::Curve for Curve {
+                const COMPRESS_POINTS: bool = false;
+            }
+
+            impl Arithmetic for Curve {
+                type Scalar = Scalar;
+                type AffinePoint = Point;
+            }
+
+            impl CurveOrder for Curve {
+                fn order() -> ElementBytes<Curve> {
+                    ElementBytes::<Curve>::clone_from_slice(&$order)
+                }
+            }
+
+            #[cfg(feature = "hazmat")]
+            impl DigestPrimitive for Curve {
+                type Digest = sha2::Sha256;
+            }
+
+            /// Newtype for the crate's scalar, allowing [`NormalizeLow`] to
+            /// be implemented for it
+            #[derive(Clone, Copy, Default)]
+            pub struct Scalar(pub ::$krate::Scalar);
+
+            impl ConditionallySelectable for Scalar {
+                fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                    Scalar(::$krate::Scalar::conditional_select(&a.0, &b.0, choice))
+                }
+            }
+
+            impl ConstantTimeEq for Scalar {
+                fn ct_eq(&self, other: &Self) -> Choice {
+                    self.0.ct_eq(&other.0)
+                }
+            }
+
+            impl FromBytes for Scalar {
+                type Size = U32;
+
+                fn from_bytes(bytes: &ElementBytes<Curve>) -> CtOption<Self> {
+                    ::$krate::Scalar::from_bytes(bytes).map(Scalar)
+                }
+            }
+
+            impl From<Scalar> for ElementBytes<Curve> {
+                fn from(scalar: Scalar) -> ElementBytes<Curve> {
+                    scalar.0.into()
+                }
+            }
+
+            impl NormalizeLow for Scalar {
+                fn normalize_low(&self) -> (Self, bool) {
+                    let negated = -self.0;
+                    let bytes: ElementBytes<Curve> = self.0.into();
+                    let negated_bytes: ElementBytes<Curve> = negated.into();
+
+                    if bytes.as_slice() > negated_bytes.as_slice() {
+                        (Scalar(negated), true)
+                    } else {
+                        (*self, false)
+                    }
+                }
+            }
+
+            /// Newtype for the crate's affine point, allowing it to be used
+            /// with [`Curve`]
+            #[derive(Clone, Copy, Debug)]
+            pub struct Point(pub ::$krate::AffinePoint);
+
+            impl Point {
+                /// Convert a projective point to affine, or none if it's the
+                /// identity
+                pub fn from_projective(point: ::$krate::ProjectivePoint) -> CtOption<Self> {
+                    let affine = point.to_affine();
+                    let point = affine.unwrap_or(::$krate::AffinePoint::generator());
+                    CtOption::new(Point(point), affine.is_some())
+                }
+            }
+
+            impl Default for Point {
+                fn default() -> Self {
+                    Self::generator()
+                }
+            }
+
+            impl ConditionallySelectable for Point {
+                fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                    Point(::$krate::AffinePoint::conditional_select(&a.0, &b.0, choice))
+                }
+            }
+
+            impl Generator for Point {
+                fn generator() -> Self {
+                    Point(::$krate::AffinePoint::generator())
+                }
+            }
+
+            impl Mul<NonZeroScalar<Curve>> for Point {
+                type Output = Point;
+
+                fn mul(self, scalar: NonZeroScalar<Curve>) -> Point {
+                    let point = ::$krate::ProjectivePoint::from(self.0) * &scalar.as_ref().0;
+                    Point(point.to_affine().unwrap())
+                }
+            }
+
+            impl From<Point> for UncompressedPoint<Curve> {
+                fn from(point: Point) -> UncompressedPoint<Curve> {
+                    let point = ::$krate::UncompressedPoint::from(point.0);
+                    UncompressedPoint::from_bytes(point.into_bytes()).unwrap()
+                }
+            }
+
+            impl FromPublicKey<Curve> for Point {
+                fn from_public_key(public_key: &PublicKey<Curve>) -> CtOption<Self> {
+                    let public_key =
+                        PublicKey::<::$krate::$inner>::from_bytes(public_key.as_bytes()).unwrap();
+                    let point = ::$krate::AffinePoint::from_public_key(&public_key);
+                    let is_some = point.is_some();
+                    CtOption::new(
+                        Point(point.unwrap_or(::$krate::AffinePoint::generator())),
+                        is_some,
+                    )
+                }
+            }
+
+            #[cfg(feature = "hazmat")]
+            impl VerifyPrimitive<Curve> for Point {
+                fn verify_prehashed(
+                    &self,
+                    hashed_msg: &ElementBytes<Curve>,
+                    signature: &Signature<Curve>,
+                ) -> Result<(), Error> {
+                    verify_prehashed::<Curve>(self, hashed_msg, signature)
+                }
+            }
+
+            #[cfg(feature = "hazmat")]
+            impl CurveArithmetic for Curve {
+                fn add_scalars(a: &Scalar, b: &Scalar) -> Scalar {
+                    Scalar(a.0 + &b.0)
+                }
+
+                fn mul_scalars(a: &Scalar, b: &Scalar) -> Scalar {
+                    Scalar(a.0 * &b.0)
+                }
+
+                fn negate_scalar(a: &Scalar) -> Scalar {
+                    Scalar(-a.0)
+                }
+
+                fn invert_scalar(a: &Scalar) -> CtOption<Scalar> {
+                    a.0.invert().map(Scalar)
+                }
+
+                fn reduce_scalar(bytes: &ElementBytes<Self>) -> Scalar {
+                    Scalar(::$krate::Scalar::from_bytes_reduced(bytes))
+                }
+
+                fn add_points(a: &Point, b: &Point) -> CtOption<Point> {
+                    Point::from_projective(
+                        ::$krate::ProjectivePoint::from(a.0)
+                            + &::$krate::ProjectivePoint::from(b.0),
+                    )
+                }
+
+                fn mul_point(point: &Point, k: &Scalar) -> CtOption<Point> {
+                    Point::from_projective(::$krate::ProjectivePoint::from(point.0) * &k.0)
+                }
+
+                fn x_coordinate(point: &Point) -> ElementBytes<Self> {
+                    let point = ::$krate::UncompressedPoint::from(point.0);
+                    ElementBytes::<Self>::clone_from_slice(&point.as_bytes()[1..33])
+                }
+
+                type ProjectivePoint = ::$krate::ProjectivePoint;
+
+                fn identity() -> ::$krate::ProjectivePoint {
+                    ::$krate::ProjectivePoint::identity()
+                }
+
+                fn to_projective(point: &Point) -> ::$krate::ProjectivePoint {
+                    ::$krate::ProjectivePoint::from(point.0)
+                }
+
+                fn to_affine(point: &::$krate::ProjectivePoint) -> CtOption<Point> {
+                    Point::from_projective(*point)
+                }
+
+                fn add_projective(
+                    a: &::$krate::ProjectivePoint,
+                    b: &::$krate::ProjectivePoint,
+                ) -> ::$krate::ProjectivePoint {
+                    *a + b
+                }
+
+                fn double_projective(a: &::$krate::ProjectivePoint) -> ::$krate::ProjectivePoint {
+                    a.double()
+                }
+
+                $($mul_base)*
+            }
+        }
+    };
+}
+
+impl_curve!(p256, p256::NistP256, crate::common::P256_ORDER);
+impl_curve!(k256, k256::Secp256k1, crate::common::K256_ORDER);
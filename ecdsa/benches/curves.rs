@@ -0,0 +1,107 @@
+//! Benchmark suite parameterized over P-256 and secp256k1.
+//!
+//! Measures parsing signatures in fixed-size and ASN.1 DER form, serializing
+//! them as DER, and normalizing `s`. When the `hazmat` feature is enabled,
+//! signing and verifying a prehashed message are measured as well, with
+//! parsing kept out of the verification timings so regressions in each can
+//! be told apart.
+//!
+//! Each curve type is built on the `p256` or `k256` crate's arithmetic (see
+//! the `common` module).
+//!
+//! Run with `cargo bench --bench curves`, optionally with `--features hazmat`.
+
+mod common;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ecdsa::{Curve, NormalizeLow, Signature};
+use elliptic_curve::{consts::U32, Arithmetic};
+
+#[cfg(feature = "hazmat")]
+use {
+    ecdsa::hazmat::{sign_prehashed, verify_prehashed, CurveArithmetic},
+    elliptic_curve::{ElementBytes, FromBytes},
+    sha2::{Digest, Sha256},
+};
+
+/// Signature with a high `s`, which is valid for both curves
+const SIGNATURE: [u8; 64] = [
+    0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42,
+    0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42, 0x42,
+    0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0,
+    0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0, 0xf0,
+];
+
+/// Benchmark the curve-independent encoding operations
+fn bench_encoding<C>(c: &mut Criterion, curve: &str)
+where
+    C: Curve + Arithmetic<ElementSize = U32>,
+    C::Scalar: NormalizeLow,
+{
+    let signature = Signature::<C>::from_p1363(&SIGNATURE).unwrap();
+    let der = signature.to_der();
+    let mut group = c.benchmark_group(curve);
+
+    group.bench_function("parse (fixed)", |b| {
+        b.iter(|| Signature::<C>::from_p1363(black_box(&SIGNATURE)).unwrap())
+    });
+
+    group.bench_function("parse (DER)", |b| {
+        b.iter(|| Signature::<C>::from_der(black_box(der.as_bytes())).unwrap())
+    });
+
+    group.bench_function("serialize (DER)", |b| {
+        b.iter(|| black_box(&signature).to_der())
+    });
+
+    group.bench_function("normalize_s", |b| {
+        b.iter(|| {
+            let mut normalized = *black_box(&signature);
+            normalized.normalize_s().unwrap();
+            normalized
+        })
+    });
+
+    group.finish();
+}
+
+/// Benchmark signing and verifying a prehashed message
+#[cfg(feature = "hazmat")]
+fn bench_hazmat<C>(c: &mut Criterion, curve: &str)
+where
+    C: CurveArithmetic<ElementSize = U32>,
+{
+    let secret_scalar = C::Scalar::from_bytes(&Sha256::digest(b"secret key")).unwrap();
+    let public_key = C::mul_base(&secret_scalar).unwrap();
+    let prehash = ElementBytes::<C>::clone_from_slice(&Sha256::digest(b"sample"));
+
+    let mut k_bytes = ElementBytes::<C>::default();
+    k_bytes[31] = 1;
+    let k = C::Scalar::from_bytes(&k_bytes).unwrap();
+    let signature = sign_prehashed::<C>(&secret_scalar, &k, &prehash).unwrap();
+    let mut group = c.benchmark_group(curve);
+
+    group.bench_function("sign_prehashed", |b| {
+        b.iter(|| sign_prehashed::<C>(&secret_scalar, &k, black_box(&prehash)).unwrap())
+    });
+
+    group.bench_function("verify_prehashed", |b| {
+        b.iter(|| verify_prehashed::<C>(&public_key, black_box(&prehash), &signature).unwrap())
+    });
+
+    group.finish();
+}
+
+fn bench_curves(c: &mut Criterion) {
+    bench_encoding::<common::p256::Curve>(c, "P-256");
+    bench_encoding::<common::k256::Curve>(c, "secp256k1");
+
+    #[cfg(feature = "hazmat")]
+    {
+        bench_hazmat::<common::p256::Curve>(c, "P-256");
+        bench_hazmat::<common::k256::Curve>(c, "secp256k1");
+    }
+}
+
+criterion_group!(benches, bench_curves);
+criterion_main!(benches);
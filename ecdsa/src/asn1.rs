@@ -8,15 +8,19 @@
 
 use crate::{
     generic_array::{typenum::Unsigned, ArrayLength, GenericArray},
-    Error,
+    Error, ErrorKind, SignatureBytes,
 };
 use core::{
     convert::{TryFrom, TryInto},
     fmt,
+    marker::PhantomData,
     ops::{Add, Range},
 };
 use elliptic_curve::{consts::U9, weierstrass::Curve, ElementBytes};
 
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+
 /// Maximum overhead of an ASN.1 DER-encoded ECDSA signature for a given curve:
 /// 9-bytes.
 ///
@@ -49,6 +53,11 @@ const SEQUENCE_TAG: u8 = 0x30;
 /// ASN.1 DER-encoded signature.
 ///
 /// Generic over the scalar size of the elliptic curve.
+///
+/// The encoded signature is stored in a fixed-size stack buffer of
+/// [`MaxSize`] bytes, with [`Signature::as_bytes`] returning only the portion
+/// actually used. Serializing and parsing DER therefore doesn't require a heap
+/// (i.e. the `alloc` feature).
 pub struct Signature<C>
 where
     C: Curve,
@@ -75,7 +84,7 @@ where
 {
     /// Parse an ASN.1 DER-encoded ECDSA signature from a byte slice
     fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
-        bytes.try_into()
+        Self::try_from(bytes).map_err(Into::into)
     }
 }
 
@@ -92,7 +101,51 @@ where
         self.s_range.end
     }
 
-    /// Create an ASN.1 DER encoded signature from the `r` and `s` scalars
+    /// Borrow the ASN.1 DER-encoded signature as a byte slice
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes.as_slice()[..self.len()]
+    }
+
+    /// Read a DER-encoded signature from the given reader.
+    ///
+    /// Reads exactly the `SEQUENCE` header and the number of bytes given by
+    /// its length, leaving the reader positioned immediately after the
+    /// signature. Returns an error if the input is truncated, or if the
+    /// declared length exceeds [`MaxSize`] for this curve.
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn from_reader<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        let mut bytes = DocumentBytes::<C>::default();
+        read_exact(reader, &mut bytes[..2])?;
+
+        if bytes[0] != SEQUENCE_TAG {
+            return Err(ParseError::UnexpectedTag.into());
+        }
+
+        let mut header_len = 2;
+
+        if bytes[1] == 0x81 {
+            read_exact(reader, &mut bytes[2..3])?;
+            header_len = 3;
+        }
+
+        let (zlen, _) = parse_length(&bytes[1..header_len])?;
+        let total_len = header_len.checked_add(zlen).unwrap();
+
+        if total_len > bytes.len() {
+            return Err(ParseError::TooLong.into());
+        }
+
+        read_exact(reader, &mut bytes[header_len..total_len])?;
+        Self::try_from(&bytes[..total_len]).map_err(Into::into)
+    }
+
+    /// Create an ASN.1 DER encoded signature from the `r` and `s` scalars.
+    ///
+    /// Integers are always encoded in their minimal form: leading zeroes are
+    /// stripped, and a single `0x00` byte is prepended only when required to
+    /// keep an integer with its high bit set positive. The output is
+    /// therefore canonical DER, so parsing and re-serializing is idempotent.
     pub(crate) fn from_scalars(r: &ElementBytes<C>, s: &ElementBytes<C>) -> Self {
         let r_len = int_length(r);
         let s_len = int_length(s);
@@ -100,7 +153,7 @@ where
         let mut bytes = DocumentBytes::<C>::default();
 
         // SEQUENCE header
-        bytes[0] = SEQUENCE_TAG as u8;
+        bytes[0] = SEQUENCE_TAG;
         let zlen = r_len.checked_add(s_len).unwrap().checked_add(4).unwrap();
 
         let offset = if zlen >= 0x80 {
@@ -125,15 +178,72 @@ where
             .expect("generated invalid ASN.1 DER")
     }
 
-    /// Get the `r` component of the signature (leading zeros removed)
-    pub(crate) fn r(&self) -> &[u8] {
+    /// Get the `r` component of the signature (leading zero removed)
+    pub fn r(&self) -> &[u8] {
         &self.bytes[self.r_range.clone()]
     }
 
-    /// Get the `s` component of the signature (leading zeros removed)
-    pub(crate) fn s(&self) -> &[u8] {
+    /// Get the `s` component of the signature (leading zero removed)
+    pub fn s(&self) -> &[u8] {
         &self.bytes[self.s_range.clone()]
     }
+
+    /// Get the encoded length of the `r` component's `INTEGER` value in
+    /// bytes, including the leading zero (if any) required to keep it positive
+    pub fn r_len(&self) -> usize {
+        self.int_len(&self.r_range)
+    }
+
+    /// Get the encoded length of the `s` component's `INTEGER` value in
+    /// bytes, including the leading zero (if any) required to keep it positive
+    pub fn s_len(&self) -> usize {
+        self.int_len(&self.s_range)
+    }
+
+    /// Get the length of the entire DER-encoded signature in bytes, including
+    /// the `SEQUENCE` header. Equivalent to [`Signature::len`].
+    pub fn total_len(&self) -> usize {
+        self.len()
+    }
+
+    /// Get the encoded length of the `INTEGER` whose value (with the leading
+    /// zero removed) is in the given range.
+    ///
+    /// The byte preceding the value is either the leading zero or the
+    /// `INTEGER`'s length, which is never zero as empty integers are invalid.
+    fn int_len(&self, range: &Range<usize>) -> usize {
+        if self.bytes[range.start.checked_sub(1).unwrap()] == 0 {
+            range.len().checked_add(1).unwrap()
+        } else {
+            range.len()
+        }
+    }
+
+    /// Rebuild this signature with `r` replaced by the given big endian
+    /// integer, re-deriving the `SEQUENCE` and `INTEGER` framing so the
+    /// result is well-formed DER.
+    ///
+    /// Returns an error if `r` (less any leading zeroes) is larger than the
+    /// curve's field size, as the result would not be a valid signature.
+    pub fn with_r(&self, r: &[u8]) -> Result<Self, Error> {
+        Ok(Self::from_scalars(
+            &pad_int::<C>(r)?,
+            &pad_int::<C>(self.s())?,
+        ))
+    }
+
+    /// Rebuild this signature with `s` replaced by the given big endian
+    /// integer, re-deriving the `SEQUENCE` and `INTEGER` framing so the
+    /// result is well-formed DER.
+    ///
+    /// Returns an error if `s` (less any leading zeroes) is larger than the
+    /// curve's field size, as the result would not be a valid signature.
+    pub fn with_s(&self, s: &[u8]) -> Result<Self, Error> {
+        Ok(Self::from_scalars(
+            &pad_int::<C>(self.r())?,
+            &pad_int::<C>(s)?,
+        ))
+    }
 }
 
 impl<C> AsRef<[u8]> for Signature<C>
@@ -144,7 +254,23 @@ where
     <C::ElementSize as Add>::Output: Add<MaxOverhead> + ArrayLength<u8>,
 {
     fn as_ref(&self) -> &[u8] {
-        &self.bytes.as_slice()[..self.len()]
+        self.as_bytes()
+    }
+}
+
+impl<C> Clone for Signature<C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<MaxOverhead> + ArrayLength<u8>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+            r_range: self.r_range.clone(),
+            s_range: self.s_range.clone(),
+        }
     }
 }
 
@@ -170,71 +296,292 @@ where
     MaxSize<C>: ArrayLength<u8>,
     <C::ElementSize as Add>::Output: Add<MaxOverhead> + ArrayLength<u8>,
 {
-    type Error = Error;
-
-    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
-        // Signature format is a SEQUENCE of two INTEGER values. We
-        // support only integers of less than 127 bytes each (signed
-        // encoding) so the resulting raw signature will have length
-        // at most 254 bytes.
-        //
-        // First byte is SEQUENCE tag.
-        if bytes[0] != SEQUENCE_TAG as u8 {
-            return Err(Error::new());
-        }
+    type Error = ParseError;
 
-        // The SEQUENCE length will be encoded over one or two bytes. We
-        // limit the total SEQUENCE contents to 255 bytes, because it
-        // makes things simpler; this is enough for subgroup orders up
-        // to 999 bits.
-        let mut zlen = bytes[1] as usize;
+    /// Parse a strictly DER-encoded signature, rejecting BER-isms such as
+    /// non-minimal lengths or integers and trailing data
+    fn try_from(bytes: &[u8]) -> Result<Self, ParseError> {
+        let (r_range, s_range) = parse_signature::<C>(bytes)?;
+        let mut byte_arr = DocumentBytes::<C>::default();
+        byte_arr[..bytes.len()].copy_from_slice(bytes);
 
-        let offset = if zlen > 0x80 {
-            if zlen != 0x81 {
-                return Err(Error::new());
-            }
+        Ok(Signature {
+            bytes: byte_arr,
+            r_range,
+            s_range,
+        })
+    }
+}
 
-            zlen = bytes[2] as usize;
-            3
-        } else {
-            2
-        };
+/// Borrowed view of an ASN.1 DER-encoded signature.
+///
+/// Unlike [`Signature`], which copies the input into its own buffer, the
+/// `r` and `s` components are borrowed from the parsed input. This allows
+/// signatures to be parsed out of a larger buffer without copying, e.g. when
+/// verifying many of them on a constrained device. The same strict DER rules
+/// are applied when parsing.
+pub struct SignatureRef<'a, C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<MaxOverhead> + ArrayLength<u8>,
+{
+    /// ASN.1 DER-encoded signature data
+    bytes: &'a [u8],
 
-        if zlen != bytes.len().checked_sub(offset).unwrap() {
-            return Err(Error::new());
-        }
+    /// Range of the `r` value within the signature
+    r_range: Range<usize>,
 
-        // First INTEGER (r)
-        let r_range = parse_int(&bytes[offset..], C::ElementSize::to_usize())?;
-        let r_start = offset.checked_add(r_range.start).unwrap();
-        let r_end = offset.checked_add(r_range.end).unwrap();
+    /// Range of the `s` value within the signature
+    s_range: Range<usize>,
 
-        // Second INTEGER (s)
-        let s_range = parse_int(&bytes[r_end..], C::ElementSize::to_usize())?;
-        let s_start = r_end.checked_add(s_range.start).unwrap();
-        let s_end = r_end.checked_add(s_range.end).unwrap();
+    /// Curve the signature is for
+    curve: PhantomData<C>,
+}
+
+impl<'a, C> SignatureRef<'a, C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<MaxOverhead> + ArrayLength<u8>,
+{
+    /// Parse a strictly DER-encoded signature, borrowing its contents
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, ParseError> {
+        let (r_range, s_range) = parse_signature::<C>(bytes)?;
+
+        Ok(SignatureRef {
+            bytes,
+            r_range,
+            s_range,
+            curve: PhantomData,
+        })
+    }
+
+    /// Get the DER-encoded signature this view borrows from
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Get the `r` component of the signature (leading zero removed)
+    pub fn r(&self) -> &'a [u8] {
+        &self.bytes[self.r_range.clone()]
+    }
+
+    /// Get the `s` component of the signature (leading zero removed)
+    pub fn s(&self) -> &'a [u8] {
+        &self.bytes[self.s_range.clone()]
+    }
+
+    /// Copy this signature into an owned [`Signature`]
+    pub fn to_owned_der(&self) -> Signature<C> {
+        let mut bytes = DocumentBytes::<C>::default();
+        bytes[..self.bytes.len()].copy_from_slice(self.bytes);
 
-        if s_end != bytes.as_ref().len() {
-            return Err(Error::new());
+        Signature {
+            bytes,
+            r_range: self.r_range.clone(),
+            s_range: self.s_range.clone(),
         }
+    }
 
-        let mut byte_arr = DocumentBytes::<C>::default();
-        byte_arr[..s_end].copy_from_slice(bytes.as_ref());
+    /// Convert this signature into the fixed-size form
+    pub fn to_fixed(&self) -> crate::Signature<C> {
+        let r = pad_int::<C>(self.r()).expect("ASN.1 signature integers fit in field size");
+        let s = pad_int::<C>(self.s()).expect("ASN.1 signature integers fit in field size");
+        crate::Signature::from_scalars(&r, &s)
+    }
+}
 
-        Ok(Signature {
-            bytes: byte_arr,
-            r_range: Range {
-                start: r_start,
-                end: r_end,
-            },
-            s_range: Range {
-                start: s_start,
-                end: s_end,
-            },
+impl<'a, C> Clone for SignatureRef<'a, C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<MaxOverhead> + ArrayLength<u8>,
+{
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes,
+            r_range: self.r_range.clone(),
+            s_range: self.s_range.clone(),
+            curve: PhantomData,
+        }
+    }
+}
+
+impl<'a, C> fmt::Debug for SignatureRef<'a, C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<MaxOverhead> + ArrayLength<u8>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("asn1::SignatureRef")
+            .field("r", &self.r())
+            .field("s", &self.s())
+            .finish()
+    }
+}
+
+impl<'a, C> TryFrom<&'a [u8]> for SignatureRef<'a, C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<MaxOverhead> + ArrayLength<u8>,
+{
+    type Error = ParseError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, ParseError> {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl<'a, C> From<SignatureRef<'a, C>> for Signature<C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<MaxOverhead> + ArrayLength<u8>,
+{
+    fn from(signature: SignatureRef<'a, C>) -> Signature<C> {
+        signature.to_owned_der()
+    }
+}
+
+/// Parse a strictly DER-encoded signature, returning the ranges of its `r`
+/// and `s` values (with any leading zero removed) within `bytes`
+fn parse_signature<C>(bytes: &[u8]) -> Result<(Range<usize>, Range<usize>), ParseError>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<MaxOverhead> + ArrayLength<u8>,
+{
+    // Signature format is a SEQUENCE of two INTEGER values. We
+    // support only integers of less than 127 bytes each (signed
+    // encoding) so the resulting raw signature will have length
+    // at most 254 bytes.
+    //
+    // First byte is SEQUENCE tag.
+    match bytes.first() {
+        Some(&SEQUENCE_TAG) => (),
+        Some(_) => return Err(ParseError::UnexpectedTag),
+        None => return Err(ParseError::Truncated),
+    }
+
+    // The SEQUENCE length will be encoded over one or two bytes. We
+    // limit the total SEQUENCE contents to 255 bytes, because it
+    // makes things simpler; this is enough for subgroup orders up
+    // to 999 bits.
+    let (zlen, len_size) = parse_length(&bytes[1..])?;
+    let offset = len_size.checked_add(1).unwrap();
+    let body_len = bytes.len().checked_sub(offset).unwrap();
+
+    // Reject declared lengths which can't fit in the signature buffer
+    // before examining the contents
+    if offset.checked_add(zlen).unwrap() > MaxSize::<C>::to_usize() {
+        return Err(ParseError::TooLong);
+    }
+
+    if zlen > body_len {
+        return Err(ParseError::Truncated);
+    }
+
+    if zlen < body_len {
+        return Err(ParseError::TrailingData);
+    }
+
+    // First INTEGER (r)
+    let r_range = parse_int(&bytes[offset..], C::ElementSize::to_usize())?;
+    let r_start = offset.checked_add(r_range.start).unwrap();
+    let r_end = offset.checked_add(r_range.end).unwrap();
+
+    // Second INTEGER (s)
+    let s_range = parse_int(&bytes[r_end..], C::ElementSize::to_usize())?;
+    let s_start = r_end.checked_add(s_range.start).unwrap();
+    let s_end = r_end.checked_add(s_range.end).unwrap();
+
+    if s_end != bytes.len() {
+        return Err(ParseError::TrailingData);
+    }
+
+    Ok((
+        Range {
+            start: r_start,
+            end: r_end,
+        },
+        Range {
+            start: s_start,
+            end: s_end,
+        },
+    ))
+}
+
+/// Errors which can occur when parsing an ASN.1 DER-encoded signature.
+///
+/// These can be converted into a [`signature::Error`], which carries an
+/// [`ErrorKind::Asn1`] as its source when the `std` feature is enabled.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// Input ended before the end of the encoded signature
+    Truncated,
+
+    /// Expected an ASN.1 `SEQUENCE` or `INTEGER` tag but found another tag
+    UnexpectedTag,
+
+    /// Length is not encoded in the shortest possible form, or uses an
+    /// encoding (e.g. indefinite length) which is not permitted in DER
+    NonCanonicalLength,
+
+    /// `INTEGER` has zero-length contents, which is not a valid encoding of
+    /// any value (in particular, not of zero)
+    EmptyInteger,
+
+    /// `INTEGER` has an unnecessary leading zero byte
+    NonMinimalInteger,
+
+    /// `INTEGER` has its high bit set, i.e. it is negative
+    NegativeInteger,
+
+    /// `INTEGER` is larger than the curve's scalar size
+    IntegerTooLarge,
+
+    /// Additional data was found after the end of the signature
+    TrailingData,
+
+    /// Encoded signature is longer than [`MaxSize`] for the curve
+    TooLong,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ParseError::Truncated => "ASN.1 signature truncated",
+            ParseError::UnexpectedTag => "unexpected ASN.1 tag",
+            ParseError::NonCanonicalLength => "non-canonical ASN.1 DER length",
+            ParseError::EmptyInteger => "empty ASN.1 INTEGER",
+            ParseError::NonMinimalInteger => "non-minimal ASN.1 DER INTEGER",
+            ParseError::NegativeInteger => "negative ASN.1 INTEGER",
+            ParseError::IntegerTooLarge => "ASN.1 INTEGER larger than scalar size",
+            ParseError::TrailingData => "trailing data after ASN.1 signature",
+            ParseError::TooLong => "ASN.1 signature exceeds maximum size",
         })
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Error {
+        ErrorKind::Asn1(err).into()
+    }
+}
+
 #[cfg(all(feature = "digest", feature = "hazmat"))]
 impl<C> signature::PrehashSignature for Signature<C>
 where
@@ -246,35 +593,154 @@ where
     type Digest = C::Digest;
 }
 
-/// Parse an integer from its ASN.1 DER serialization
-fn parse_int(bytes: &[u8], scalar_size: usize) -> Result<Range<usize>, Error> {
-    if bytes.len() < 3 {
-        return Err(Error::new());
+/// Convert an ASN.1 DER-encoded signature into the fixed-size IEEE P1363
+/// `r || s` form for the curve `C`.
+///
+/// This is a pure re-framing of the bytes which only depends on the curve's
+/// `ElementSize`, and doesn't require curve arithmetic (i.e. `r` and `s` are
+/// not checked to be valid scalars).
+pub fn der_to_p1363<C>(der: &[u8]) -> Result<SignatureBytes<C>, Error>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<MaxOverhead> + ArrayLength<u8>,
+{
+    crate::Signature::<C>::from_der(der).map(|signature| signature.to_p1363())
+}
+
+/// Convert a fixed-size IEEE P1363 `r || s` signature for the curve `C` into
+/// ASN.1 DER, returning an error if `bytes` is the wrong length.
+///
+/// Like [`der_to_p1363`], this doesn't require curve arithmetic.
+pub fn p1363_to_der<C>(bytes: &[u8]) -> Result<Signature<C>, Error>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<MaxOverhead> + ArrayLength<u8>,
+{
+    crate::Signature::<C>::from_p1363(bytes).map(|signature| signature.to_der())
+}
+
+/// Fill `buf` from the reader, treating a premature end of input as a
+/// truncated signature
+#[cfg(feature = "std")]
+fn read_exact<R: Read>(reader: &mut R, buf: &mut [u8]) -> Result<(), Error> {
+    reader.read_exact(buf).map_err(|err| match err.kind() {
+        io::ErrorKind::UnexpectedEof => ParseError::Truncated.into(),
+        _ => Error::from_source(err),
+    })
+}
+
+/// Does the given input start with a DER `SEQUENCE` header whose declared
+/// length matches the length of the input?
+pub(crate) fn is_sequence(bytes: &[u8]) -> bool {
+    match (bytes.first(), bytes.get(1..).map(parse_length)) {
+        (Some(&SEQUENCE_TAG), Some(Ok((len, len_size)))) => {
+            len_size.checked_add(1).unwrap().checked_add(len) == Some(bytes.len())
+        }
+        _ => false,
     }
+}
 
-    if bytes[0] != INTEGER_TAG as u8 {
-        return Err(Error::new());
+/// Is the given input a signature in strict canonical DER form?
+///
+/// This checks the same rules the parser enforces (lengths and integers use
+/// their minimal encoding, integers are non-negative, and there's no data
+/// after the end of the `SEQUENCE`), and additionally rejects zero `r` or
+/// `s` values, without decoding or re-encoding the signature. It isn't
+/// specific to a particular curve, so doesn't check integers against the
+/// curve's scalar size.
+pub fn is_canonical(bytes: &[u8]) -> bool {
+    if !is_sequence(bytes) {
+        return false;
     }
 
-    let len = bytes[1] as usize;
+    let offset = match parse_length(&bytes[1..]) {
+        Ok((_, len_size)) => len_size.checked_add(1).unwrap(),
+        Err(_) => return false,
+    };
+
+    let r_range = match parse_int(&bytes[offset..], core::usize::MAX) {
+        Ok(range) => range,
+        Err(_) => return false,
+    };
+
+    let r_end = offset.checked_add(r_range.end).unwrap();
 
-    if len >= 0x80 || len.checked_add(2).unwrap() > bytes.len() {
-        return Err(Error::new());
+    match parse_int(&bytes[r_end..], core::usize::MAX) {
+        Ok(s_range) => {
+            r_range.start < r_range.end
+                && s_range.start < s_range.end
+                && r_end.checked_add(s_range.end) == Some(bytes.len())
+        }
+        Err(_) => false,
     }
+}
 
-    let mut start = 2usize;
+/// Parse a DER length, returning the length and the number of bytes used to
+/// encode it. Only lengths which fit in a single byte are supported.
+fn parse_length(bytes: &[u8]) -> Result<(usize, usize), ParseError> {
+    match bytes.first() {
+        Some(&len) if len < 0x80 => Ok((len as usize, 1)),
+        Some(0x81) => match bytes.get(1) {
+            Some(&len) if len >= 0x80 => Ok((len as usize, 2)),
+            Some(_) => Err(ParseError::NonCanonicalLength),
+            None => Err(ParseError::Truncated),
+        },
+        Some(_) => Err(ParseError::NonCanonicalLength),
+        None => Err(ParseError::Truncated),
+    }
+}
+
+/// Parse an integer from its ASN.1 DER serialization, returning the range of
+/// its value with the leading zero byte (if any) removed
+fn parse_int(bytes: &[u8], scalar_size: usize) -> Result<Range<usize>, ParseError> {
+    match bytes.first() {
+        Some(&INTEGER_TAG) => (),
+        Some(_) => return Err(ParseError::UnexpectedTag),
+        None => return Err(ParseError::Truncated),
+    }
+
+    let (len, len_size) = parse_length(&bytes[1..])?;
+    let start = len_size.checked_add(1).unwrap();
     let end = start.checked_add(len).unwrap();
 
-    start = start
-        .checked_add(trim_zeroes(&bytes[start..end], scalar_size)?)
-        .unwrap();
+    if end > bytes.len() {
+        return Err(ParseError::Truncated);
+    }
+
+    let value = &bytes[start..end];
+
+    if value.is_empty() {
+        return Err(ParseError::EmptyInteger);
+    }
+
+    if value[0] >= 0x80 {
+        return Err(ParseError::NegativeInteger);
+    }
+
+    // A leading zero is only permitted when needed to clear the sign bit
+    if value.len() > 1 && value[0] == 0 && value[1] < 0x80 {
+        return Err(ParseError::NonMinimalInteger);
+    }
+
+    let leading_zeroes = if value[0] == 0 { 1 } else { 0 };
 
-    Ok(Range { start, end })
+    if value.len().checked_sub(leading_zeroes).unwrap() > scalar_size {
+        return Err(ParseError::IntegerTooLarge);
+    }
+
+    Ok(Range {
+        start: start.checked_add(leading_zeroes).unwrap(),
+        end,
+    })
 }
 
 /// Serialize scalar as ASN.1 DER
 fn serialize_int(scalar: &[u8], out: &mut [u8], len: usize, scalar_size: usize) {
-    out[0] = INTEGER_TAG as u8;
+    out[0] = INTEGER_TAG;
     out[1] = len as u8;
 
     if len > scalar_size {
@@ -286,6 +752,23 @@ fn serialize_int(scalar: &[u8], out: &mut [u8], len: usize, scalar_size: usize)
     }
 }
 
+/// Left-pad a big endian integer with zeroes to the curve's field size,
+/// returning an error if it's too large
+fn pad_int<C: Curve>(mut int: &[u8]) -> Result<ElementBytes<C>, Error> {
+    while int.first() == Some(&0) {
+        int = &int[1..];
+    }
+
+    let mut bytes = ElementBytes::<C>::default();
+    let offset = bytes
+        .len()
+        .checked_sub(int.len())
+        .ok_or(ParseError::IntegerTooLarge)?;
+
+    bytes[offset..].copy_from_slice(int);
+    Ok(bytes)
+}
+
 /// Compute ASN.1 DER encoded length for the provided scalar. The ASN.1
 /// encoding is signed, so its leading bit must have value 0; it must also be
 /// of minimal length (so leading bytes of value 0 must be removed, except if
@@ -302,65 +785,540 @@ fn int_length(mut x: &[u8]) -> usize {
     }
 }
 
-/// Compute an offset within an ASN.1 INTEGER after skipping leading zeroes
-fn trim_zeroes(mut bytes: &[u8], scalar_size: usize) -> Result<usize, Error> {
-    let mut offset = 0;
+#[cfg(test)]
+mod tests {
+    use super::{der_to_p1363, is_canonical, p1363_to_der, ParseError, SignatureRef};
+    use crate::dev::MockCurve;
+    use core::convert::TryFrom;
+    use hex_literal::hex;
+    use signature::Signature as _;
+
+    type Signature = crate::Signature<MockCurve>;
+    type Asn1Signature = super::Signature<MockCurve>;
+
+    const EXAMPLE_SIGNATURE: [u8; 64] = [
+        0xf3, 0xac, 0x80, 0x61, 0xb5, 0x14, 0x79, 0x5b, 0x88, 0x43, 0xe3, 0xd6, 0x62, 0x95, 0x27,
+        0xed, 0x2a, 0xfd, 0x6b, 0x1f, 0x6a, 0x55, 0x5a, 0x7a, 0xca, 0xbb, 0x5e, 0x6f, 0x79, 0xc8,
+        0xc2, 0xac, 0x8b, 0xf7, 0x78, 0x19, 0xca, 0x5, 0xa6, 0xb2, 0x78, 0x6c, 0x76, 0x26, 0x2b,
+        0xf7, 0x37, 0x1c, 0xef, 0x97, 0xb2, 0x18, 0xe9, 0x6f, 0x17, 0x5a, 0x3c, 0xcd, 0xda, 0x2a,
+        0xcc, 0x5, 0x89, 0x3,
+    ];
+
+    #[test]
+    fn test_fixed_to_asn1_signature_roundtrip() {
+        let signature1 = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+
+        // Convert to ASN.1 DER and back
+        let asn1_signature = signature1.to_der();
+        let signature2 = Signature::from_der(asn1_signature.as_bytes()).unwrap();
+
+        assert_eq!(signature1, signature2);
+    }
+
+    /// Serialization only uses the fixed-size buffer (this test is run with
+    /// the `alloc` feature both enabled and disabled)
+    #[test]
+    fn test_stack_allocated_roundtrip() {
+        use super::MaxSize;
+        use crate::generic_array::typenum::Unsigned;
+
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        let der: Asn1Signature = signature.to_der();
+        assert!(der.len() <= MaxSize::<MockCurve>::to_usize());
+        assert_eq!(der.as_bytes().len(), der.len());
+
+        let mut buffer = [0u8; 73];
+        buffer[..der.len()].copy_from_slice(der.as_bytes());
+        let reparsed = Asn1Signature::try_from(&buffer[..der.len()]).unwrap();
+        assert_eq!(reparsed.as_bytes(), der.as_bytes());
+        assert_eq!(Signature::try_from(reparsed).unwrap(), signature);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_asn1_aliases() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        let der = signature.to_der();
+        assert_eq!(signature.to_asn1().as_bytes(), der.as_bytes());
+        assert_eq!(Signature::from_asn1(der.as_bytes()).unwrap(), signature);
+    }
+
+    #[test]
+    fn test_asn1_canonical() {
+        let der = hex!(
+            "3046
+             022100f3ac8061b514795b8843e3d6629527ed2afd6b1f6a555a7acabb5e6f79c8c2ac
+             0221008bf77819ca05a6b2786c76262bf7371cef97b218e96f175a3ccdda2acc058903"
+        );
+
+        let signature = Signature::from_der(&der).unwrap();
+        assert_eq!(signature.as_ref(), &EXAMPLE_SIGNATURE[..]);
+        assert_eq!(signature.to_der().as_bytes(), &der[..]);
+    }
+
+    #[test]
+    fn test_asn1_canonical_leading_zeroes() {
+        // `r` has two leading zero bytes which must be stripped, while `s`
+        // has one leading zero byte followed by a byte with its high bit set,
+        // so it must keep a single zero byte to remain positive
+        let bytes = hex!(
+            "00007f0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d
+             008002030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        );
+        let der = hex!(
+            "3042
+             021e7f0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d
+             0220008002030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        );
+
+        let signature = Signature::from_bytes(&bytes).unwrap();
+        assert_eq!(signature.to_der().as_bytes(), &der[..]);
+
+        let reparsed = Signature::from_der(signature.to_der().as_bytes()).unwrap();
+        assert_eq!(reparsed, signature);
+        assert_eq!(reparsed.to_der().as_bytes(), &der[..]);
+    }
+
+    #[test]
+    fn test_asn1_component_lengths() {
+        // `r` needs a leading zero to clear the sign bit, while `s` is one
+        // byte shorter than the field
+        let der = hex!(
+            "3044
+             022100800102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f
+             021f0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+        );
+
+        let signature = Asn1Signature::try_from(&der[..]).unwrap();
+        assert_eq!(signature.r_len(), 33);
+        assert_eq!(signature.r().len(), 32);
+        assert_eq!(signature.s_len(), 31);
+        assert_eq!(signature.s().len(), 31);
+        assert_eq!(signature.total_len(), der.len());
+
+        let signature = Asn1Signature::try_from(&hex!("3006020101020100")[..]).unwrap();
+        assert_eq!((signature.r_len(), signature.s_len()), (1, 1));
+        assert_eq!(signature.total_len(), 8);
+    }
+
+    #[test]
+    fn test_asn1_small_integers() {
+        let signature = Signature::from_der(&hex!("3006020101020100")).unwrap();
+        assert_eq!(signature.r()[31], 1);
+        assert_eq!(signature.s()[31], 0);
+    }
+
+    #[test]
+    fn test_asn1_truncated() {
+        for der in &[&[][..], &hex!("30"), &hex!("300602010102")] {
+            assert_eq!(
+                Asn1Signature::try_from(*der).unwrap_err(),
+                ParseError::Truncated
+            );
+        }
+    }
+
+    #[test]
+    fn test_asn1_unexpected_tag() {
+        for der in &[hex!("3106020101020101"), hex!("3006040101020101")] {
+            assert_eq!(
+                Asn1Signature::try_from(&der[..]).unwrap_err(),
+                ParseError::UnexpectedTag
+            );
+        }
+    }
 
-    if bytes.len() > scalar_size {
-        if bytes.len() != scalar_size.checked_add(1).unwrap() {
-            return Err(Error::new());
+    #[test]
+    fn test_asn1_overlong_length() {
+        for der in &[
+            &hex!("308106020101020101")[..],
+            &hex!("300702810101020101"),
+            &hex!("3080020101020101"),
+        ] {
+            assert_eq!(
+                Asn1Signature::try_from(*der).unwrap_err(),
+                ParseError::NonCanonicalLength
+            );
         }
+    }
 
-        if bytes[0] != 0 {
-            return Err(Error::new());
+    #[test]
+    fn test_asn1_oversized_declared_length() {
+        // `SEQUENCE` claiming 0x7F bytes: more than the buffer, and more than
+        // `MaxSize` for this curve
+        for der in &[
+            &hex!("307f")[..],
+            &hex!("307f020101020101"),
+            &[0x30, 0x7f, 0x02, 0x01],
+        ] {
+            assert_eq!(
+                Asn1Signature::try_from(*der).unwrap_err(),
+                ParseError::TooLong
+            );
         }
 
-        bytes = &bytes[1..];
-        offset += 1;
+        // Declared lengths up to `MaxSize` are accepted, and the contents parsed
+        let mut der = [0u8; 73];
+        der[..2].copy_from_slice(&hex!("3047"));
+        assert_eq!(
+            Asn1Signature::try_from(&der[..]).unwrap_err(),
+            ParseError::UnexpectedTag
+        );
+
+        // `INTEGER` claiming 0x7F bytes on a short buffer
+        assert_eq!(
+            Asn1Signature::try_from(&hex!("3006027f01020101")[..]).unwrap_err(),
+            ParseError::Truncated
+        );
     }
 
-    while !bytes.is_empty() && bytes[0] == 0 {
-        bytes = &bytes[1..];
-        offset += 1;
+    #[test]
+    fn test_asn1_non_minimal_integer() {
+        assert_eq!(
+            Asn1Signature::try_from(&hex!("300702020001020101")[..]).unwrap_err(),
+            ParseError::NonMinimalInteger
+        );
     }
 
-    Ok(offset)
-}
+    #[test]
+    fn test_asn1_empty_integer() {
+        // Zero-length `r`
+        assert_eq!(
+            Asn1Signature::try_from(&hex!("30050200020101")[..]).unwrap_err(),
+            ParseError::EmptyInteger
+        );
 
-#[cfg(test)]
-mod tests {
-    use elliptic_curve::consts::U32;
-    use signature::Signature as _;
+        // Zero-length `s`
+        assert_eq!(
+            Asn1Signature::try_from(&hex!("30050201010200")[..]).unwrap_err(),
+            ParseError::EmptyInteger
+        );
+    }
 
-    #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
-    pub struct ExampleCurve;
+    #[test]
+    fn test_asn1_negative_integer() {
+        assert_eq!(
+            Asn1Signature::try_from(&hex!("3006020101020181")[..]).unwrap_err(),
+            ParseError::NegativeInteger
+        );
+    }
 
-    impl elliptic_curve::Curve for ExampleCurve {
-        type ElementSize = U32;
+    #[test]
+    fn test_asn1_integer_too_large() {
+        let der = hex!(
+            "3026
+             0221010000000000000000000000000000000000000000000000000000000000000000
+             020101"
+        );
+
+        assert_eq!(
+            Asn1Signature::try_from(&der[..]).unwrap_err(),
+            ParseError::IntegerTooLarge
+        );
+
+        #[allow(deprecated)]
+        let result = Signature::from_asn1(&der);
+        assert!(result.is_err());
+        assert!(Signature::from_der(&der).is_err());
     }
 
-    impl elliptic_curve::weierstrass::Curve for ExampleCurve {
-        const COMPRESS_POINTS: bool = false;
+    #[test]
+    fn test_asn1_signature_ref() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        let der = signature.to_der();
+        let input = der.as_bytes();
+        let view = SignatureRef::<MockCurve>::from_bytes(input).unwrap();
+
+        assert_eq!(view.as_bytes(), input);
+        assert_eq!(view.r(), der.r());
+        assert_eq!(view.s(), der.s());
+        assert_eq!(view.to_fixed(), signature);
+        assert_eq!(Asn1Signature::from(view.clone()).as_bytes(), input);
+
+        // `r` and `s` are borrowed from the input rather than copied
+        let start = input.as_ptr() as usize;
+        let end = start + input.len();
+
+        for component in &[view.r(), view.s()] {
+            let ptr = component.as_ptr() as usize;
+            assert!(start <= ptr && ptr + component.len() <= end);
+        }
+
+        // Rejects the same inputs as the owned signature
+        let mut trailing = [0u8; 80];
+        trailing[..input.len()].copy_from_slice(input);
+        assert_eq!(
+            SignatureRef::<MockCurve>::from_bytes(&trailing[..input.len() + 1]).unwrap_err(),
+            ParseError::TrailingData
+        );
     }
 
-    type Signature = crate::Signature<ExampleCurve>;
+    #[test]
+    fn test_asn1_from_fixed_signature() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
 
-    const EXAMPLE_SIGNATURE: [u8; 64] = [
-        0xf3, 0xac, 0x80, 0x61, 0xb5, 0x14, 0x79, 0x5b, 0x88, 0x43, 0xe3, 0xd6, 0x62, 0x95, 0x27,
-        0xed, 0x2a, 0xfd, 0x6b, 0x1f, 0x6a, 0x55, 0x5a, 0x7a, 0xca, 0xbb, 0x5e, 0x6f, 0x79, 0xc8,
-        0xc2, 0xac, 0x8b, 0xf7, 0x78, 0x19, 0xca, 0x5, 0xa6, 0xb2, 0x78, 0x6c, 0x76, 0x26, 0x2b,
-        0xf7, 0x37, 0x1c, 0xef, 0x97, 0xb2, 0x18, 0xe9, 0x6f, 0x17, 0x5a, 0x3c, 0xcd, 0xda, 0x2a,
-        0xcc, 0x5, 0x89, 0x3,
-    ];
+        #[allow(deprecated)]
+        let expected = signature.to_asn1();
+
+        let der = Asn1Signature::from(signature);
+        assert_eq!(der.as_bytes(), expected.as_bytes());
+
+        let der: Asn1Signature = signature.into();
+        assert_eq!(der.as_bytes(), expected.as_bytes());
+    }
 
     #[test]
-    fn test_fixed_to_asn1_signature_roundtrip() {
-        let signature1 = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+    fn test_asn1_over_length_integer_conversion() {
+        // Bypass the parser to build a document whose `r` or `s` is longer
+        // than the field, which must be rejected rather than panicking
+        let mut bytes = super::DocumentBytes::<MockCurve>::default();
+        bytes.iter_mut().for_each(|byte| *byte = 0x42);
 
-        // Convert to ASN.1 DER and back
-        let asn1_signature = signature1.to_asn1();
-        let signature2 = Signature::from_asn1(asn1_signature.as_ref()).unwrap();
+        for (r_range, s_range) in [(0..33, 33..34), (0..1, 1..34), (0..33, 33..66)]
+            .iter()
+            .cloned()
+        {
+            let doc = Asn1Signature {
+                bytes,
+                r_range,
+                s_range,
+            };
 
-        assert_eq!(signature1, signature2);
+            assert!(Signature::try_from(doc).is_err());
+        }
+    }
+
+    #[test]
+    fn test_asn1_with_r_and_s() {
+        let der = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap().to_der();
+
+        // Clearing the high bit of `r` removes its leading zero
+        let mut r = [0u8; 32];
+        r.copy_from_slice(der.r());
+        r[0] &= 0x7f;
+        let modified = der.with_r(&r).unwrap();
+        assert_eq!(modified.len(), der.len() - 1);
+        assert_eq!(modified.r(), &r[..]);
+        assert_eq!(modified.s(), der.s());
+
+        // The result must still be well-formed DER
+        let reparsed = Asn1Signature::try_from(modified.as_bytes()).unwrap();
+        assert_eq!(reparsed.as_bytes(), modified.as_bytes());
+        assert_eq!(reparsed.r(), &r[..]);
+
+        // Leading zeroes are removed, and short integers are accepted
+        let modified = der.with_s(&[0, 0, 1]).unwrap();
+        assert_eq!(modified.s(), &[1]);
+        assert!(Asn1Signature::try_from(modified.as_bytes()).is_ok());
+
+        let modified = der.with_r(&[0x80]).unwrap().with_s(&[]).unwrap();
+        assert_eq!(modified.as_bytes(), &hex!("3007 02020080 020100")[..]);
+
+        // Integers which would exceed the field size are rejected
+        assert!(der.with_r(&[0x01; 33]).is_err());
+        assert!(der.with_s(&[0xff; 33]).is_err());
+        assert!(der.with_r(&[0x01; 32]).is_ok());
+    }
+
+    #[test]
+    fn test_asn1_trailing_data() {
+        for der in &[&hex!("300602010102010100")[..], &hex!("300702010102010100")] {
+            assert_eq!(
+                Asn1Signature::try_from(*der).unwrap_err(),
+                ParseError::TrailingData
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_canonical() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        assert!(is_canonical(signature.to_der().as_bytes()));
+        assert!(is_canonical(&hex!("3006 020101 020101")));
+        assert!(is_canonical(&hex!("3008 02020080 02020080")));
+
+        for der in &[
+            // Empty input and truncated header
+            &[][..],
+            &hex!("30"),
+            // Wrong tags
+            &hex!("3106 020101 020101"),
+            &hex!("3006 030101 020101"),
+            // SEQUENCE length longer than, and shorter than, the contents
+            &hex!("3007 020101 020101"),
+            &hex!("3005 020101 020101"),
+            // Trailing data after the SEQUENCE
+            &hex!("3006 020101 020101 00"),
+            // Trailing data inside the SEQUENCE
+            &hex!("3007 020101 020101 00"),
+            // Non-minimal SEQUENCE and INTEGER lengths
+            &hex!("308106 020101 020101"),
+            &hex!("3007 02810101 020101"),
+            // Non-minimal integer with an unnecessary leading zero
+            &hex!("3007 02020001 020101"),
+            // Negative integer
+            &hex!("3006 020181 020101"),
+            // Empty integer
+            &hex!("3005 0200 020101"),
+            // Zero-valued r and s
+            &hex!("3006 020100 020101"),
+            &hex!("3006 020101 020100"),
+        ] {
+            assert!(!is_canonical(der), "{:02x?}", der);
+        }
+    }
+
+    #[test]
+    fn test_der_p1363_conversions() {
+        let vectors: &[(&[u8], &[u8])] = &[
+            (
+                &EXAMPLE_SIGNATURE,
+                &hex!(
+                    "3046
+                     022100f3ac8061b514795b8843e3d6629527ed2afd6b1f6a555a7acabb5e6f79c8c2ac
+                     0221008bf77819ca05a6b2786c76262bf7371cef97b218e96f175a3ccdda2acc058903"
+                ),
+            ),
+            // `r` is left-padded with zeroes, so its minimal DER encoding is
+            // shorter than the field, while `s` needs a leading zero
+            (
+                &hex!(
+                    "00007F1111111111111111111111111111111111111111111111111111111111
+                     8022222222222222222222222222222222222222222222222222222222222222"
+                ),
+                &hex!(
+                    "3043
+                     021E7F1111111111111111111111111111111111111111111111111111111111
+                     022100 8022222222222222222222222222222222222222222222222222222222222222"
+                ),
+            ),
+            // Single-byte integers
+            (
+                &hex!(
+                    "0000000000000000000000000000000000000000000000000000000000000001
+                     0000000000000000000000000000000000000000000000000000000000000000"
+                ),
+                &hex!("3006020101020100"),
+            ),
+        ];
+
+        for &(p1363, der) in vectors {
+            assert_eq!(der_to_p1363::<MockCurve>(der).unwrap().as_slice(), p1363);
+            assert_eq!(p1363_to_der::<MockCurve>(p1363).unwrap().as_bytes(), der);
+        }
+
+        assert!(der_to_p1363::<MockCurve>(&EXAMPLE_SIGNATURE).is_err());
+        assert!(p1363_to_der::<MockCurve>(&EXAMPLE_SIGNATURE[..63]).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_asn1_from_reader() {
+        let der = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap().to_der();
+        let mut stream = std::vec::Vec::from(der.as_bytes());
+        stream.extend_from_slice(b"trailer");
+
+        let mut reader = &stream[..];
+        let signature = Asn1Signature::from_reader(&mut reader).unwrap();
+        assert_eq!(signature.as_bytes(), der.as_bytes());
+        assert_eq!(reader, b"trailer");
+
+        let mut reader = &hex!("3006020101020100 3006020102020100")[..];
+        let first = Asn1Signature::from_reader(&mut reader).unwrap();
+        let second = Asn1Signature::from_reader(&mut reader).unwrap();
+        assert_eq!(first.r(), &[1]);
+        assert_eq!(second.r(), &[2]);
+        assert!(reader.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_asn1_from_reader_errors() {
+        fn parse_error(mut der: &[u8]) -> ParseError {
+            let err = Asn1Signature::from_reader(&mut der).unwrap_err();
+            match crate::error::tests::kind(err) {
+                crate::ErrorKind::Asn1(err) => err,
+                other => panic!("unexpected error kind: {:?}", other),
+            }
+        }
+
+        assert_eq!(parse_error(&[]), ParseError::Truncated);
+        assert_eq!(parse_error(&hex!("30")), ParseError::Truncated);
+        assert_eq!(parse_error(&hex!("3081")), ParseError::Truncated);
+        assert_eq!(parse_error(&hex!("30060201010201")), ParseError::Truncated);
+        assert_eq!(
+            parse_error(&hex!("3106020101020100")),
+            ParseError::UnexpectedTag
+        );
+        assert_eq!(parse_error(&hex!("3080")), ParseError::NonCanonicalLength);
+        assert_eq!(parse_error(&hex!("3081ff")), ParseError::TooLong);
+        assert_eq!(parse_error(&hex!("3048")), ParseError::TooLong);
+        assert_eq!(parse_error(&hex!("307f020101")), ParseError::TooLong);
+    }
+
+    /// RFC 6979 A.2.6 signatures of "sample" using P-384 with SHA-384 and
+    /// SHA-256 (the latter having an `r` which doesn't need a leading zero)
+    #[test]
+    fn test_asn1_p384() {
+        type P384Signature = crate::Signature<crate::test_curves::p384::NistP384>;
+
+        let vectors: &[(&[u8], &[u8])] = &[
+            (
+                &hex!(
+                    "94EDBB92A5ECB8AAD4736E56C691916B3F88140666CE9FA73D64C4EA95AD133C
+                     81A648152E44ACF96E36DD1E80FABE46
+                     99EF4AEB15F178CEA1FE40DB2603138F130E740A19624526203B6351D0A3A94F
+                     A329C145786E679E7B82C71A38628AC8"
+                ),
+                &hex!(
+                    "3066
+                     023100
+                     94EDBB92A5ECB8AAD4736E56C691916B3F88140666CE9FA73D64C4EA95AD133C
+                     81A648152E44ACF96E36DD1E80FABE46
+                     023100
+                     99EF4AEB15F178CEA1FE40DB2603138F130E740A19624526203B6351D0A3A94F
+                     A329C145786E679E7B82C71A38628AC8"
+                ),
+            ),
+            (
+                &hex!(
+                    "21B13D1E013C7FA1392D03C5F99AF8B30C570C6F98D4EA8E354B63A21D3DAA33
+                     BDE1E888E63355D92FA2B3C36D8FB2CD
+                     F3AA443FB107745BF4BD77CB3891674632068A10CA67E3D45DB2266FA7D1FEEB
+                     EFDC63ECCD1AC42EC0CB8668A4FA0AB0"
+                ),
+                &hex!(
+                    "3065
+                     0230
+                     21B13D1E013C7FA1392D03C5F99AF8B30C570C6F98D4EA8E354B63A21D3DAA33
+                     BDE1E888E63355D92FA2B3C36D8FB2CD
+                     023100
+                     F3AA443FB107745BF4BD77CB3891674632068A10CA67E3D45DB2266FA7D1FEEB
+                     EFDC63ECCD1AC42EC0CB8668A4FA0AB0"
+                ),
+            ),
+        ];
+
+        for &(fixed, der) in vectors {
+            let signature = P384Signature::from_bytes(fixed).unwrap();
+            assert_eq!(signature.to_der().as_bytes(), der);
+            assert_eq!(P384Signature::from_der(der).unwrap(), signature);
+        }
+
+        // Integers larger than the 48-byte field are rejected
+        let der = hex!(
+            "3066
+             0231
+             0194EDBB92A5ECB8AAD4736E56C691916B3F88140666CE9FA73D64C4EA95AD13
+             3C81A648152E44ACF96E36DD1E80FABE46
+             023100
+             99EF4AEB15F178CEA1FE40DB2603138F130E740A19624526203B6351D0A3A94F
+             A329C145786E679E7B82C71A38628AC8"
+        );
+
+        assert_eq!(
+            super::Signature::<crate::test_curves::p384::NistP384>::try_from(&der[..]).unwrap_err(),
+            ParseError::IntegerTooLarge
+        );
     }
 }
@@ -0,0 +1,182 @@
+//! Base64 encoding support (RFC 4648), used by the PEM and JWS encodings
+
+use crate::{Error, ErrorKind};
+use alloc::{string::String, vec::Vec};
+
+/// Base64 variants
+// Only the variants required by the enabled features are constructed
+#[cfg_attr(not(all(feature = "jws", feature = "pem")), allow(dead_code))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) enum Alphabet {
+    /// Standard alphabet with `=` padding (RFC 4648 §4)
+    Standard,
+
+    /// URL and filename safe alphabet without padding (RFC 4648 §5)
+    UrlSafeNoPad,
+}
+
+impl Alphabet {
+    /// Get the character used to encode the given sextet
+    fn encode_sextet(self, sextet: u8) -> char {
+        let c = match sextet {
+            0..=25 => b'A' + sextet,
+            26..=51 => b'a' + sextet - 26,
+            52..=61 => b'0' + sextet - 52,
+            62 if self == Alphabet::Standard => b'+',
+            62 => b'-',
+            63 if self == Alphabet::Standard => b'/',
+            63 => b'_',
+            _ => unreachable!(),
+        };
+
+        c as char
+    }
+
+    /// Decode a single base64 character
+    fn decode_sextet(self, c: u8) -> Result<u8, Error> {
+        match (c, self) {
+            (b'A'..=b'Z', _) => Ok(c - b'A'),
+            (b'a'..=b'z', _) => Ok(c - b'a' + 26),
+            (b'0'..=b'9', _) => Ok(c - b'0' + 52),
+            (b'+', Alphabet::Standard) | (b'-', Alphabet::UrlSafeNoPad) => Ok(62),
+            (b'/', Alphabet::Standard) | (b'_', Alphabet::UrlSafeNoPad) => Ok(63),
+            _ => Err(ErrorKind::InvalidEncoding.into()),
+        }
+    }
+
+    /// Is the output of this variant padded with `=`?
+    fn is_padded(self) -> bool {
+        self == Alphabet::Standard
+    }
+}
+
+/// Encode bytes as base64 using the given alphabet
+pub(crate) fn encode(bytes: &[u8], alphabet: Alphabet) -> String {
+    let mut out = String::with_capacity(bytes.chunks(3).len() * 4);
+
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).cloned().unwrap_or(0),
+            chunk.get(2).cloned().unwrap_or(0),
+        ];
+
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(alphabet.encode_sextet((n >> (18 - 6 * i) & 0x3f) as u8));
+            } else if alphabet.is_padded() {
+                out.push('=');
+            }
+        }
+    }
+
+    out
+}
+
+/// Decode base64 using the given alphabet, rejecting non-canonical encodings
+/// (i.e. incorrect padding, or nonzero unused bits in the final character)
+pub(crate) fn decode(base64: &[u8], alphabet: Alphabet) -> Result<Vec<u8>, Error> {
+    let padding = base64.iter().rev().take_while(|&&c| c == b'=').count();
+    let data = &base64[..base64.len() - padding];
+    let remainder = data.chunks(4).last().map(<[u8]>::len).unwrap_or(0);
+
+    let padding_ok = if alphabet.is_padded() {
+        padding == (4 - remainder) % 4
+    } else {
+        padding == 0
+    };
+
+    if data.is_empty() || remainder == 1 || !padding_ok {
+        return Err(ErrorKind::InvalidEncoding.into());
+    }
+
+    let mut out = Vec::with_capacity(data.chunks(4).len() * 3);
+
+    for chunk in data.chunks(4) {
+        let mut n = 0u32;
+
+        for &c in chunk {
+            n = (n << 6) | u32::from(alphabet.decode_sextet(c)?);
+        }
+
+        n <<= 6 * (4 - chunk.len()) as u32;
+        let bytes = [(n >> 16) as u8, (n >> 8) as u8, n as u8];
+        let len = chunk.len() - 1;
+
+        // Unused bits in the final sextet must be zero
+        if bytes[len..].iter().any(|&byte| byte != 0) {
+            return Err(ErrorKind::InvalidEncoding.into());
+        }
+
+        out.extend_from_slice(&bytes[..len]);
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode, Alphabet};
+
+    /// RFC 4648 §10 test vectors
+    const VECTORS: &[(&[u8], &str)] = &[
+        (b"f", "Zg=="),
+        (b"fo", "Zm8="),
+        (b"foo", "Zm9v"),
+        (b"foob", "Zm9vYg=="),
+        (b"fooba", "Zm9vYmE="),
+        (b"foobar", "Zm9vYmFy"),
+    ];
+
+    #[test]
+    fn standard_round_trip() {
+        for &(bytes, base64) in VECTORS {
+            assert_eq!(encode(bytes, Alphabet::Standard), base64);
+            assert_eq!(
+                decode(base64.as_bytes(), Alphabet::Standard).unwrap(),
+                bytes
+            );
+        }
+    }
+
+    #[test]
+    fn url_safe_round_trip() {
+        for &(bytes, base64) in VECTORS {
+            let base64 = base64.trim_end_matches('=');
+            assert_eq!(encode(bytes, Alphabet::UrlSafeNoPad), base64);
+            assert_eq!(
+                decode(base64.as_bytes(), Alphabet::UrlSafeNoPad).unwrap(),
+                bytes
+            );
+        }
+
+        assert_eq!(encode(&[0xfb, 0xff], Alphabet::UrlSafeNoPad), "-_8");
+        assert_eq!(encode(&[0xfb, 0xff], Alphabet::Standard), "+/8=");
+    }
+
+    #[test]
+    fn reject_invalid_standard() {
+        for base64 in &[
+            "Zg=", "Zg", "Zg!=", "Z===", "Zg==Zm9v", "Zh==", "Zm9=", "-_8=", "",
+        ] {
+            assert!(
+                decode(base64.as_bytes(), Alphabet::Standard).is_err(),
+                "{}",
+                base64
+            );
+        }
+    }
+
+    #[test]
+    fn reject_invalid_url_safe() {
+        for base64 in &["Zg==", "Zm8=", "Z", "Zh", "Zm9", "+/8", ""] {
+            assert!(
+                decode(base64.as_bytes(), Alphabet::UrlSafeNoPad).is_err(),
+                "{}",
+                base64
+            );
+        }
+    }
+}
@@ -3,6 +3,234 @@
 // TODO(tarcieri): implement full set of tests from ECDSA2VS
 // <https://csrc.nist.gov/CSRC/media/Projects/Cryptographic-Algorithm-Validation-Program/documents/dss2/ecdsa2vs.pdf>
 
+use elliptic_curve::{consts::U32, Curve, ElementBytes};
+
+/// Define a mock curve with the given field size and group order.
+///
+/// Its scalar type is range checked against the order and supports "low S"
+/// normalization, which is sufficient for exercising signature framing,
+/// ASN.1 DER, and normalization. Point arithmetic is NOT implemented: all
+/// operations on its point type return the placeholder.
+macro_rules! impl_mock_curve {
+    (
+        $(#[$attr:meta])*
+        pub struct $curve:ident {
+            size: $size:ty,
+            order: $order:expr,
+            scalar: $scalar:ident,
+            point: $point:ident,
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+        pub struct $curve;
+
+        impl $curve {
+            /// Create a signature with the given `r` and `s` values, which are
+            /// big endian integers left-padded with zeroes to the field size.
+            ///
+            /// Panics if either value is larger than the field size.
+            pub fn signature(r: &[u8], s: &[u8]) -> $crate::Signature<$curve> {
+                $crate::Signature::from_scalars(
+                    &$crate::dev::pad::<$curve>(r),
+                    &$crate::dev::pad::<$curve>(s),
+                )
+            }
+        }
+
+        impl elliptic_curve::Curve for $curve {
+            type ElementSize = $size;
+        }
+
+        impl elliptic_curve::weierstrass::Curve for $curve {
+            const COMPRESS_POINTS: bool = false;
+        }
+
+        impl $crate::CurveOrder for $curve {
+            fn order() -> elliptic_curve::ElementBytes<$curve> {
+                elliptic_curve::ElementBytes::<$curve>::clone_from_slice(&$order)
+            }
+        }
+
+        impl elliptic_curve::Arithmetic for $curve {
+            type Scalar = $scalar;
+            type AffinePoint = $point;
+        }
+
+        /// Scalar of the mock curve, i.e. a big endian integer less than its
+        /// group order
+        #[derive(Clone, Copy, Debug, Default)]
+        pub struct $scalar(elliptic_curve::ElementBytes<$curve>);
+
+        impl $scalar {
+            /// Compute `n - self`
+            fn negate(&self) -> Self {
+                let mut result = elliptic_curve::ElementBytes::<$curve>::default();
+                let mut borrow = 0i16;
+
+                for i in (0..result.len()).rev() {
+                    let diff = i16::from($order[i]) - i16::from(self.0[i]) - borrow;
+                    borrow = if diff < 0 { 1 } else { 0 };
+                    result[i] = (diff + (borrow << 8)) as u8;
+                }
+
+                $scalar(result)
+            }
+        }
+
+        impl elliptic_curve::subtle::ConditionallySelectable for $scalar {
+            fn conditional_select(
+                a: &Self,
+                b: &Self,
+                choice: elliptic_curve::subtle::Choice,
+            ) -> Self {
+                let mut bytes = elliptic_curve::ElementBytes::<$curve>::default();
+
+                for (i, byte) in bytes.iter_mut().enumerate() {
+                    *byte = elliptic_curve::subtle::ConditionallySelectable::conditional_select(
+                        &a.0[i], &b.0[i], choice,
+                    );
+                }
+
+                $scalar(bytes)
+            }
+        }
+
+        impl elliptic_curve::subtle::ConstantTimeEq for $scalar {
+            fn ct_eq(&self, other: &Self) -> elliptic_curve::subtle::Choice {
+                elliptic_curve::subtle::ConstantTimeEq::ct_eq(self.0.as_slice(), other.0.as_slice())
+            }
+        }
+
+        impl elliptic_curve::FromBytes for $scalar {
+            type Size = $size;
+
+            fn from_bytes(
+                bytes: &elliptic_curve::ElementBytes<$curve>,
+            ) -> elliptic_curve::subtle::CtOption<Self> {
+                let in_range = bytes.as_slice() < &$order[..];
+                elliptic_curve::subtle::CtOption::new(
+                    $scalar(*bytes),
+                    elliptic_curve::subtle::Choice::from(in_range as u8),
+                )
+            }
+        }
+
+        impl From<$scalar> for elliptic_curve::ElementBytes<$curve> {
+            fn from(scalar: $scalar) -> elliptic_curve::ElementBytes<$curve> {
+                scalar.0
+            }
+        }
+
+        impl $crate::NormalizeLow for $scalar {
+            fn normalize_low(&self) -> (Self, bool) {
+                let negated = self.negate();
+
+                // `self` is high iff it's larger than `n - self`
+                if self.0 > negated.0 {
+                    (negated, true)
+                } else {
+                    (*self, false)
+                }
+            }
+        }
+
+        /// Placeholder point type for the mock curve.
+        ///
+        /// Point arithmetic is not supported: all operations return the
+        /// placeholder.
+        #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+        pub struct $point;
+
+        impl elliptic_curve::subtle::ConditionallySelectable for $point {
+            fn conditional_select(_: &Self, _: &Self, _: elliptic_curve::subtle::Choice) -> Self {
+                $point
+            }
+        }
+
+        impl elliptic_curve::point::Generator for $point {
+            fn generator() -> Self {
+                $point
+            }
+        }
+
+        impl core::ops::Mul<elliptic_curve::scalar::NonZeroScalar<$curve>> for $point {
+            type Output = $point;
+
+            fn mul(self, _: elliptic_curve::scalar::NonZeroScalar<$curve>) -> $point {
+                $point
+            }
+        }
+    };
+}
+
+/// Order of the [`MockCurve`] group (the same as that of NIST P-256)
+pub const MOCK_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0xbc, 0xe6, 0xfa, 0xad, 0xa7, 0x17, 0x9e, 0x84, 0xf3, 0xb9, 0xca, 0xc2, 0xfc, 0x63, 0x25, 0x51,
+];
+
+impl_mock_curve! {
+    /// Mock elliptic curve with a 256-bit field, for testing code which is
+    /// generic over curves without depending on a real curve implementation.
+    ///
+    /// Its [`MockScalar`] type is range checked against [`MOCK_ORDER`] and
+    /// supports "low S" normalization, which is sufficient for exercising
+    /// signature framing, ASN.1 DER, and normalization. Point arithmetic is NOT
+    /// implemented: see [`MockAffinePoint`].
+    pub struct MockCurve {
+        size: U32,
+        order: MOCK_ORDER,
+        scalar: MockScalar,
+        point: MockAffinePoint,
+    }
+}
+
+/// Left-pad the given big endian integer with zeroes to the field size
+pub(crate) fn pad<C: Curve>(integer: &[u8]) -> ElementBytes<C> {
+    let mut bytes = ElementBytes::<C>::default();
+    let offset = bytes
+        .len()
+        .checked_sub(integer.len())
+        .expect("integer larger than field size");
+
+    bytes[offset..].copy_from_slice(integer);
+    bytes
+}
+
+/// Mock NIST P-521 curve, for testing generic code with a curve whose order
+/// is not a multiple of 8 bits long.
+///
+/// Field elements are 66 bytes (528 bits), but the order [`p521::ORDER`] is
+/// 521 bits, so the top 7 bits of every valid scalar are zero. Like
+/// [`MockCurve`], scalars are range checked and support "low S"
+/// normalization, but point arithmetic is NOT implemented.
+pub mod p521 {
+    use elliptic_curve::consts::U66;
+
+    /// Order of the NIST P-521 group
+    pub const ORDER: [u8; 66] = [
+        0x01, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xfa, 0x51, 0x86, 0x87, 0x83, 0xbf, 0x2f, 0x96, 0x6b, 0x7f, 0xcc, 0x01,
+        0x48, 0xf7, 0x09, 0xa5, 0xd0, 0x3b, 0xb5, 0xc9, 0xb8, 0x89, 0x9c, 0x47, 0xae, 0xbb, 0x6f,
+        0xb7, 0x1e, 0x91, 0x38, 0x64, 0x09,
+    ];
+
+    /// Signature over the [`MockP521`] curve
+    pub type Signature = crate::Signature<MockP521>;
+
+    impl_mock_curve! {
+        /// Mock NIST P-521 curve type
+        pub struct MockP521 {
+            size: U66,
+            order: ORDER,
+            scalar: Scalar,
+            point: AffinePoint,
+        }
+    }
+}
+
 /// ECDSA test vector
 pub struct TestVector {
     /// Private scalar
@@ -100,3 +328,311 @@ macro_rules! new_verification_test {
         // TODO(tarcieri): test invalid Q, invalid r, invalid m
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        p521::{self, MockP521},
+        MockCurve, MockScalar, MOCK_ORDER,
+    };
+    use crate::{
+        asn1::{der_to_p1363, p1363_to_der},
+        CurveOrder, Signature,
+    };
+    use elliptic_curve::{ElementBytes, FromBytes};
+    use hex_literal::hex;
+    use proptest::prelude::*;
+    use std::format;
+
+    /// RFC 6979 A.2.5 signature of "sample" using P-256 and SHA-256
+    const SIGNATURE: [u8; 64] = hex!(
+        "EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716
+         F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8"
+    );
+
+    #[test]
+    fn signature_helper() {
+        let signature = MockCurve::signature(&[1], &[2, 3]);
+        assert_eq!(signature.r()[..31], [0; 31]);
+        assert_eq!(signature.r()[31], 1);
+        assert_eq!(signature.s()[30..], [2, 3]);
+        assert_eq!(
+            MockCurve::signature(&SIGNATURE[..32], &SIGNATURE[32..]).as_ref(),
+            &SIGNATURE[..]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn signature_helper_too_large() {
+        MockCurve::signature(&[0; 33], &[1]);
+    }
+
+    #[test]
+    fn normalize_s() {
+        let mut signature = MockCurve::signature(&SIGNATURE[..32], &SIGNATURE[32..]);
+        assert!(!signature.is_low_s().unwrap());
+        assert!(signature.normalize_s().unwrap());
+        assert_eq!(
+            signature.s().as_slice(),
+            hex!("0834E36AD29A83BF2BC9385E491D6099C8FDF9D1ED67AA7EA5F51F93782857A9")
+        );
+        assert!(signature.is_low_s().unwrap());
+        assert!(!signature.normalize_s().unwrap());
+    }
+
+    #[test]
+    fn scalar_range() {
+        let mut n_minus_one = MOCK_ORDER;
+        n_minus_one[31] -= 1;
+
+        assert!(MockCurve::signature(&[1], &n_minus_one)
+            .split_scalars()
+            .is_ok());
+        assert!(MockCurve::signature(&[1], &MOCK_ORDER)
+            .split_scalars()
+            .is_err());
+    }
+
+    /// P-521 signature of "sample" using SHA-512, from RFC 6979 A.2.7
+    const P521_SIGNATURE: [u8; 132] = hex!(
+        "00C328FAFCBD79DD77850370C46325D987CB525569FB63C5D3BC53950E6D4C5F174E
+         25A1EE9017B5D450606ADD152B534931D7D4E8455CC91F9B15BF05EC36E377FA
+         00617CCE7CF5064806C467F678D3B4080D6F1CC50AF26CA209417308281B68AF2826
+         23EAA63E5B5C0723D8B8C37FF0777B1A20F8CCB1DCCC43997F1EE0E44DA4A67A"
+    );
+
+    /// ASN.1 DER encoding of [`P521_SIGNATURE`]
+    const P521_SIGNATURE_DER: [u8; 138] = hex!(
+        "308187
+         0242
+         00C328FAFCBD79DD77850370C46325D987CB525569FB63C5D3BC53950E6D4C5F174E
+         25A1EE9017B5D450606ADD152B534931D7D4E8455CC91F9B15BF05EC36E377FA
+         0241
+         617CCE7CF5064806C467F678D3B4080D6F1CC50AF26CA209417308281B68AF2826
+         23EAA63E5B5C0723D8B8C37FF0777B1A20F8CCB1DCCC43997F1EE0E44DA4A67A"
+    );
+
+    #[test]
+    fn p521_order_bits() {
+        assert_eq!(MockP521::order_bits(), 521);
+        assert_eq!(MockCurve::order_bits(), 256);
+    }
+
+    #[test]
+    fn p521_der_roundtrip() {
+        let signature = p521::Signature::from_p1363(&P521_SIGNATURE).unwrap();
+        assert_eq!(signature.r().as_slice(), &P521_SIGNATURE[..66]);
+        assert_eq!(signature.s().as_slice(), &P521_SIGNATURE[66..]);
+        assert_eq!(signature.to_der().as_bytes(), &P521_SIGNATURE_DER[..]);
+        assert_eq!(
+            p521::Signature::from_der(&P521_SIGNATURE_DER).unwrap(),
+            signature
+        );
+        assert_eq!(
+            MockP521::signature(&P521_SIGNATURE[1..66], &P521_SIGNATURE[66..]),
+            signature
+        );
+    }
+
+    #[test]
+    fn p521_scalar_range() {
+        let mut n_minus_one = p521::ORDER;
+        n_minus_one[65] -= 1;
+        assert!(MockP521::signature(&[1], &n_minus_one)
+            .split_scalars()
+            .is_ok());
+        assert!(MockP521::signature(&[1], &p521::ORDER)
+            .split_scalars()
+            .is_err());
+
+        // Fits in the 66-byte field, but exceeds 521 bits
+        let mut too_wide = [0u8; 66];
+        too_wide[0] = 0x02;
+        assert!(MockP521::signature(&too_wide, &[1])
+            .split_scalars()
+            .is_err());
+    }
+
+    #[test]
+    fn p521_normalize_s() {
+        // The RFC 6979 signature is already low S
+        let mut signature = p521::Signature::from_p1363(&P521_SIGNATURE).unwrap();
+        assert!(signature.is_low_s().unwrap());
+        assert!(!signature.normalize_s().unwrap());
+        assert_eq!(signature.as_ref(), &P521_SIGNATURE[..]);
+
+        // n - s
+        let high_s = hex!(
+            "019E8331830AF9B7F93B9809872C4BF7F290E33AF50D935DF6BE8CF7D7E49750D7D4
+             2D9BE14563D38F47A7133DC906922AB61ABCFD06ACD004153C50D63A4393BD8F"
+        );
+        let mut signature = MockP521::signature(&P521_SIGNATURE[..66], &high_s);
+        assert!(!signature.is_low_s().unwrap());
+        assert!(signature.normalize_s().unwrap());
+        assert_eq!(signature.as_ref(), &P521_SIGNATURE[..]);
+    }
+
+    #[cfg(feature = "hazmat")]
+    #[test]
+    fn p521_bits2int() {
+        use crate::hazmat::{bits2field, bits2int};
+
+        let bits = MockP521::order_bits();
+        let mut digest = [0u8; 66];
+
+        for (i, byte) in digest.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+
+        // A 66-byte digest is truncated to its leftmost 521 bits, i.e. shifted
+        // right by 7 bits, rather than used in full as a 528-bit integer
+        let expected = hex!(
+            "0000020406080A0C0E10121416181A1C1E20222426282A2C2E30323436383A3C3E40
+             424446484A4C4E50525456585A5C5E60626466686A6C6E70727476787A7C7E80"
+        );
+        assert_eq!(
+            bits2int::<MockP521>(&digest, bits).as_slice(),
+            &expected[..]
+        );
+        assert_eq!(bits2field::<MockP521>(&digest).as_slice(), &digest[..]);
+
+        let mut expected = [0xFF; 66];
+        expected[0] = 0x01;
+        assert_eq!(
+            bits2int::<MockP521>(&[0xFF; 66], bits).as_slice(),
+            &expected[..]
+        );
+
+        // Digests shorter than the order (e.g. SHA-512) are left-padded
+        let sha512 = hex!(
+            "39A5E04AAFF7455D9850C605364F514C11324CE64016960D23D5DC57D3FFD8F4
+             9A739468AB8049BF18EEF820CDB1AD6C9015F838556BC7FAD4138B23FDF986C7"
+        );
+        let int = bits2int::<MockP521>(&sha512, bits);
+        assert_eq!(int[..2], [0, 0]);
+        assert_eq!(&int[2..], &sha512[..]);
+    }
+
+    /// Strategy generating nonzero scalars less than [`MOCK_ORDER`],
+    /// covering field-width values with and without the high bit set as well
+    /// as shorter values whose DER encodings are minimal-length
+    fn scalar() -> impl Strategy<Value = ElementBytes<MockCurve>> {
+        let leading_zeroes = prop_oneof![Just(0), 1..32usize];
+
+        (any::<[u8; 32]>(), leading_zeroes, any::<bool>()).prop_filter_map(
+            "scalar is zero or not less than the order",
+            |(bytes, leading_zeroes, high_bit)| {
+                let mut bytes = ElementBytes::<MockCurve>::clone_from_slice(&bytes);
+
+                for byte in &mut bytes[..leading_zeroes] {
+                    *byte = 0;
+                }
+
+                // Force the high bit of the first nonzero byte on or off
+                if let Some(first) = bytes.iter_mut().find(|byte| **byte != 0) {
+                    if high_bit {
+                        *first |= 0x80;
+                    } else {
+                        *first &= 0x7f;
+                    }
+                }
+
+                if bytes.iter().any(|&byte| byte != 0) && bytes.as_slice() < &MOCK_ORDER[..] {
+                    Some(bytes)
+                } else {
+                    None
+                }
+            },
+        )
+    }
+
+    /// Strip the leading zeroes from a big endian integer
+    fn strip_leading_zeroes(bytes: &[u8]) -> &[u8] {
+        let leading_zeroes = bytes.iter().take_while(|&&byte| byte == 0).count();
+        &bytes[leading_zeroes..]
+    }
+
+    proptest! {
+        #[test]
+        fn from_scalars_roundtrip(r in scalar(), s in scalar()) {
+            let signature = Signature::<MockCurve>::from_scalars(&r, &s);
+            prop_assert_eq!(signature.r(), &r);
+            prop_assert_eq!(signature.s(), &s);
+            prop_assert_eq!(
+                Signature::<MockCurve>::from((signature.r(), signature.s())),
+                signature
+            );
+            prop_assert!(signature.split_scalars().is_ok());
+        }
+
+        #[test]
+        fn der_roundtrip(r in scalar(), s in scalar()) {
+            let signature = Signature::<MockCurve>::from_scalars(&r, &s);
+            let der = signature.to_der();
+            let decoded = Signature::<MockCurve>::from_der(der.as_bytes()).unwrap();
+
+            prop_assert_eq!(decoded, signature);
+            let reencoded = decoded.to_der();
+            prop_assert_eq!(reencoded.as_bytes(), der.as_bytes());
+
+            // Integers are minimal, and padded only to clear the sign bit
+            for (value, encoded, encoded_len) in
+                &[(&r, der.r(), der.r_len()), (&s, der.s(), der.s_len())]
+            {
+                let minimal = strip_leading_zeroes(value);
+                prop_assert_eq!(*encoded, minimal);
+                prop_assert_eq!(*encoded_len, minimal.len() + (minimal[0] >> 7) as usize);
+            }
+
+            prop_assert_eq!(der.total_len(), der.as_bytes().len());
+        }
+
+        #[test]
+        #[allow(deprecated)]
+        fn asn1_roundtrip(r in scalar(), s in scalar()) {
+            let signature = Signature::<MockCurve>::from_scalars(&r, &s);
+            let asn1 = signature.to_asn1();
+            let der = signature.to_der();
+
+            prop_assert_eq!(asn1.as_bytes(), der.as_bytes());
+            prop_assert_eq!(
+                Signature::<MockCurve>::from_asn1(asn1.as_bytes()).unwrap(),
+                signature
+            );
+        }
+
+        #[test]
+        fn der_p1363_conversion_roundtrip(r in scalar(), s in scalar()) {
+            let signature = Signature::<MockCurve>::from_scalars(&r, &s);
+            let der = p1363_to_der::<MockCurve>(signature.as_ref()).unwrap();
+            let p1363 = der_to_p1363::<MockCurve>(der.as_bytes()).unwrap();
+            let expected = signature.to_der();
+
+            prop_assert_eq!(der.as_bytes(), expected.as_bytes());
+            prop_assert_eq!(p1363.as_slice(), signature.as_ref());
+        }
+
+        #[test]
+        fn normalize_s_roundtrip(r in scalar(), s in scalar()) {
+            let signature = Signature::<MockCurve>::from_scalars(&r, &s);
+            let was_low = signature.is_low_s().unwrap();
+
+            let mut normalized = signature;
+            prop_assert_eq!(normalized.normalize_s().unwrap(), !was_low);
+            prop_assert_eq!(normalized.r(), signature.r());
+            prop_assert!(normalized.is_low_s().unwrap());
+
+            // A high `s` is replaced by `n - s`
+            if !was_low {
+                let s = MockScalar::from_bytes(normalized.s()).unwrap().negate();
+                prop_assert_eq!(ElementBytes::<MockCurve>::from(s), *signature.s());
+            }
+
+            // Normalizing is idempotent
+            let mut renormalized = normalized;
+            prop_assert!(!renormalized.normalize_s().unwrap());
+            prop_assert_eq!(renormalized, normalized);
+        }
+    }
+}
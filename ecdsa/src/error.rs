@@ -0,0 +1,136 @@
+//! Error kinds describing why an ECDSA operation failed.
+
+use crate::{asn1, Error};
+use core::fmt;
+
+/// Kinds of errors which can occur in this crate.
+///
+/// All errors are returned as an opaque [`signature::Error`]. When the `std`
+/// feature is enabled, the `ErrorKind` describing the failure is attached as
+/// the error's `source()`, from which it can be retrieved using
+/// `downcast_ref::<ErrorKind>()`. Without `std` no details are retained.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Input has the wrong length
+    InvalidLength,
+
+    /// Input is not validly encoded (e.g. contains non-hexadecimal digits)
+    InvalidEncoding,
+
+    /// Error parsing an ASN.1 DER-encoded signature
+    Asn1(asn1::ParseError),
+
+    /// Scalar value is not less than the order of the curve
+    ScalarOutOfRange,
+
+    /// Scalar value is zero
+    ZeroScalar,
+
+    /// Point is not on the curve, or is the point at infinity
+    InvalidPoint,
+
+    /// Recovery ID is out of range, or doesn't identify a valid point
+    InvalidRecoveryId,
+
+    /// Signature is not valid for the given public key and message
+    VerificationFailed,
+
+    /// Signature is valid, but its `s` component is not in "low S" form, so
+    /// it was rejected as malleable
+    HighS,
+
+    /// An RNG is required to sign non-deterministically or with blinding, but
+    /// none was given
+    RngRequired,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidLength => f.write_str("invalid length"),
+            ErrorKind::InvalidEncoding => f.write_str("invalid encoding"),
+            ErrorKind::Asn1(err) => write!(f, "ASN.1 error: {}", err),
+            ErrorKind::ScalarOutOfRange => f.write_str("scalar out of range"),
+            ErrorKind::ZeroScalar => f.write_str("scalar is zero"),
+            ErrorKind::InvalidPoint => f.write_str("invalid curve point"),
+            ErrorKind::InvalidRecoveryId => f.write_str("invalid recovery ID"),
+            ErrorKind::VerificationFailed => f.write_str("verification failed"),
+            ErrorKind::HighS => f.write_str("signature is not in low-S form"),
+            ErrorKind::RngRequired => {
+                f.write_str("RNG required for non-deterministic or blinded signing")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ErrorKind {}
+
+impl From<ErrorKind> for Error {
+    #[cfg(feature = "std")]
+    fn from(kind: ErrorKind) -> Error {
+        Error::from_source(kind)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn from(_: ErrorKind) -> Error {
+        Error::new()
+    }
+}
+
+impl From<asn1::ParseError> for ErrorKind {
+    fn from(err: asn1::ParseError) -> ErrorKind {
+        ErrorKind::Asn1(err)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+pub(crate) mod tests {
+    use super::ErrorKind;
+    use crate::{asn1, Error, Signature};
+    use core::{convert::TryFrom, str::FromStr};
+    use p256::NistP256;
+    use std::error::Error as _;
+
+    type P256Signature = Signature<NistP256>;
+
+    /// Extract the [`ErrorKind`] attached to the given error
+    pub(crate) fn kind(err: Error) -> ErrorKind {
+        *err.source()
+            .and_then(|source| source.downcast_ref::<ErrorKind>())
+            .expect("error has no ErrorKind source")
+    }
+
+    #[test]
+    fn invalid_length() {
+        let err = P256Signature::try_from(&[0u8; 63][..]).unwrap_err();
+        assert_eq!(kind(err), ErrorKind::InvalidLength);
+    }
+
+    #[test]
+    fn invalid_encoding() {
+        let err = P256Signature::from_str(&"zz".repeat(64)).unwrap_err();
+        assert_eq!(kind(err), ErrorKind::InvalidEncoding);
+    }
+
+    #[test]
+    fn asn1_error() {
+        let err = P256Signature::from_der(&[0x31, 0x00]).unwrap_err();
+        assert_eq!(kind(err), ErrorKind::Asn1(asn1::ParseError::UnexpectedTag));
+    }
+
+    #[test]
+    fn scalar_out_of_range() {
+        let signature = P256Signature::try_from(&[0xffu8; 64][..]).unwrap();
+        let err = signature.split_scalars().unwrap_err();
+        assert_eq!(kind(err), ErrorKind::ScalarOutOfRange);
+    }
+
+    #[test]
+    fn zero_scalar() {
+        let signature = P256Signature::try_from(&[0u8; 64][..]).unwrap();
+        let err = signature.split_scalars().unwrap_err();
+        assert_eq!(kind(err), ErrorKind::ZeroScalar);
+    }
+}
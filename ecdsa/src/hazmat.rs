@@ -11,16 +11,47 @@
 //! Failure to use them correctly can lead to catastrophic failures including
 //! FULL PRIVATE KEY RECOVERY!
 
-use crate::{Signature, SignatureSize};
+use crate::{CurveOrder, ErrorKind, Signature, SignatureSize};
 use core::borrow::Borrow;
 use elliptic_curve::{
-    generic_array::ArrayLength, ops::Invert, weierstrass::Curve, Arithmetic, ElementBytes,
+    generic_array::{typenum::Unsigned, ArrayLength},
+    ops::Invert,
+    point::Generator,
+    subtle::{ConstantTimeEq, CtOption},
+    weierstrass::Curve,
+    Arithmetic, ElementBytes, FromBytes,
 };
 use signature::Error;
 
 #[cfg(feature = "digest")]
 use signature::{digest::Digest, PrehashSignature};
 
+#[cfg(feature = "rfc6979")]
+use {
+    crate::rfc6979::HmacDrbg,
+    elliptic_curve::zeroize::Zeroizing,
+    hmac::digest::{BlockInput, FixedOutput, Reset, Update},
+};
+
+#[cfg(feature = "zeroize")]
+use elliptic_curve::zeroize::Zeroize;
+
+#[cfg(feature = "recoverable")]
+use {
+    crate::recoverable::{self, RecoveryId},
+    core::ops::Add,
+    elliptic_curve::{
+        consts::U1,
+        weierstrass::{
+            point::{CompressedPointSize, UncompressedPoint, UncompressedPointSize},
+            public_key::FromPublicKey,
+        },
+    },
+};
+
+#[cfg(all(feature = "rand", feature = "rfc6979"))]
+use elliptic_curve::rand_core::{CryptoRng, RngCore};
+
 /// Try to sign the given prehashed message using ECDSA.
 ///
 /// This trait is intended to be implemented on a type with access
@@ -108,6 +139,992 @@ where
     ) -> Result<(), Error>;
 }
 
+/// Scalar and point arithmetic needed by the generic ECDSA functionality in
+/// this crate.
+///
+/// The [`Arithmetic`] trait only provides a curve's `Scalar` and
+/// `AffinePoint` types, along with multiplication of a point by a non-zero
+/// scalar. This trait supplies the remaining operations on those types, and
+/// is intended to be impl'd on the curve type by crates which provide curve
+/// arithmetic (e.g. `p256::NistP256`).
+///
+/// Implementations MUST be constant time with respect to scalars.
+///
+/// # Curve support
+///
+/// No released curve crate implements this trait yet: `p256` 0.4 and `k256`
+/// 0.4, the releases built on the same `elliptic-curve` version as this
+/// crate, predate it. As it's defined here, the orphan rule also prevents
+/// crates other than the curve crate itself from impl'ing it on the curve
+/// crate's types.
+///
+/// Until curve crates adopt it, code using those crates can instead define
+/// its own curve type wrapping the curve crate's arithmetic, with newtypes
+/// for its `Scalar` and `AffinePoint` that delegate to the wrapped types.
+/// This crate's benchmarks do exactly this for `p256` and `k256`: see
+/// `benches/common/mod.rs` in the source repository.
+///
+/// # Implementing this trait
+///
+/// Curve crates can impl this trait on their curve type by delegating to the
+/// arithmetic they already provide on their own `Scalar` and projective
+/// point types, converting to and from affine coordinates at the
+/// boundaries. The curve type must also impl [`CurveOrder`], which is used
+/// to truncate digests to the bit length of the order (see [`bits2int`]).
+/// For example, inside a curve crate which defines `NistP256`, `Scalar`,
+/// `AffinePoint` and `ProjectivePoint`:
+///
+/// ```ignore
+/// impl ecdsa::hazmat::CurveArithmetic for NistP256 {
+///     fn add_scalars(a: &Scalar, b: &Scalar) -> Scalar {
+///         *a + b
+///     }
+///
+///     fn mul_point(point: &AffinePoint, k: &Scalar) -> CtOption<AffinePoint> {
+///         (ProjectivePoint::from(*point) * k).to_affine()
+///     }
+///
+///     type ProjectivePoint = ProjectivePoint;
+///
+///     fn add_projective(a: &ProjectivePoint, b: &ProjectivePoint) -> ProjectivePoint {
+///         *a + b
+///     }
+///
+///     // ...and so on for the remaining operations
+/// }
+/// ```
+///
+/// With this in place, the curve's `Scalar` type can impl [`SignPrimitive`]
+/// by calling [`sign_prehashed`], and its `AffinePoint` type can impl
+/// [`VerifyPrimitive`] by calling [`verify_prehashed`], rather than each
+/// curve crate reimplementing the ECDSA equations.
+///
+/// # APIs requiring this trait
+///
+/// Besides the functions in this module, the following require this trait:
+///
+/// - `SigningKey`'s `Signer`, `DigestSigner` and `PrehashSigner` impls, as
+///   well as `SigningKey::sign_with_options` and `SigningKey::signing_stream`;
+/// - `Signer::sign_with_options` and the `DigestSigner`, `PrehashSigner` and
+///   `SigningStream` APIs in the `signer` module;
+/// - `verifier::validate_public_key` and `verifier::add_public_keys`;
+/// - `Signature::normalize_s_ct` and `Signature::verify_recovering`;
+/// - public key recovery from `recoverable::Signature`s;
+/// - batch verification.
+///
+/// The remaining `Signer` and `Verifier` APIs only need [`Arithmetic`] along
+/// with [`SignPrimitive`] and [`VerifyPrimitive`] impls.
+pub trait CurveArithmetic: Curve + Arithmetic + CurveOrder {
+    /// Cofactor `h` of the curve, i.e. the number of points on the curve
+    /// divided by the order `n` of the subgroup generated by the base point.
+    ///
+    /// Public keys on curves with a cofactor larger than 1 are checked to be
+    /// in this subgroup when verifying (see [`check_subgroup`]), to prevent
+    /// small-subgroup attacks. Prime-order curves such as P-256 and secp256k1
+    /// use the default of 1, which skips the check.
+    const COFACTOR: u64 = 1;
+
+    /// Compute `a + b (mod n)`
+    fn add_scalars(a: &Self::Scalar, b: &Self::Scalar) -> Self::Scalar;
+
+    /// Compute `a × b (mod n)`
+    fn mul_scalars(a: &Self::Scalar, b: &Self::Scalar) -> Self::Scalar;
+
+    /// Compute `-a (mod n)`
+    fn negate_scalar(a: &Self::Scalar) -> Self::Scalar;
+
+    /// Compute the multiplicative inverse of `a`, or none if `a` is zero.
+    ///
+    /// Like the other operations, this MUST be constant time, e.g. computing
+    /// `a^(n-2)` using Fermat's little theorem with a fixed addition chain,
+    /// rather than using a variable-time algorithm such as the extended
+    /// Euclidean algorithm. Signing doesn't rely on it to invert the secret
+    /// ephemeral scalar `k`, instead using [`invert_scalar_ct`].
+    fn invert_scalar(a: &Self::Scalar) -> CtOption<Self::Scalar>;
+
+    /// Reduce a big endian serialized integer modulo the curve's order `n`
+    fn reduce_scalar(bytes: &ElementBytes<Self>) -> Self::Scalar;
+
+    /// Compute `a + b`, or none if the result is the point at infinity
+    fn add_points(a: &Self::AffinePoint, b: &Self::AffinePoint) -> CtOption<Self::AffinePoint>;
+
+    /// Compute `k×P`, or none if the result is the point at infinity
+    fn mul_point(point: &Self::AffinePoint, k: &Self::Scalar) -> CtOption<Self::AffinePoint>;
+
+    /// Compute `k×𝑮`, or none if the result is the point at infinity.
+    ///
+    /// Defaults to [`CurveArithmetic::mul_point`] with the generator. This is
+    /// used by [`sign_prehashed`] to compute `𝐑 = k×𝑮`, which dominates the
+    /// cost of signing, as well as when verifying signatures. Curves can
+    /// override it to use a precomputed table of multiples of the generator
+    /// (e.g. a windowed or comb table), which can improve signing throughput
+    /// several-fold at the cost of memory.
+    ///
+    /// Overrides MUST be constant time with respect to `k`, including any
+    /// table lookups (e.g. by conditionally selecting across every entry).
+    fn mul_base(k: &Self::Scalar) -> CtOption<Self::AffinePoint> {
+        Self::mul_point(&Self::AffinePoint::generator(), k)
+    }
+
+    /// Serialize the affine `x`-coordinate of the given point as a big endian
+    /// integer (i.e. an element of the base field)
+    fn x_coordinate(point: &Self::AffinePoint) -> ElementBytes<Self>;
+
+    /// Point representation used for intermediate results when summing many
+    /// points, such as projective coordinates, which unlike affine
+    /// coordinates can be added without a field inversion per addition.
+    ///
+    /// Used by [`multiscalar_mul_vartime`].
+    type ProjectivePoint: Copy;
+
+    /// Get the point at infinity as a [`CurveArithmetic::ProjectivePoint`]
+    fn identity() -> Self::ProjectivePoint;
+
+    /// Convert an affine point into a [`CurveArithmetic::ProjectivePoint`]
+    fn to_projective(point: &Self::AffinePoint) -> Self::ProjectivePoint;
+
+    /// Convert a [`CurveArithmetic::ProjectivePoint`] into affine
+    /// coordinates, or none if it's the point at infinity
+    fn to_affine(point: &Self::ProjectivePoint) -> CtOption<Self::AffinePoint>;
+
+    /// Compute `a + b`.
+    ///
+    /// This MUST be correct for all inputs, including when `a` and `b` are
+    /// equal or either is the point at infinity (i.e. it must use complete
+    /// addition formulas, or handle those cases separately).
+    fn add_projective(
+        a: &Self::ProjectivePoint,
+        b: &Self::ProjectivePoint,
+    ) -> Self::ProjectivePoint;
+
+    /// Compute `2×a`.
+    ///
+    /// Defaults to [`CurveArithmetic::add_projective`] with `a` twice. Curves
+    /// with dedicated doubling formulas should override this.
+    fn double_projective(a: &Self::ProjectivePoint) -> Self::ProjectivePoint {
+        Self::add_projective(a, a)
+    }
+}
+
+/// Compute the multiplicative inverse of a secret scalar in constant time,
+/// or none if it's zero.
+///
+/// This is how [`sign_prehashed`] and [`sign_prehashed_blinded`] compute
+/// `k⁻¹`. It computes `s^(n-2) mod n` using Fermat's little theorem, by
+/// square-and-multiply over the bits of `n - 2` with
+/// [`CurveArithmetic::mul_scalars`]. The sequence of multiplications only
+/// depends on the (public) order `n`, so the inversion is constant time as
+/// long as `mul_scalars` is, regardless of how the curve implements
+/// [`CurveArithmetic::invert_scalar`].
+///
+/// Whether the input is zero is reported using [`CtOption`], so also doesn't
+/// leak through timing unless the caller branches on it.
+pub fn invert_scalar_ct<C>(s: &C::Scalar) -> CtOption<C::Scalar>
+where
+    C: CurveArithmetic,
+{
+    // n - 2: the order is odd and larger than 2, so this doesn't underflow
+    let mut exponent = C::order();
+    let mut borrow = 2;
+
+    for byte in exponent.iter_mut().rev() {
+        let (difference, overflow) = byte.overflowing_sub(borrow);
+        *byte = difference;
+        borrow = overflow as u8;
+    }
+
+    let mut one = ElementBytes::<C>::default();
+    *one.last_mut().unwrap() = 1;
+
+    let mut result = C::reduce_scalar(&one);
+
+    for &byte in exponent.iter() {
+        for i in (0..8).rev() {
+            result = C::mul_scalars(&result, &result);
+
+            if (byte >> i) & 1 == 1 {
+                result = C::mul_scalars(&result, s);
+            }
+        }
+    }
+
+    CtOption::new(result, !s.ct_eq(&C::Scalar::default()))
+}
+
+/// Sign the given prehashed message using ECDSA with the provided secret
+/// scalar `d` and ephemeral scalar `k`.
+///
+/// Computes `𝐑 = k×𝑮`, `r = x(𝐑) mod n`, and `s = k⁻¹(z + r·d) mod n`,
+/// where `z` is the prehashed message reduced modulo `n`.
+///
+/// Returns an error if `k` is zero, or if either `r` or `s` is zero.
+///
+/// `k` MUST be either uniformly random or generated deterministically, e.g.
+/// using [`rfc6979_generate_k`]. Reusing `k` for two different messages, or
+/// selecting it from a biased distribution, reveals the secret scalar!
+pub fn sign_prehashed<C>(
+    secret_scalar: &C::Scalar,
+    ephemeral_scalar: &C::Scalar,
+    hashed_msg: &ElementBytes<C>,
+) -> Result<Signature<C>, Error>
+where
+    C: CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    sign_prehashed_with_point::<C>(secret_scalar, ephemeral_scalar, hashed_msg)
+        .map(|(signature, _)| signature)
+}
+
+/// Sign the given prehashed message like [`sign_prehashed`], returning a
+/// [`recoverable::Signature`] whose [`RecoveryId`] is computed from the point
+/// `𝐑 = k×𝑮`.
+///
+/// The recovery ID is correct even in the (very unlikely) case that the
+/// x-coordinate of `𝐑` is not less than `n`. The signature's `s` is not
+/// normalized: negating it after signing also negates the `𝐑` it recovers
+/// with, so the y-parity bit of the recovery ID must then be flipped.
+///
+/// [`recoverable::Signature`]: crate::recoverable::Signature
+/// [`RecoveryId`]: crate::recoverable::RecoveryId
+#[cfg(feature = "recoverable")]
+#[cfg_attr(docsrs, doc(cfg(feature = "recoverable")))]
+pub fn sign_prehashed_recoverable<C>(
+    secret_scalar: &C::Scalar,
+    ephemeral_scalar: &C::Scalar,
+    hashed_msg: &ElementBytes<C>,
+) -> Result<recoverable::Signature<C>, Error>
+where
+    C: CurveArithmetic,
+    C::AffinePoint: FromPublicKey<C>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPoint<C>: From<C::AffinePoint>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    recoverable::SignatureSize<C>: ArrayLength<u8>,
+{
+    let (signature, r_point) =
+        sign_prehashed_with_point::<C>(secret_scalar, ephemeral_scalar, hashed_msg)?;
+    let recovery_id = RecoveryId::from_signature_and_key(&signature, &r_point)?;
+    Ok(recoverable::Signature::new(&signature, recovery_id))
+}
+
+/// Sign the given prehashed message like [`sign_prehashed`], also returning
+/// the point `𝐑 = k×𝑮`.
+fn sign_prehashed_with_point<C>(
+    secret_scalar: &C::Scalar,
+    ephemeral_scalar: &C::Scalar,
+    hashed_msg: &ElementBytes<C>,
+) -> Result<(Signature<C>, C::AffinePoint), Error>
+where
+    C: CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    let (r_point, r, s_precursor) =
+        compute_signature_parts::<C>(secret_scalar, ephemeral_scalar, hashed_msg)?;
+    let k_inverse = invert_scalar_ct::<C>(ephemeral_scalar);
+
+    if k_inverse.is_none().into() {
+        return Err(ErrorKind::ZeroScalar.into());
+    }
+
+    let s = C::mul_scalars(&k_inverse.unwrap(), &s_precursor);
+
+    if s.ct_eq(&C::Scalar::default()).into() {
+        return Err(ErrorKind::ZeroScalar.into());
+    }
+
+    Ok((Signature::from_scalars(&r.into(), &s.into()), r_point))
+}
+
+/// Intermediate values of an ECDSA signature computed by
+/// [`compute_signature_parts`]: the point `𝐑`, the scalar `r`, and the `s`
+/// precursor `z + r·d`.
+pub type SignatureParts<C> = (
+    <C as Arithmetic>::AffinePoint,
+    <C as Arithmetic>::Scalar,
+    <C as Arithmetic>::Scalar,
+);
+
+/// Compute the intermediate values of an ECDSA signature over the given
+/// prehashed message with secret scalar `d` and ephemeral scalar `k`,
+/// returning `(𝐑, r, z + r·d)` where `𝐑 = k×𝑮` and `r = x(𝐑) mod n`.
+///
+/// The signature is completed by computing `s = k⁻¹(z + r·d) mod n`, as done
+/// by [`sign_prehashed`]. Exposing the parts allows protocols built on top
+/// of ECDSA (e.g. threshold or multi-party signing) to agree on `𝐑` or
+/// combine `s` precursors before completing the signature.
+///
+/// Returns an error if `k` is zero or `r` is zero.
+///
+/// # ⚠️ Warning
+///
+/// This is a low-level building block which is very easy to misuse:
+///
+/// - the `s` precursor is linear in the secret scalar, so revealing it (or
+///   any signature completed from it) along with `r` and `z` for a known
+///   `k`, or for two messages signed with the same `k`, reveals `d`;
+/// - `k` MUST meet the same requirements as for [`sign_prehashed`], and MUST
+///   never be reused, including across aborted protocol runs in which `𝐑`
+///   was revealed;
+/// - the returned values are not checked for consistency with any other
+///   party's: verify completed signatures before releasing them.
+pub fn compute_signature_parts<C>(
+    secret_scalar: &C::Scalar,
+    ephemeral_scalar: &C::Scalar,
+    hashed_msg: &ElementBytes<C>,
+) -> Result<SignatureParts<C>, Error>
+where
+    C: CurveArithmetic,
+{
+    let r_point = C::mul_base(ephemeral_scalar);
+
+    if r_point.is_none().into() {
+        return Err(ErrorKind::ZeroScalar.into());
+    }
+
+    let r_point = r_point.unwrap();
+    let r = C::reduce_scalar(&C::x_coordinate(&r_point));
+
+    if r.ct_eq(&C::Scalar::default()).into() {
+        return Err(ErrorKind::ZeroScalar.into());
+    }
+
+    let z = C::reduce_scalar(hashed_msg);
+    let s_precursor = C::add_scalars(&z, &C::mul_scalars(&r, secret_scalar));
+    Ok((r_point, r, s_precursor))
+}
+
+/// Source of candidate ephemeral scalars (i.e. nonces) `k` for
+/// [`sign_prehashed_with_retry`].
+///
+/// Any [`Iterator`] over scalars is a [`NonceSource`].
+pub trait NonceSource<C: CurveArithmetic> {
+    /// Get the next candidate ephemeral scalar, or `None` if this source is
+    /// exhausted.
+    ///
+    /// Candidates MUST meet the requirements described for
+    /// [`sign_prehashed`], and MUST NOT repeat.
+    fn next_nonce(&mut self) -> Option<C::Scalar>;
+}
+
+impl<C, I> NonceSource<C> for I
+where
+    C: CurveArithmetic,
+    I: Iterator<Item = C::Scalar>,
+{
+    fn next_nonce(&mut self) -> Option<C::Scalar> {
+        self.next()
+    }
+}
+
+/// Sign the given prehashed message using [`sign_prehashed`], retrying with
+/// the next candidate ephemeral scalar from `nonces` whenever `k`, `r`, or
+/// `s` is zero, as required by FIPS 186-4 § 6.3.
+///
+/// Returns the error for the last candidate if `nonces` is exhausted before
+/// a signature is produced, or a [`ErrorKind::ZeroScalar`] error if it
+/// doesn't produce any candidates.
+pub fn sign_prehashed_with_retry<C, N>(
+    secret_scalar: &C::Scalar,
+    nonces: &mut N,
+    hashed_msg: &ElementBytes<C>,
+) -> Result<Signature<C>, Error>
+where
+    C: CurveArithmetic,
+    N: NonceSource<C> + ?Sized,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    let mut result = Err(ErrorKind::ZeroScalar.into());
+
+    while let Some(ephemeral_scalar) = nonces.next_nonce() {
+        result = sign_prehashed::<C>(secret_scalar, &ephemeral_scalar, hashed_msg);
+
+        if result.is_ok() {
+            break;
+        }
+    }
+
+    result
+}
+
+/// Sign the given prehashed message like [`sign_prehashed`], but with the
+/// secret scalar `d` and ephemeral scalar `k` multiplicatively masked by the
+/// `blinding` scalar `b`.
+///
+/// Computes `s = (b·k)⁻¹ · (b·z + r·(b·d)) mod n`, which is equal to the
+/// unblinded `s`, so the inversion and the multiplication by the secret
+/// scalar never operate on unmasked secrets. This makes power and timing
+/// analysis of those operations harder on platforms where they may leak.
+///
+/// `b` MUST be a uniformly random non-zero scalar, freshly generated for each
+/// signature. Returns an error if `b` is zero, in addition to the conditions
+/// described for [`sign_prehashed`].
+pub fn sign_prehashed_blinded<C>(
+    secret_scalar: &C::Scalar,
+    ephemeral_scalar: &C::Scalar,
+    hashed_msg: &ElementBytes<C>,
+    blinding: &C::Scalar,
+) -> Result<Signature<C>, Error>
+where
+    C: CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    let blinded_k_inverse = invert_scalar_ct::<C>(&C::mul_scalars(blinding, ephemeral_scalar));
+    let r_point = C::mul_base(ephemeral_scalar);
+
+    if blinded_k_inverse.is_none().into() || r_point.is_none().into() {
+        return Err(ErrorKind::ZeroScalar.into());
+    }
+
+    let r = C::reduce_scalar(&C::x_coordinate(&r_point.unwrap()));
+    let blinded_z = C::mul_scalars(blinding, &C::reduce_scalar(hashed_msg));
+    let blinded_d = C::mul_scalars(blinding, secret_scalar);
+    let s = C::mul_scalars(
+        &blinded_k_inverse.unwrap(),
+        &C::add_scalars(&blinded_z, &C::mul_scalars(&r, &blinded_d)),
+    );
+
+    let zero = C::Scalar::default();
+
+    if (r.ct_eq(&zero) | s.ct_eq(&zero)).into() {
+        return Err(ErrorKind::ZeroScalar.into());
+    }
+
+    Ok(Signature::from_scalars(&r.into(), &s.into()))
+}
+
+/// Verify the given prehashed message against an ECDSA signature using the
+/// affine point `𝐐` of the signer's public key.
+///
+/// Computes `𝐑 = u₁×𝑮 + u₂×𝐐` where `u₁ = z·s⁻¹` and `u₂ = r·s⁻¹`, and checks
+/// that `x(𝐑) mod n` is equal to `r`.
+///
+/// Signatures where `r` or `s` are zero or not less than `n` are rejected
+/// (rather than reduced modulo `n`) before any point arithmetic is
+/// performed. On curves with a cofactor
+/// larger than 1, `𝐐` is then checked to be in the subgroup generated by `𝑮`
+/// using [`check_subgroup`].
+pub fn verify_prehashed<C>(
+    public_key: &C::AffinePoint,
+    hashed_msg: &ElementBytes<C>,
+    signature: &Signature<C>,
+) -> Result<(), Error>
+where
+    C: CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    verify_prehashed_recovering::<C>(public_key, hashed_msg, signature).map(|_| ())
+}
+
+/// Verify the given prehashed message against an ECDSA signature as
+/// described in [`verify_prehashed`], returning the affine x-coordinate of
+/// the computed point `𝐑` if it's valid.
+///
+/// This is the value which is reduced modulo `n` and compared against `r`,
+/// and can be used to build schemes with (partial) message recovery, e.g. in
+/// the style of ISO/IEC 9796, on top of ECDSA.
+pub fn verify_prehashed_recovering<C>(
+    public_key: &C::AffinePoint,
+    hashed_msg: &ElementBytes<C>,
+    signature: &Signature<C>,
+) -> Result<ElementBytes<C>, Error>
+where
+    C: CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    verify_scalar_recovering::<C>(public_key, &C::reduce_scalar(hashed_msg), signature)
+}
+
+/// Verify an ECDSA signature over a message which has already been hashed
+/// and converted to a scalar `z`, e.g. by a prior computation in another
+/// protocol.
+///
+/// This is [`verify_prehashed`] without the conversion of the prehash to a
+/// scalar, so `z` is used exactly as given rather than being reduced again.
+pub fn verify_scalar<C>(
+    public_key: &C::AffinePoint,
+    z: &C::Scalar,
+    signature: &Signature<C>,
+) -> Result<(), Error>
+where
+    C: CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    verify_scalar_recovering::<C>(public_key, z, signature).map(|_| ())
+}
+
+/// Verify an ECDSA signature over the message scalar `z`, returning the
+/// affine x-coordinate of the computed point `𝐑` if it's valid
+fn verify_scalar_recovering<C>(
+    public_key: &C::AffinePoint,
+    z: &C::Scalar,
+    signature: &Signature<C>,
+) -> Result<ElementBytes<C>, Error>
+where
+    C: CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    // FIPS 186-4 requires `1 <= r, s <= n - 1`: decode both strictly rather
+    // than reducing them, as the field's byte width can hold values >= n
+    let (r, s) = signature.split_scalars()?;
+    check_subgroup::<C>(public_key)?;
+
+    let s_inverse = C::invert_scalar(&s).unwrap();
+    let u1 = C::mul_scalars(z, &s_inverse);
+    let u2 = C::mul_scalars(&r, &s_inverse);
+
+    let u1_g = C::mul_base(&u1);
+    let u2_q = C::mul_point(public_key, &u2);
+
+    // Not constant time, but we're operating on public values
+    let point = if u1_g.is_none().into() {
+        u2_q
+    } else if u2_q.is_none().into() {
+        u1_g
+    } else {
+        C::add_points(&u1_g.unwrap(), &u2_q.unwrap())
+    };
+
+    if point.is_none().into() {
+        return Err(ErrorKind::VerificationFailed.into());
+    }
+
+    let x = C::x_coordinate(&point.unwrap());
+
+    if C::reduce_scalar(&x).ct_eq(&r).into() {
+        Ok(x)
+    } else {
+        Err(ErrorKind::VerificationFailed.into())
+    }
+}
+
+/// Width in bits of the windows of scalar digits used by
+/// [`multiscalar_mul_vartime`]
+const MSM_WINDOW_BITS: usize = 4;
+
+/// Number of terms [`multiscalar_mul_vartime`] processes at once, bounding
+/// the size of the tables of multiples it keeps on the stack
+const MSM_CHUNK_TERMS: usize = 8;
+
+/// Compute the sum `k₁×P₁ + k₂×P₂ + ⋯` of the given points multiplied by
+/// the given scalars, or none if it's the point at infinity.
+///
+/// This uses Straus' method (a.k.a. Shamir's trick) with fixed 4-bit
+/// windows: a table of small multiples `0×Pᵢ, 1×Pᵢ, …, 15×Pᵢ` is computed
+/// for each point, and the doublings are shared between all the terms, so
+/// each one costs 14 additions for its table and one addition per window,
+/// plus its share of the doublings. This is several times cheaper than
+/// computing each `kᵢ×Pᵢ` separately with [`CurveArithmetic::mul_point`].
+///
+/// Terms are processed in chunks of eight, so this doesn't allocate, and
+/// intermediate results are kept as [`CurveArithmetic::ProjectivePoint`]s.
+///
+/// NOT constant time: it skips zero digits and the table lookups depend on
+/// the scalars, so it MUST only be used with public values, e.g. when
+/// verifying signatures.
+pub fn multiscalar_mul_vartime<C, I>(terms: I) -> CtOption<C::AffinePoint>
+where
+    C: CurveArithmetic,
+    I: IntoIterator<Item = (C::AffinePoint, C::Scalar)>,
+{
+    let mut terms = terms.into_iter();
+    let mut sum = C::identity();
+
+    loop {
+        let mut tables = [[C::identity(); 1 << MSM_WINDOW_BITS]; MSM_CHUNK_TERMS];
+        let mut scalars: [ElementBytes<C>; MSM_CHUNK_TERMS] = Default::default();
+        let mut len = 0;
+
+        for ((table, bytes), (point, scalar)) in tables
+            .iter_mut()
+            .zip(scalars.iter_mut())
+            .zip(terms.by_ref())
+        {
+            table[1] = C::to_projective(&point);
+
+            for i in 2..table.len() {
+                table[i] = C::add_projective(&table[i - 1], &table[1]);
+            }
+
+            *bytes = scalar.into();
+            len += 1;
+        }
+
+        if len == 0 {
+            break;
+        }
+
+        let mut chunk_sum = C::identity();
+
+        for i in 0..C::ElementSize::to_usize() {
+            for &shift in &[4, 0] {
+                for _ in 0..MSM_WINDOW_BITS {
+                    chunk_sum = C::double_projective(&chunk_sum);
+                }
+
+                for (table, bytes) in tables[..len].iter().zip(&scalars[..len]) {
+                    let digit = usize::from((bytes[i] >> shift) & 0xf);
+
+                    if digit != 0 {
+                        chunk_sum = C::add_projective(&chunk_sum, &table[digit]);
+                    }
+                }
+            }
+        }
+
+        sum = C::add_projective(&sum, &chunk_sum);
+
+        if len < MSM_CHUNK_TERMS {
+            break;
+        }
+    }
+
+    C::to_affine(&sum)
+}
+
+/// Check the given point is in the subgroup of order `n` generated by the
+/// base point, i.e. that `n×P` is the point at infinity, returning an error
+/// if it isn't.
+///
+/// This is a no-op for curves with a [`CurveArithmetic::COFACTOR`] of 1, as
+/// every point on them is in the subgroup. Otherwise `n×P` is computed as
+/// `(n - 1)×P + P`, as `n` itself isn't a valid scalar.
+///
+/// Not constant time, but we're operating on public values.
+pub fn check_subgroup<C: CurveArithmetic>(point: &C::AffinePoint) -> Result<(), Error> {
+    if C::COFACTOR == 1 {
+        return Ok(());
+    }
+
+    let mut one = ElementBytes::<C>::default();
+    *one.last_mut().unwrap() = 1;
+    let minus_one = C::negate_scalar(&C::reduce_scalar(&one));
+
+    // `(n - 1)×P` can only be the point at infinity if `n×P = P`
+    let negated = C::mul_point(point, &minus_one);
+
+    if negated.is_some().into() && C::add_points(&negated.unwrap(), point).is_none().into() {
+        Ok(())
+    } else {
+        Err(ErrorKind::InvalidPoint.into())
+    }
+}
+
+/// Detect whether two signatures were produced with the same ephemeral
+/// scalar `k` over different messages.
+///
+/// Signatures sharing `k` have identical `r` components, and if they were
+/// computed over different prehashed messages they leak the secret key (see
+/// e.g. the 2010 PlayStation 3 ECDSA break). Signing services can use this as
+/// a cheap guard before publishing multiple signatures made with the same
+/// key. Signatures over the same message (as produced when re-signing
+/// deterministically) are not reported.
+///
+/// Prehashes are compared after conversion with [`bits2int`], so digests
+/// which only differ beyond the bit length of the order are considered the
+/// same message.
+///
+/// Not constant time, but we're operating on public values.
+pub fn detect_nonce_reuse<C>(
+    sig_a: &Signature<C>,
+    prehash_a: &[u8],
+    sig_b: &Signature<C>,
+    prehash_b: &[u8],
+) -> bool
+where
+    C: CurveOrder,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    sig_a.r() == sig_b.r()
+        && bits2int::<C>(prehash_a, C::order_bits()) != bits2int::<C>(prehash_b, C::order_bits())
+}
+
+/// Recover the secret scalar `d` from two signatures which were produced with
+/// the same ephemeral scalar `k` over different prehashed messages `z_a` and
+/// `z_b` (see [`detect_nonce_reuse`]).
+///
+/// This is an attack: it exists to demonstrate the consequences of nonce
+/// reuse and for forensic and test tooling, e.g. recovering a key known to
+/// have been compromised this way.
+///
+/// Computes `k = (z_a - z_b) / (s_a - s_b)` and `d = (s_a·k - z_a) / r`. If
+/// either signature's `s` was negated after signing (e.g. low-S
+/// normalization), negate it back before calling this function, or try both.
+///
+/// Returns an error if the signatures have different `r` components, or if
+/// they have the same `s` component (i.e. are over the same message).
+pub fn recover_secret_from_reused_nonce<C>(
+    sig_a: &Signature<C>,
+    z_a: &ElementBytes<C>,
+    sig_b: &Signature<C>,
+    z_b: &ElementBytes<C>,
+) -> Result<C::Scalar, Error>
+where
+    C: CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    if sig_a.r() != sig_b.r() {
+        return Err(ErrorKind::VerificationFailed.into());
+    }
+
+    let (r, s_a, s_b) = (
+        C::Scalar::from_bytes(sig_a.r()),
+        C::Scalar::from_bytes(sig_a.s()),
+        C::Scalar::from_bytes(sig_b.s()),
+    );
+
+    if r.is_none().into() || s_a.is_none().into() || s_b.is_none().into() {
+        return Err(ErrorKind::ScalarOutOfRange.into());
+    }
+
+    let (r, s_a, s_b) = (r.unwrap(), s_a.unwrap(), s_b.unwrap());
+    let (z_a, z_b) = (C::reduce_scalar(z_a), C::reduce_scalar(z_b));
+    let sub = |a: &C::Scalar, b: &C::Scalar| C::add_scalars(a, &C::negate_scalar(b));
+
+    let s_diff_inverse = C::invert_scalar(&sub(&s_a, &s_b));
+    let r_inverse = C::invert_scalar(&r);
+
+    if s_diff_inverse.is_none().into() || r_inverse.is_none().into() {
+        return Err(ErrorKind::ZeroScalar.into());
+    }
+
+    let k = C::mul_scalars(&sub(&z_a, &z_b), &s_diff_inverse.unwrap());
+    Ok(C::mul_scalars(
+        &sub(&C::mul_scalars(&s_a, &k), &z_a),
+        &r_inverse.unwrap(),
+    ))
+}
+
+/// Reduce a big endian serialized integer of any length modulo the curve's
+/// order `n`, e.g. to derive a scalar from a wide (double-width) random value.
+///
+/// The input is processed in `ElementSize` chunks from its most significant
+/// end using [`CurveArithmetic`] scalar operations, so this function is
+/// constant time with respect to the contents (but not the length) of `wide`,
+/// provided those operations are.
+///
+/// If `wide` is a uniformly random string of `b` bits, the statistical
+/// distance of the result from a uniformly random scalar is at most `n / 2ᵇ`.
+/// For a `2 × ElementSize` byte input this is less than `2^-(8 × ElementSize)`
+/// (e.g. `2⁻²⁵⁶` for P-256), whereas reducing a single field element's worth
+/// of random bytes has a statistical distance
+/// of `(2^(8 × ElementSize) - n) / 2^(8 × ElementSize)`.
+pub fn reduce_bytes_to_scalar<C>(wide: &[u8]) -> C::Scalar
+where
+    C: CurveArithmetic,
+{
+    let mut chunk = ElementBytes::<C>::default();
+    let size = chunk.len();
+
+    // 2^(8 × ElementSize) mod n, computed as (2^(8 × ElementSize) - 1) + 1
+    let mut one = ElementBytes::<C>::default();
+    one[size - 1] = 1;
+    chunk.iter_mut().for_each(|byte| *byte = 0xff);
+    let radix = C::add_scalars(&C::reduce_scalar(&chunk), &C::reduce_scalar(&one));
+
+    let (head, tail) = wide.split_at(wide.len() % size);
+    let mut result = C::Scalar::default();
+
+    for bytes in Some(head).into_iter().chain(tail.chunks_exact(size)) {
+        chunk.iter_mut().for_each(|byte| *byte = 0);
+        chunk[size - bytes.len()..].copy_from_slice(bytes);
+        result = C::add_scalars(&C::mul_scalars(&result, &radix), &C::reduce_scalar(&chunk));
+    }
+
+    #[cfg(feature = "zeroize")]
+    chunk.as_mut_slice().zeroize();
+
+    result
+}
+
+/// Deterministically generate an ECDSA ephemeral scalar `k` as described in
+/// [RFC 6979 §3.2][1], using HMAC instantiated with the digest `D`.
+///
+/// Accepts the following arguments:
+///
+/// - `secret_scalar`: the signing key's secret scalar `x`
+/// - `prehash`: the hashed message `H(m)` to be signed, converted with
+///   [`bits2int`], which is reduced modulo the curve's order `n` to compute
+///   `bits2octets(h1)`
+/// - `extra_entropy`: optional additional data `k'` as described in
+///   [RFC 6979 §3.6][2], which may be used to "hedge" the nonce against
+///   fault attacks. Pass an empty slice for the purely deterministic variant.
+///
+/// The HMAC-DRBG is iterated until it produces `k` in the range `[1, n)`,
+/// where each candidate is converted with [`bits2int`], i.e. truncated to the
+/// leftmost [`CurveOrder::order_bits`] bits of the DRBG output.
+///
+/// [1]: https://tools.ietf.org/html/rfc6979#section-3.2
+/// [2]: https://tools.ietf.org/html/rfc6979#section-3.6
+#[cfg(feature = "rfc6979")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rfc6979")))]
+pub fn rfc6979_generate_k<C, D>(
+    secret_scalar: &C::Scalar,
+    prehash: &ElementBytes<C>,
+    extra_entropy: &[u8],
+) -> Zeroizing<C::Scalar>
+where
+    C: CurveArithmetic,
+    C::Scalar: Zeroize,
+    D: Update + BlockInput + FixedOutput + Reset + Default + Clone,
+    D::BlockSize: ArrayLength<u8>,
+{
+    rfc6979_generate_k_with_data::<C, D>(secret_scalar, prehash, &[extra_entropy])
+}
+
+/// Generate `k` as in [`rfc6979_generate_k`], with the additional data `k'`
+/// given as the concatenation of several slices
+#[cfg(feature = "rfc6979")]
+pub(crate) fn rfc6979_generate_k_with_data<C, D>(
+    secret_scalar: &C::Scalar,
+    prehash: &ElementBytes<C>,
+    additional_data: &[&[u8]],
+) -> Zeroizing<C::Scalar>
+where
+    C: CurveArithmetic,
+    C::Scalar: Zeroize,
+    D: Update + BlockInput + FixedOutput + Reset + Default + Clone,
+    D::BlockSize: ArrayLength<u8>,
+{
+    let mut x: ElementBytes<C> = (*secret_scalar).into();
+    let mut h1: ElementBytes<C> = C::reduce_scalar(prehash).into();
+    let mut drbg = HmacDrbg::<D>::new(&x, &h1, additional_data);
+    x.as_mut_slice().zeroize();
+    h1.as_mut_slice().zeroize();
+
+    let mut t = ElementBytes::<C>::default();
+
+    loop {
+        drbg.generate_into(&mut t);
+        let mut candidate = bits2int::<C>(&t, C::order_bits());
+        let k = C::Scalar::from_bytes(&candidate);
+        t.as_mut_slice().zeroize();
+        candidate.as_mut_slice().zeroize();
+
+        if k.is_some().into() {
+            let k = Zeroizing::new(k.unwrap());
+
+            if (!k.ct_eq(&C::Scalar::default())).into() {
+                return k;
+            }
+        }
+
+        drbg.reseed();
+    }
+}
+
+/// Sign the given prehashed message using a "hedged" ephemeral scalar `k`.
+///
+/// This is RFC 6979 with a field element's worth of bytes from `rng` passed
+/// as the additional data `k'` (see [`rfc6979_generate_k`]). The resulting
+/// signatures are only reproducible if the same RNG output is replayed, and
+/// a weak or broken RNG degrades gracefully to the deterministic scheme,
+/// while the fresh randomness makes fault attacks against signing harder.
+#[cfg(all(feature = "rand", feature = "rfc6979"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "rand", feature = "rfc6979"))))]
+pub fn sign_hedged<C, D, R>(
+    secret_scalar: &C::Scalar,
+    prehash: &ElementBytes<C>,
+    mut rng: R,
+) -> Result<Signature<C>, Error>
+where
+    C: CurveArithmetic,
+    C::Scalar: Zeroize,
+    D: Update + BlockInput + FixedOutput + Reset + Default + Clone,
+    D::BlockSize: ArrayLength<u8>,
+    R: CryptoRng + RngCore,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    let mut entropy = ElementBytes::<C>::default();
+    rng.fill_bytes(&mut entropy);
+
+    let k = rfc6979_generate_k::<C, D>(secret_scalar, prehash, &entropy);
+    entropy.as_mut_slice().zeroize();
+
+    sign_prehashed::<C>(secret_scalar, &k, prehash)
+}
+
+/// Convert a digest's output into a field element sized integer, taking the
+/// leftmost bytes if the digest is larger than the field as described in
+/// FIPS 186-4 § 6.4 (or left-padding it with zeroes if it's smaller).
+///
+/// This is [`bits2int`] with an order bit length equal to the size of the
+/// curve's field elements, so for curves whose order is not a multiple of 8
+/// bits long (e.g. P-521), it only matches FIPS 186-4 for digests no longer
+/// than the order, such as SHA-512 with P-521. The signing and verification
+/// APIs in this crate instead use [`bits2int`] with
+/// [`CurveOrder::order_bits`], which correctly truncates longer digests.
+pub fn bits2field<C: Curve>(digest: &[u8]) -> ElementBytes<C> {
+    bits2int::<C>(digest, C::ElementSize::to_usize() * 8)
+}
+
+/// Convert a digest's output into a scalar as described in FIPS 186-4 § 6.4,
+/// i.e. `bits2int(digest) mod n`.
+///
+/// If the digest is longer than the curve's order `n`, only its leftmost
+/// [`CurveOrder::order_bits`] bits are used and the remainder is ignored.
+/// This works at bit granularity, so e.g. only the leftmost 521 bits of a
+/// 66-byte digest are used for P-521. The resulting integer is then reduced
+/// modulo `n` once, which for digests of at most the order's length is the
+/// same as interpreting it as a big endian integer modulo `n`.
+///
+/// This is exactly how the signing and verification APIs in this crate
+/// convert digests, prior to the reduction modulo `n`.
+///
+/// Use [`reduce_bytes_to_scalar`] instead to reduce the entire input.
+pub fn hash_to_scalar<C>(digest_bytes: &[u8]) -> C::Scalar
+where
+    C: CurveArithmetic,
+{
+    C::reduce_scalar(&bits2int::<C>(digest_bytes, C::order_bits()))
+}
+
+/// Convert a digest's output into an integer of at most `order_bits` bits as
+/// described in FIPS 186-4 § 6.4 and RFC 6979 § 2.3.2, where `order_bits` is
+/// the bit length of the curve's order `n`.
+///
+/// If the digest is longer than `order_bits`, its leftmost `order_bits` bits
+/// are kept. This works at bit granularity, which matters for curves whose
+/// order is not a multiple of 8 bits (e.g. P-521). Shorter digests are
+/// left-padded with zeroes.
+///
+/// The result is not reduced modulo `n`.
+///
+/// # Panics
+///
+/// Panics if `order_bits` exceeds the size of the curve's field elements.
+pub fn bits2int<C: Curve>(digest: &[u8], order_bits: usize) -> ElementBytes<C> {
+    let mut bytes = ElementBytes::<C>::default();
+    let size = bytes.len();
+
+    // Number of trailing bits in the last byte which exceed `order_bits`
+    let shift = (8 - order_bits % 8) % 8;
+    let order_len = (order_bits + shift) / 8;
+    assert!(order_len <= size, "order larger than field element size");
+
+    if digest.len() * 8 <= order_bits {
+        bytes[size - digest.len()..].copy_from_slice(digest);
+        return bytes;
+    }
+
+    let offset = size - order_len;
+    bytes[offset..].copy_from_slice(&digest[..order_len]);
+
+    if shift > 0 {
+        let mut carry = 0;
+
+        for byte in &mut bytes[offset..] {
+            let next_carry = *byte << (8 - shift);
+            *byte = (*byte >> shift) | carry;
+            carry = next_carry;
+        }
+    }
+
+    bytes
+}
+
 /// Bind a preferred [`Digest`] algorithm to an elliptic curve type.
 ///
 /// Generally there is a preferred variety of the SHA-2 family used with ECDSA
@@ -133,3 +1150,875 @@ where
 {
     type Digest = C::Digest;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        bits2field, bits2int, check_subgroup, compute_signature_parts, detect_nonce_reuse,
+        hash_to_scalar, invert_scalar_ct, multiscalar_mul_vartime,
+        recover_secret_from_reused_nonce, reduce_bytes_to_scalar, sign_prehashed,
+        sign_prehashed_blinded, sign_prehashed_with_retry, verify_prehashed,
+        verify_prehashed_recovering, verify_scalar, CurveArithmetic,
+    };
+    use crate::{
+        test_curves::{
+            cofactor::{self, CofactorCurve},
+            small::{self, SmallCurve},
+        },
+        Signature,
+    };
+    use elliptic_curve::{
+        weierstrass::public_key::{FromPublicKey, PublicKey},
+        ElementBytes, FromBytes,
+    };
+    use hex_literal::hex;
+    use p256::{AffinePoint, NistP256, Scalar};
+    use sha2::{Digest, Sha256, Sha512};
+
+    #[cfg(feature = "recoverable")]
+    use {
+        super::sign_prehashed_recoverable,
+        crate::{
+            recoverable::RecoveryId,
+            test_curves::reduced::{self, ReducedCurve},
+        },
+        elliptic_curve::weierstrass::point::UncompressedPoint,
+    };
+
+    #[cfg(feature = "rfc6979")]
+    use super::rfc6979_generate_k;
+
+    #[cfg(all(feature = "rand", feature = "rfc6979"))]
+    use {super::sign_hedged, crate::test_curves::TestRng};
+
+    /// Secret key from RFC 6979 A.2.5 (P-256)
+    const SECRET_KEY: [u8; 32] =
+        hex!("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+
+    /// Public key from RFC 6979 A.2.5 (P-256)
+    const PUBLIC_KEY: [u8; 65] = hex!(
+        "04 60FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6
+            7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299"
+    );
+
+    /// RFC 6979 A.2.5 signature of "sample" using SHA-256
+    const SIGNATURE: [u8; 64] = hex!(
+        "EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716
+         F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8"
+    );
+
+    fn secret_scalar() -> Scalar {
+        Scalar::from_bytes(&SECRET_KEY.into()).unwrap()
+    }
+
+    fn public_key() -> AffinePoint {
+        let public_key = PublicKey::from_bytes(&PUBLIC_KEY[..]).unwrap();
+        AffinePoint::from_public_key(&public_key).unwrap()
+    }
+
+    /// RFC 6979 A.2.5: P-256, SHA-256, message = "sample"
+    #[test]
+    fn sign_prehashed_sample() {
+        let k = Scalar::from_bytes(
+            &hex!("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60").into(),
+        )
+        .unwrap();
+
+        let signature =
+            sign_prehashed::<NistP256>(&secret_scalar(), &k, &Sha256::digest(b"sample")).unwrap();
+
+        assert_eq!(
+            signature.r().as_slice(),
+            hex!("EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716")
+        );
+        assert_eq!(
+            signature.s().as_slice(),
+            hex!("F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8")
+        );
+    }
+
+    /// RFC 6979 A.2.5: P-256, SHA-256, message = "test"
+    #[test]
+    fn sign_prehashed_test() {
+        let k = Scalar::from_bytes(
+            &hex!("D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0").into(),
+        )
+        .unwrap();
+
+        let signature =
+            sign_prehashed::<NistP256>(&secret_scalar(), &k, &Sha256::digest(b"test")).unwrap();
+
+        assert_eq!(
+            signature.r().as_slice(),
+            hex!("F1ABB023518351CD71D881567B1EA663ED3EFCF6C5132B354F28D3B0B7D38367")
+        );
+        assert_eq!(
+            signature.s().as_slice(),
+            hex!("019F4113742A2B14BD25926B49C649155F267E60D3814B4C0CC84250E46F0083")
+        );
+    }
+
+    #[test]
+    fn sign_prehashed_with_retry_skips_invalid_nonces() {
+        let k = Scalar::from_bytes(
+            &hex!("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60").into(),
+        )
+        .unwrap();
+
+        // Craft a prehash `z = -r·d` for which `crafted_k` yields `s = 0`
+        let crafted_k = Scalar::from_bytes(&[0x42; 32].into()).unwrap();
+        let signature =
+            sign_prehashed::<NistP256>(&secret_scalar(), &crafted_k, &[1; 32].into()).unwrap();
+        let r = Scalar::from_bytes(signature.r()).unwrap();
+        let z = NistP256::negate_scalar(&NistP256::mul_scalars(&r, &secret_scalar()));
+        let prehash = ElementBytes::<NistP256>::from(z);
+
+        // Zero `k` is rejected, then `s = 0`, then `k` succeeds
+        let candidates = [Scalar::default(), crafted_k, k];
+        let mut nonces = candidates.iter().cloned();
+        let signature =
+            sign_prehashed_with_retry::<NistP256, _>(&secret_scalar(), &mut nonces, &prehash)
+                .unwrap();
+
+        assert_eq!(
+            signature,
+            sign_prehashed::<NistP256>(&secret_scalar(), &k, &prehash).unwrap()
+        );
+        assert!(verify_prehashed::<NistP256>(&public_key(), &prehash, &signature).is_ok());
+        assert!(nonces.next().is_none());
+
+        // Exhausted without a valid candidate
+        let mut nonces = candidates[..2].iter().cloned();
+        assert!(
+            sign_prehashed_with_retry::<NistP256, _>(&secret_scalar(), &mut nonces, &prehash)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn sign_prehashed_blinded_matches_unblinded() {
+        let k = Scalar::from_bytes(
+            &hex!("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60").into(),
+        )
+        .unwrap();
+        let blinding = Scalar::from_bytes(&[0x42; 32].into()).unwrap();
+        let prehash = Sha256::digest(b"sample");
+
+        let signature =
+            sign_prehashed_blinded::<NistP256>(&secret_scalar(), &k, &prehash, &blinding).unwrap();
+
+        assert_eq!(signature.as_ref(), &SIGNATURE[..]);
+        assert!(verify_prehashed::<NistP256>(&public_key(), &prehash, &signature).is_ok());
+
+        // Zero blinding scalar
+        assert!(sign_prehashed_blinded::<NistP256>(
+            &secret_scalar(),
+            &k,
+            &prehash,
+            &Scalar::default()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn compute_signature_parts_reassemble() {
+        let k = Scalar::from_bytes(
+            &hex!("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60").into(),
+        )
+        .unwrap();
+        let prehash = Sha256::digest(b"sample");
+
+        let (r_point, r, s_precursor) =
+            compute_signature_parts::<NistP256>(&secret_scalar(), &k, &prehash).unwrap();
+        assert_eq!(
+            NistP256::x_coordinate(&r_point).as_slice(),
+            &SIGNATURE[..32]
+        );
+
+        let s = NistP256::mul_scalars(&NistP256::invert_scalar(&k).unwrap(), &s_precursor);
+        let signature = Signature::<NistP256>::from_scalars(&r.into(), &s.into());
+        assert_eq!(
+            signature,
+            sign_prehashed::<NistP256>(&secret_scalar(), &k, &prehash).unwrap()
+        );
+        assert_eq!(signature.as_ref(), &SIGNATURE[..]);
+
+        assert!(compute_signature_parts::<NistP256>(
+            &secret_scalar(),
+            &Scalar::default(),
+            &prehash
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn detect_nonce_reuse_same_k() {
+        let k = Scalar::from_bytes(
+            &hex!("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60").into(),
+        )
+        .unwrap();
+
+        let (prehash_a, prehash_b) = (Sha256::digest(b"sample"), Sha256::digest(b"test"));
+        let sig_a = sign_prehashed::<NistP256>(&secret_scalar(), &k, &prehash_a).unwrap();
+        let sig_b = sign_prehashed::<NistP256>(&secret_scalar(), &k, &prehash_b).unwrap();
+
+        assert!(detect_nonce_reuse(&sig_a, &prehash_a, &sig_b, &prehash_b));
+
+        // Re-signing the same message with the same `k` doesn't leak the key
+        assert!(!detect_nonce_reuse(&sig_a, &prehash_a, &sig_a, &prehash_a));
+    }
+
+    #[test]
+    fn recover_secret_from_reused_nonce_p256() {
+        let k = Scalar::from_bytes(&[0x42; 32].into()).unwrap();
+        let (z_a, z_b) = (Sha256::digest(b"sample"), Sha256::digest(b"test"));
+        let sig_a = sign_prehashed::<NistP256>(&secret_scalar(), &k, &z_a).unwrap();
+        let sig_b = sign_prehashed::<NistP256>(&secret_scalar(), &k, &z_b).unwrap();
+
+        let recovered =
+            recover_secret_from_reused_nonce::<NistP256>(&sig_a, &z_a, &sig_b, &z_b).unwrap();
+        assert_eq!(ElementBytes::<NistP256>::from(recovered), SECRET_KEY.into());
+
+        // Signatures over the same message don't determine the key
+        assert!(recover_secret_from_reused_nonce::<NistP256>(&sig_a, &z_a, &sig_a, &z_a).is_err());
+    }
+
+    #[test]
+    fn recover_secret_from_distinct_nonces() {
+        let k = Scalar::from_bytes(&[0x42; 32].into()).unwrap();
+        let (z_a, z_b) = (Sha256::digest(b"sample"), Sha256::digest(b"test"));
+        let sig_a =
+            Signature::<NistP256>::from_scalars(SIGNATURE[..32].into(), SIGNATURE[32..].into());
+        let sig_b = sign_prehashed::<NistP256>(&secret_scalar(), &k, &z_b).unwrap();
+
+        let result = recover_secret_from_reused_nonce::<NistP256>(&sig_a, &z_a, &sig_b, &z_b);
+
+        #[cfg(feature = "std")]
+        assert_eq!(
+            crate::error::tests::kind(result.unwrap_err()),
+            crate::ErrorKind::VerificationFailed
+        );
+        #[cfg(not(feature = "std"))]
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn detect_nonce_reuse_distinct_k() {
+        let (prehash_a, prehash_b) = (Sha256::digest(b"sample"), Sha256::digest(b"test"));
+        let sig_a =
+            Signature::<NistP256>::from_scalars(SIGNATURE[..32].into(), SIGNATURE[32..].into());
+        let k = Scalar::from_bytes(&[0x42; 32].into()).unwrap();
+        let sig_b = sign_prehashed::<NistP256>(&secret_scalar(), &k, &prehash_b).unwrap();
+
+        assert!(!detect_nonce_reuse(&sig_a, &prehash_a, &sig_b, &prehash_b));
+    }
+
+    #[test]
+    fn sign_prehashed_zero_k() {
+        let result = sign_prehashed::<NistP256>(
+            &secret_scalar(),
+            &Scalar::default(),
+            &Sha256::digest(b"sample"),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn verify_prehashed_valid() {
+        let signature =
+            Signature::<NistP256>::from_scalars(SIGNATURE[..32].into(), SIGNATURE[32..].into());
+
+        assert!(verify_prehashed::<NistP256>(
+            &public_key(),
+            &Sha256::digest(b"sample"),
+            &signature
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn verify_scalar_matches_verify_prehashed() {
+        let signature =
+            Signature::<NistP256>::from_scalars(SIGNATURE[..32].into(), SIGNATURE[32..].into());
+
+        for msg in &[&b"sample"[..], b"test"] {
+            let prehash = Sha256::digest(msg);
+            let z = NistP256::reduce_scalar(&prehash);
+
+            assert_eq!(
+                verify_scalar::<NistP256>(&public_key(), &z, &signature).is_ok(),
+                verify_prehashed::<NistP256>(&public_key(), &prehash, &signature).is_ok()
+            );
+        }
+
+        let z = NistP256::reduce_scalar(&Sha256::digest(b"sample"));
+        assert!(verify_scalar::<NistP256>(&public_key(), &z, &signature).is_ok());
+    }
+
+    #[test]
+    fn verify_prehashed_recovering_returns_r() {
+        let signature =
+            Signature::<NistP256>::from_scalars(SIGNATURE[..32].into(), SIGNATURE[32..].into());
+        let prehash = Sha256::digest(b"sample");
+
+        let x =
+            verify_prehashed_recovering::<NistP256>(&public_key(), &prehash, &signature).unwrap();
+        assert_eq!(&x, signature.r());
+
+        let prehash = Sha256::digest(b"test");
+        assert!(
+            verify_prehashed_recovering::<NistP256>(&public_key(), &prehash, &signature).is_err()
+        );
+    }
+
+    #[test]
+    fn verify_prehashed_wrong_message() {
+        let signature =
+            Signature::<NistP256>::from_scalars(SIGNATURE[..32].into(), SIGNATURE[32..].into());
+
+        assert!(
+            verify_prehashed::<NistP256>(&public_key(), &Sha256::digest(b"test"), &signature)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn verify_prehashed_tampered_s() {
+        let mut s = SIGNATURE;
+        s[63] ^= 1;
+        let signature = Signature::<NistP256>::from_scalars(s[..32].into(), s[32..].into());
+
+        assert!(verify_prehashed::<NistP256>(
+            &public_key(),
+            &Sha256::digest(b"sample"),
+            &signature
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn verify_prehashed_out_of_range_scalars() {
+        let (r, s) = SIGNATURE.split_at(32);
+
+        // `n`, the order of the P-256 curve, and `n + 1`
+        let n = hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551");
+        let n_plus_one = hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632552");
+
+        for &(r, s) in &[
+            (&n[..], s),
+            (&n_plus_one[..], s),
+            (r, &n[..]),
+            (r, &n_plus_one[..]),
+        ] {
+            let signature = Signature::<NistP256>::from_scalars(r.into(), s.into());
+            let result =
+                verify_prehashed::<NistP256>(&public_key(), &Sha256::digest(b"sample"), &signature);
+
+            #[cfg(feature = "std")]
+            assert_eq!(
+                crate::error::tests::kind(result.unwrap_err()),
+                crate::ErrorKind::ScalarOutOfRange
+            );
+            #[cfg(not(feature = "std"))]
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn verify_prehashed_zero_scalars() {
+        let (r, s) = SIGNATURE.split_at(32);
+        let zero = [0u8; 32];
+
+        for &(r, s) in &[(&zero[..], s), (r, &zero[..]), (&zero[..], &zero[..])] {
+            let signature = Signature::<NistP256>::from_scalars(r.into(), s.into());
+            let result =
+                verify_prehashed::<NistP256>(&public_key(), &Sha256::digest(b"sample"), &signature);
+
+            #[cfg(feature = "std")]
+            assert_eq!(
+                crate::error::tests::kind(result.unwrap_err()),
+                crate::ErrorKind::ZeroScalar
+            );
+            #[cfg(not(feature = "std"))]
+            assert!(result.is_err());
+        }
+    }
+
+    #[cfg(feature = "rfc6979")]
+    fn generate_k(msg: &[u8], extra_entropy: &[u8]) -> ElementBytes<NistP256> {
+        let h = Sha256::digest(msg);
+        let k = rfc6979_generate_k::<NistP256, Sha256>(&secret_scalar(), &h, extra_entropy);
+        (*k).into()
+    }
+
+    /// RFC 6979 A.2.5: P-256, SHA-256, message = "sample"
+    #[cfg(feature = "rfc6979")]
+    #[test]
+    fn rfc6979_sample() {
+        assert_eq!(
+            generate_k(b"sample", &[]).as_slice(),
+            hex!("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60")
+        );
+    }
+
+    /// RFC 6979 A.2.5: P-256, SHA-256, message = "test"
+    #[cfg(feature = "rfc6979")]
+    #[test]
+    fn rfc6979_test() {
+        assert_eq!(
+            generate_k(b"test", &[]).as_slice(),
+            hex!("D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0")
+        );
+    }
+
+    /// The order of the [`CofactorCurve`] is 6 bits long, so each 8-bit DRBG
+    /// output must be truncated to its leftmost 6 bits (RFC 6979 §3.2 h.3)
+    #[cfg(feature = "rfc6979")]
+    #[test]
+    fn rfc6979_truncates_to_order_bits() {
+        use super::rfc6979_generate_k;
+        use crate::rfc6979::HmacDrbg;
+
+        for x in 1..cofactor::ORDER {
+            let prehash = [x.wrapping_mul(37)].into();
+            let k =
+                rfc6979_generate_k::<CofactorCurve, Sha256>(&cofactor::Scalar(x), &prehash, &[]);
+
+            let h1: ElementBytes<CofactorCurve> = CofactorCurve::reduce_scalar(&prehash).into();
+            let mut drbg = HmacDrbg::<Sha256>::new(&[x], &h1, &[&[]]);
+            let mut t = [0u8];
+
+            let expected = loop {
+                drbg.generate_into(&mut t);
+                let candidate = t[0] >> 2;
+
+                if candidate != 0 && candidate < cofactor::ORDER {
+                    break candidate;
+                }
+
+                drbg.reseed();
+            };
+
+            assert_eq!(*k, cofactor::Scalar(expected));
+        }
+    }
+
+    #[cfg(feature = "rfc6979")]
+    #[test]
+    fn extra_entropy_changes_k() {
+        let k = generate_k(b"sample", &[]);
+        let hedged = generate_k(b"sample", b"extra entropy");
+        assert_ne!(k, hedged);
+        assert_eq!(hedged, generate_k(b"sample", b"extra entropy"));
+    }
+
+    #[cfg(all(feature = "rand", feature = "rfc6979"))]
+    #[test]
+    fn sign_hedged_randomizes_signatures() {
+        let prehash = Sha256::digest(b"sample");
+        let sign = |seed| {
+            sign_hedged::<NistP256, Sha256, _>(&secret_scalar(), &prehash, TestRng(seed)).unwrap()
+        };
+
+        let (signature1, signature2) = (sign(1), sign(2));
+        assert_ne!(signature1, signature2);
+        assert_eq!(signature1, sign(1));
+
+        for signature in &[signature1, signature2] {
+            assert!(verify_prehashed::<NistP256>(&public_key(), &prehash, signature).is_ok());
+        }
+    }
+
+    #[test]
+    fn reduce_bytes_to_scalar_p256() {
+        let wide = Sha512::digest(b"sample");
+        let scalar = reduce_bytes_to_scalar::<NistP256>(&wide);
+        assert_eq!(
+            elliptic_curve::ElementBytes::<NistP256>::from(scalar).as_slice(),
+            hex!("B2E0C9512FB6D048B714CBC9F3FDF4E90847DCE8D37861ECEF6A19BCC9D97523")
+        );
+    }
+
+    #[test]
+    fn reduce_bytes_to_scalar_any_length() {
+        let n = u32::from(small::ORDER);
+
+        for &wide in &[
+            &[][..],
+            &[250],
+            &[251],
+            &[0xff],
+            &[1, 0],
+            &[0xab, 0xcd, 0xef],
+        ] {
+            let expected = wide
+                .iter()
+                .fold(0, |acc, &b| (acc * 256 + u32::from(b)) % n);
+            let scalar = reduce_bytes_to_scalar::<SmallCurve>(wide);
+            assert_eq!(u32::from(scalar.0), expected, "{:?}", wide);
+        }
+    }
+
+    #[test]
+    fn reduce_bytes_to_scalar_distribution() {
+        let mut counts = [0u32; small::ORDER as usize];
+
+        for wide in 0..=core::u16::MAX {
+            counts[usize::from(reduce_bytes_to_scalar::<SmallCurve>(&wide.to_be_bytes()).0)] += 1;
+        }
+
+        // Every scalar is hit, and a double-width input is spread evenly
+        // across the whole range: 2¹⁶ = 261 × 251 + 25.
+        let min = *counts.iter().min().unwrap();
+        let max = *counts.iter().max().unwrap();
+        assert_eq!((min, max), (261, 262));
+        assert_eq!(counts.iter().filter(|&&count| count == max).count(), 25);
+    }
+
+    #[test]
+    fn invert_scalar_ct_p256() {
+        for &(s, expected) in &[
+            (
+                hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+                hex!("0000000000000000000000000000000000000000000000000000000000000001"),
+            ),
+            (
+                hex!("0000000000000000000000000000000000000000000000000000000000000002"),
+                hex!("7FFFFFFF800000007FFFFFFFFFFFFFFFDE737D56D38BCF4279DCE5617E3192A9"),
+            ),
+            (
+                hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632550"),
+                hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632550"),
+            ),
+            (
+                SECRET_KEY,
+                hex!("FF24A4EEB2B46CEE2BF82C197F40FC7F2B34205E3CBF997C4A9C6F32EC7E38D4"),
+            ),
+        ] {
+            let s = Scalar::from_bytes(&s.into()).unwrap();
+            let inverse = invert_scalar_ct::<NistP256>(&s).unwrap();
+            assert_eq!(ElementBytes::<NistP256>::from(inverse).as_slice(), expected);
+        }
+
+        assert!(bool::from(
+            invert_scalar_ct::<NistP256>(&Scalar::zero()).is_none()
+        ));
+    }
+
+    #[test]
+    fn small_curve_point_arithmetic_fails_cleanly() {
+        let (d, k) = (small::Scalar(1), small::Scalar(2));
+        assert!(sign_prehashed::<SmallCurve>(&d, &k, &[3].into()).is_err());
+    }
+
+    #[test]
+    fn invert_scalar_ct_small() {
+        for a in 1..small::ORDER {
+            let a = small::Scalar(a);
+            let inverse = invert_scalar_ct::<SmallCurve>(&a).unwrap();
+            assert_eq!(SmallCurve::mul_scalars(&a, &inverse), small::Scalar(1));
+        }
+
+        assert!(bool::from(
+            invert_scalar_ct::<SmallCurve>(&small::Scalar(0)).is_none()
+        ));
+    }
+
+    /// Coarse check that inversion time doesn't depend on the input: the
+    /// fastest of several batches of inversions of a sparse scalar and of a
+    /// dense one should take a similar time.
+    ///
+    /// This measures `invert_scalar_ct` over the `p256` crate's scalar
+    /// multiplication. Wall-clock timings are unreliable on shared machines, so
+    /// it's ignored by default: run it with `cargo test -- --ignored`.
+    #[test]
+    #[ignore]
+    fn invert_scalar_ct_timing() {
+        use std::time::Instant;
+
+        const BATCHES: usize = 50;
+        const INVERSIONS: usize = 100;
+
+        // Accumulate the results so the inversions can't be optimized away
+        let mut acc = 0u8;
+
+        let mut time = |s: &Scalar| {
+            (0..BATCHES)
+                .map(|_| {
+                    let start = Instant::now();
+
+                    for _ in 0..INVERSIONS {
+                        let inverse = invert_scalar_ct::<NistP256>(s).unwrap();
+                        acc ^= ElementBytes::<NistP256>::from(inverse)[31];
+                    }
+
+                    start.elapsed()
+                })
+                .min()
+                .unwrap()
+        };
+
+        let sparse = time(&Scalar::one());
+        let dense = time(&Scalar::from_bytes(&SECRET_KEY.into()).unwrap());
+        let ratio = dense.as_secs_f64() / sparse.as_secs_f64();
+        assert!(
+            0.5 < ratio && ratio < 2.0,
+            "timing ratio {} ({})",
+            ratio,
+            acc
+        );
+    }
+
+    #[test]
+    fn hash_to_scalar_p256() {
+        for (digest, expected) in &[
+            (
+                &Sha256::digest(b"sample")[..],
+                hex!("AF2BDBE1AA9B6EC1E2ADE1D694F41FC71A831D0268E9891562113D8A62ADD1BF"),
+            ),
+            (
+                &Sha512::digest(b"sample")[..],
+                hex!("39A5E04AAFF7455D9850C605364F514C11324CE64016960D23D5DC57D3FFD8F4"),
+            ),
+            (
+                &[0xff; 32][..],
+                hex!("00000000FFFFFFFF00000000000000004319055258E8617B0C46353D039CDAAE"),
+            ),
+            (
+                &hex!("0102")[..],
+                hex!("0000000000000000000000000000000000000000000000000000000000000102"),
+            ),
+        ] {
+            let scalar = hash_to_scalar::<NistP256>(digest);
+            assert_eq!(ElementBytes::<NistP256>::from(scalar).as_slice(), expected);
+        }
+    }
+
+    #[test]
+    fn bits2int_truncates_to_leftmost_bits() {
+        let digest = Sha512::digest(b"sample");
+
+        assert_eq!(
+            bits2int::<NistP256>(&digest, 256).as_slice(),
+            &hex!("39a5e04aaff7455d9850c605364f514c11324ce64016960d23d5dc57d3ffd8f4")
+        );
+
+        // Order bit length which is not a multiple of 8
+        assert_eq!(
+            bits2int::<NistP256>(&digest, 252).as_slice(),
+            &hex!("039a5e04aaff7455d9850c605364f514c11324ce64016960d23d5dc57d3ffd8f")
+        );
+    }
+
+    #[test]
+    fn bits2int_left_pads_short_digests() {
+        let expected = hex!("000000000000000000000000000000000000000000000000000000000000ff01");
+        assert_eq!(
+            bits2int::<NistP256>(&hex!("ff01"), 252).as_slice(),
+            &expected
+        );
+        assert_eq!(bits2field::<NistP256>(&hex!("ff01")).as_slice(), &expected);
+    }
+
+    /// RFC 6979 A.2.5: P-256, SHA-512, message = "sample"
+    #[test]
+    fn verify_prehashed_sha512() {
+        let signature = Signature::from_scalars(
+            &hex!("8496A60B5E9B47C825488827E0495B0E3FA109EC4568FD3F8D1097678EB97F00").into(),
+            &hex!("2362AB1ADBE2B8ADF9CB9EDAB740EA6049C028114F2460F96554F61FAE3302FE").into(),
+        );
+        let prehash = bits2field::<NistP256>(&Sha512::digest(b"sample"));
+        assert!(verify_prehashed::<NistP256>(&public_key(), &prehash, &signature).is_ok());
+    }
+
+    /// RFC 6979 A.2.5: P-256, SHA-256, message = "sample"
+    #[cfg(feature = "recoverable")]
+    #[test]
+    fn sign_prehashed_recoverable_sample() {
+        let k = Scalar::from_bytes(
+            &hex!("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60").into(),
+        )
+        .unwrap();
+
+        let prehash = Sha256::digest(b"sample");
+        let signature =
+            sign_prehashed_recoverable::<NistP256>(&secret_scalar(), &k, &prehash).unwrap();
+        assert_eq!(signature.signature().as_ref(), &SIGNATURE[..]);
+
+        let r_point = UncompressedPoint::from(NistP256::mul_base(&k).unwrap());
+        let recovery_id = signature.recovery_id();
+        assert!(!recovery_id.is_x_reduced());
+        assert_eq!(recovery_id.is_y_odd(), r_point.as_bytes()[64] & 1 == 1);
+
+        assert_eq!(
+            signature
+                .recover_verifying_key_from_prehash(&prehash)
+                .unwrap(),
+            PublicKey::from_bytes(&PUBLIC_KEY[..]).unwrap()
+        );
+    }
+
+    #[cfg(feature = "recoverable")]
+    #[test]
+    fn sign_prehashed_recoverable_x_reduced() {
+        let secret_scalar = reduced::Scalar(7);
+        let public_key = ReducedCurve::mul_base(&secret_scalar).unwrap();
+        let prehash = [42].into();
+        let mut x_reduced_count = 0;
+
+        // Try every nonce, some of which give an x-coordinate of `𝐑` not
+        // less than `n`
+        for k in 1..reduced::ORDER {
+            let k = reduced::Scalar(k);
+            let signature =
+                match sign_prehashed_recoverable::<ReducedCurve>(&secret_scalar, &k, &prehash) {
+                    Ok(signature) => signature,
+                    Err(_) => continue,
+                };
+
+            let r_point = ReducedCurve::mul_base(&k).unwrap();
+            let recovery_id = signature.recovery_id();
+            assert_eq!(recovery_id.is_x_reduced(), r_point.x >= reduced::ORDER);
+            assert_eq!(recovery_id.is_y_odd(), r_point.y & 1 == 1);
+            x_reduced_count += recovery_id.is_x_reduced() as usize;
+
+            let recovered_key = signature
+                .recover_verifying_key_from_prehash(&prehash)
+                .unwrap();
+            assert_eq!(
+                recovered_key.as_bytes(),
+                &[0x04, public_key.x, public_key.y][..]
+            );
+        }
+
+        assert!(x_reduced_count > 0);
+
+        // `5×𝑮 = (241, 59)`, so `r = 8` and both bits are set
+        let signature = sign_prehashed_recoverable::<ReducedCurve>(
+            &secret_scalar,
+            &reduced::Scalar(5),
+            &prehash,
+        )
+        .unwrap();
+        assert_eq!(signature.signature().r()[0], 8);
+        assert_eq!(signature.recovery_id(), RecoveryId::new(3).unwrap());
+
+        // A point which doesn't correspond to `r` matches none of the IDs
+        assert!(RecoveryId::from_signature_and_key::<ReducedCurve>(
+            &signature.signature(),
+            &public_key
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn check_subgroup_cofactor_curve() {
+        for k in 1..cofactor::ORDER {
+            let point = CofactorCurve::mul_base(&cofactor::Scalar(k)).unwrap();
+            assert!(check_subgroup::<CofactorCurve>(&point).is_ok());
+        }
+
+        let point = CofactorCurve::mul_base(&cofactor::Scalar(7)).unwrap();
+        let offset_point = CofactorCurve::add_points(&point, &cofactor::SMALL_ORDER_POINT).unwrap();
+
+        for point in &[cofactor::SMALL_ORDER_POINT, offset_point] {
+            assert!(check_subgroup::<CofactorCurve>(point).is_err());
+        }
+
+        // Prime-order curves skip the check
+        assert!(check_subgroup::<NistP256>(&public_key()).is_ok());
+    }
+
+    #[test]
+    fn verify_prehashed_rejects_small_subgroup_key() {
+        let secret_scalar = cofactor::Scalar(7);
+        let public_key = CofactorCurve::mul_base(&secret_scalar).unwrap();
+        let prehash = [0x2a].into();
+        let signature =
+            sign_prehashed::<CofactorCurve>(&secret_scalar, &cofactor::Scalar(11), &prehash)
+                .unwrap();
+
+        assert!(verify_prehashed::<CofactorCurve>(&public_key, &prehash, &signature).is_ok());
+
+        let offset_key =
+            CofactorCurve::add_points(&public_key, &cofactor::SMALL_ORDER_POINT).unwrap();
+
+        for malicious_key in &[cofactor::SMALL_ORDER_POINT, offset_key] {
+            assert!(
+                verify_prehashed::<CofactorCurve>(malicious_key, &prehash, &signature).is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn multiscalar_mul_vartime_p256() {
+        // Enough terms to span more than one chunk
+        let terms = (1..=11u8)
+            .map(|i| {
+                let point = NistP256::mul_base(&NistP256::reduce_scalar(&Sha256::digest(&[i])));
+                let scalar = NistP256::reduce_scalar(&Sha256::digest(&[i, i]));
+                (point.unwrap(), scalar)
+            })
+            .collect::<std::vec::Vec<_>>();
+
+        for len in 0..=terms.len() {
+            let expected = terms[..len].iter().fold(None, |sum, (point, scalar)| {
+                let term = NistP256::mul_point(point, scalar).unwrap();
+
+                match sum {
+                    Some(sum) => Some(NistP256::add_points(&sum, &term).unwrap()),
+                    None => Some(term),
+                }
+            });
+
+            let sum = multiscalar_mul_vartime::<NistP256, _>(terms[..len].iter().cloned());
+
+            match expected {
+                Some(expected) => assert!(
+                    p256::UncompressedPoint::from(sum.unwrap())
+                        == p256::UncompressedPoint::from(expected)
+                ),
+                None => assert!(bool::from(sum.is_none())),
+            }
+        }
+    }
+
+    #[test]
+    fn multiscalar_mul_vartime_cofactor_curve() {
+        let generator = CofactorCurve::mul_base(&cofactor::Scalar(1)).unwrap();
+
+        // The sum of multiples of the generator is `(∑ kᵢ)×𝑮`
+        for a in 0..cofactor::ORDER {
+            for b in &[0, 1, 30, cofactor::ORDER - a] {
+                let terms = [
+                    (generator, cofactor::Scalar(a)),
+                    (generator, cofactor::Scalar(b % cofactor::ORDER)),
+                    (generator, cofactor::Scalar(a)),
+                ];
+
+                let sum = multiscalar_mul_vartime::<CofactorCurve, _>(terms.iter().cloned());
+                let k = (2 * u16::from(a) + u16::from(*b)) % u16::from(cofactor::ORDER);
+
+                let expected = CofactorCurve::mul_base(&cofactor::Scalar(k as u8));
+                assert_eq!(bool::from(sum.is_some()), bool::from(expected.is_some()));
+
+                if expected.is_some().into() {
+                    assert_eq!(sum.unwrap(), expected.unwrap());
+                }
+            }
+        }
+
+        // Points outside the base point's subgroup are handled as well
+        let terms = [
+            (cofactor::SMALL_ORDER_POINT, cofactor::Scalar(3)),
+            (generator, cofactor::Scalar(5)),
+        ];
+        let sum = multiscalar_mul_vartime::<CofactorCurve, _>(terms.iter().cloned());
+        let expected = CofactorCurve::add_points(
+            &cofactor::SMALL_ORDER_POINT,
+            &CofactorCurve::mul_base(&cofactor::Scalar(5)).unwrap(),
+        );
+        assert_eq!(sum.unwrap(), expected.unwrap());
+    }
+}
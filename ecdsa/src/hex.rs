@@ -0,0 +1,69 @@
+//! Hexadecimal encoding support for signatures (no heap allocation required)
+
+use crate::{Error, ErrorKind};
+use core::{fmt, str};
+
+/// Displays the wrapped bytes as lowercase hexadecimal
+pub(crate) struct Hex<'a>(pub(crate) &'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Encode `bytes` as lowercase hexadecimal into the beginning of `out`,
+/// which must be at least twice the length of the input, returning the
+/// encoded prefix as a string.
+pub(crate) fn encode<'a>(bytes: &[u8], out: &'a mut [u8]) -> Result<&'a str, Error> {
+    let hex_len = bytes.len().checked_mul(2).unwrap();
+
+    if out.len() < hex_len {
+        return Err(ErrorKind::InvalidLength.into());
+    }
+
+    let hex = &mut out[..hex_len];
+
+    for (byte, digits) in bytes.iter().zip(hex.chunks_mut(2)) {
+        digits[0] = encode_nibble(byte >> 4);
+        digits[1] = encode_nibble(byte & 0xf);
+    }
+
+    str::from_utf8(hex).map_err(|_| ErrorKind::InvalidEncoding.into())
+}
+
+/// Encode a single nibble as a lowercase hexadecimal digit
+fn encode_nibble(nibble: u8) -> u8 {
+    match nibble {
+        0..=9 => b'0' + nibble,
+        _ => b'a' + nibble - 10,
+    }
+}
+
+/// Decode hexadecimal from `hex` into `out`, which must be exactly half the
+/// length of the input. Accepts both lower and upper case digits.
+pub(crate) fn decode(hex: &[u8], out: &mut [u8]) -> Result<(), Error> {
+    if hex.len() != out.len().checked_mul(2).unwrap() {
+        return Err(ErrorKind::InvalidLength.into());
+    }
+
+    for (digits, byte) in hex.chunks(2).zip(out.iter_mut()) {
+        *byte = (decode_nibble(digits[0])? << 4) | decode_nibble(digits[1])?;
+    }
+
+    Ok(())
+}
+
+/// Decode a single hexadecimal digit
+fn decode_nibble(digit: u8) -> Result<u8, Error> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(ErrorKind::InvalidEncoding.into()),
+    }
+}
@@ -0,0 +1,940 @@
+//! High-level ECDSA signing and verifying keys. Generic over elliptic curves.
+//!
+//! These types pair a serialized key with the [`Signer`] or [`Verifier`] for
+//! it, providing a consistent entry point for curve crates to re-export.
+
+use crate::{hazmat::DigestPrimitive, Error, ErrorKind, NormalizeLow, Signature, SignatureSize};
+use elliptic_curve::{generic_array::ArrayLength, weierstrass::Curve, Arithmetic};
+use signature::digest::Digest;
+
+#[cfg(feature = "signer")]
+use {
+    crate::{
+        hazmat::{CurveArithmetic, SignPrimitive},
+        signer::{random_nonzero_scalar, PrehashSigner, Signer, SigningOptions, SigningStream},
+    },
+    elliptic_curve::{
+        ops::Invert,
+        rand_core::{CryptoRng, RngCore},
+        zeroize::Zeroize,
+        ElementBytes, SecretKey,
+    },
+    hmac::digest::{BlockInput, FixedOutput, Reset, Update},
+    signature::DigestSigner,
+};
+
+#[cfg(feature = "verifier")]
+use {
+    crate::{
+        hazmat::VerifyPrimitive,
+        verifier::{PrehashVerifier, Verifier, VerifyingStream},
+    },
+    core::ops::Add,
+    elliptic_curve::{
+        consts::U1,
+        weierstrass::{
+            point::{CompressedPointSize, UncompressedPoint, UncompressedPointSize},
+            public_key::{FromPublicKey, PublicKey},
+        },
+    },
+    signature::DigestVerifier,
+};
+
+#[cfg(all(feature = "verifier", feature = "rand", feature = "recoverable"))]
+use {
+    crate::{
+        recoverable,
+        verifier::batch::{verify_same_key, BatchError},
+    },
+    signature::rand_core,
+};
+
+#[cfg(all(feature = "signer", feature = "verifier"))]
+use {
+    core::ops::Mul,
+    elliptic_curve::{scalar::NonZeroScalar, weierstrass::point::CompressedPoint},
+};
+
+/// ECDSA signing key: a [`SecretKey`] along with its [`Signer`]
+#[cfg(feature = "signer")]
+#[cfg_attr(docsrs, doc(cfg(feature = "signer")))]
+pub struct SigningKey<C>
+where
+    C: Curve + Arithmetic,
+    C::Scalar: Invert<Output = C::Scalar> + SignPrimitive<C> + Zeroize,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    secret_key: SecretKey<C>,
+    signer: Signer<C>,
+}
+
+#[cfg(feature = "signer")]
+impl<C> SigningKey<C>
+where
+    C: Curve + Arithmetic,
+    C::Scalar: Invert<Output = C::Scalar> + SignPrimitive<C> + Zeroize,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Initialize a signing key from a serialized secret scalar, returning an
+    /// error if it's the wrong length or not a valid scalar
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let secret_key =
+            SecretKey::from_bytes(bytes).map_err(|_| Error::from(ErrorKind::InvalidLength))?;
+        let signer = Signer::new(&secret_key)?;
+        Ok(Self { secret_key, signer })
+    }
+
+    /// Generate a random signing key.
+    ///
+    /// The secret scalar is sampled uniformly from `[1, n)` by rejection
+    /// sampling, i.e. without modulo bias: candidates which are zero or not
+    /// less than `n` are discarded and resampled.
+    pub fn random(rng: impl CryptoRng + RngCore) -> Self {
+        let secret_scalar = random_nonzero_scalar::<C, _>(rng);
+        let mut bytes: ElementBytes<C> = (*secret_scalar).into();
+        let signing_key = Self::from_bytes(&bytes).expect("random scalar is a valid secret key");
+        bytes.as_mut_slice().zeroize();
+        signing_key
+    }
+
+    /// Serialize this signing key's secret scalar.
+    ///
+    /// The returned bytes are secret, and should be zeroized after use!
+    pub fn to_bytes(&self) -> ElementBytes<C> {
+        self.secret_key.as_bytes().clone()
+    }
+
+    /// Create a [`SigningStream`] which signs a message fed to it in chunks
+    /// using this key, hashed using the digest `D`
+    pub fn signing_stream<D>(&self) -> SigningStream<'_, C, D>
+    where
+        C: CurveArithmetic,
+        D: BlockInput + FixedOutput + Reset + Update + Clone + Default,
+    {
+        SigningStream::new(&self.signer)
+    }
+
+    /// Get the [`VerifyingKey`] which corresponds to this signing key
+    #[cfg(feature = "verifier")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "verifier")))]
+    pub fn verifying_key(&self) -> VerifyingKey<C>
+    where
+        C::AffinePoint:
+            Mul<NonZeroScalar<C>, Output = C::AffinePoint> + VerifyPrimitive<C> + FromPublicKey<C>,
+        C::ElementSize: Add<U1>,
+        <C::ElementSize as Add>::Output: Add<U1>,
+        CompressedPoint<C>: From<C::AffinePoint>,
+        UncompressedPoint<C>: From<C::AffinePoint>,
+        CompressedPointSize<C>: ArrayLength<u8>,
+        UncompressedPointSize<C>: ArrayLength<u8>,
+    {
+        let public_key = PublicKey::from_secret_key(&self.secret_key, C::COMPRESS_POINTS)
+            .expect("secret key is a valid nonzero scalar");
+
+        VerifyingKey::from_public_key(public_key).expect("public key is a valid point")
+    }
+}
+
+#[cfg(feature = "signer")]
+impl<C> SigningKey<C>
+where
+    C: CurveArithmetic + DigestPrimitive,
+    C::Digest: BlockInput + FixedOutput + Reset + Update + Clone + Default,
+    C::Scalar: Invert<Output = C::Scalar> + NormalizeLow + SignPrimitive<C> + Zeroize,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Sign the given message, configured by the given [`SigningOptions`].
+    ///
+    /// See [`Signer::sign_with_options`].
+    pub fn sign_with_options(
+        &self,
+        msg: &[u8],
+        options: &SigningOptions<'_>,
+    ) -> Result<Signature<C>, Error> {
+        self.signer.sign_with_options(msg, options)
+    }
+
+    /// Sign the given message, configured by the given [`SigningOptions`],
+    /// drawing randomness from `rng` if non-deterministic signing was
+    /// requested.
+    ///
+    /// See [`Signer::sign_with_options_and_rng`].
+    pub fn sign_with_options_and_rng(
+        &self,
+        rng: impl CryptoRng + RngCore,
+        msg: &[u8],
+        options: &SigningOptions<'_>,
+    ) -> Result<Signature<C>, Error> {
+        self.signer.sign_with_options_and_rng(rng, msg, options)
+    }
+}
+
+#[cfg(feature = "signer")]
+impl<C, D> DigestSigner<D, Signature<C>> for SigningKey<C>
+where
+    C: CurveArithmetic,
+    D: BlockInput + FixedOutput + Reset + Update + Clone + Default,
+    C::Scalar: Invert<Output = C::Scalar> + SignPrimitive<C> + Zeroize,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn try_sign_digest(&self, digest: D) -> Result<Signature<C>, Error> {
+        self.signer.try_sign_digest(digest)
+    }
+}
+
+#[cfg(feature = "signer")]
+impl<C> PrehashSigner<Signature<C>> for SigningKey<C>
+where
+    C: CurveArithmetic + DigestPrimitive,
+    C::Digest: BlockInput + FixedOutput + Reset + Update + Clone + Default,
+    C::Scalar: Invert<Output = C::Scalar> + SignPrimitive<C> + Zeroize,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn sign_prehash(&self, prehash: &[u8]) -> Result<Signature<C>, Error> {
+        self.signer.sign_prehash(prehash)
+    }
+}
+
+#[cfg(feature = "signer")]
+impl<C> signature::Signer<Signature<C>> for SigningKey<C>
+where
+    C: CurveArithmetic + DigestPrimitive,
+    C::Digest: BlockInput + FixedOutput + Reset + Update + Clone + Default,
+    C::Scalar: Invert<Output = C::Scalar> + SignPrimitive<C> + Zeroize,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Sign the given message using the curve's preferred digest, with a
+    /// deterministic ephemeral scalar generated as described in RFC 6979
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature<C>, Error> {
+        self.try_sign_digest(Digest::chain(C::Digest::new(), msg))
+    }
+}
+
+/// Association between a signer and the key which verifies its signatures.
+///
+/// This allows generic code to obtain the public key for any signer which
+/// has one, without knowing how to derive it from the signer's secret.
+#[cfg(all(feature = "signer", feature = "verifier"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "signer", feature = "verifier"))))]
+pub trait Keypair<S: signature::Signature> {
+    /// Key used to verify signatures produced by this signer
+    type VerifyingKey: signature::Verifier<S>;
+
+    /// Get the verifying key which corresponds to this signer.
+    ///
+    /// This may compute the verifying key on each call (e.g. `Q = d×𝑮` for
+    /// [`SigningKey`]), so generic code should call it once and reuse the
+    /// result where possible.
+    fn verifying_key(&self) -> Self::VerifyingKey;
+}
+
+#[cfg(all(feature = "signer", feature = "verifier"))]
+impl<C> Keypair<Signature<C>> for SigningKey<C>
+where
+    C: Curve + Arithmetic + DigestPrimitive,
+    C::Digest: Digest<OutputSize = C::ElementSize>,
+    C::Scalar: Invert<Output = C::Scalar> + SignPrimitive<C> + Zeroize,
+    C::AffinePoint:
+        Mul<NonZeroScalar<C>, Output = C::AffinePoint> + VerifyPrimitive<C> + FromPublicKey<C>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPoint<C>: From<C::AffinePoint>,
+    UncompressedPoint<C>: From<C::AffinePoint>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    type VerifyingKey = VerifyingKey<C>;
+
+    fn verifying_key(&self) -> VerifyingKey<C> {
+        SigningKey::verifying_key(self)
+    }
+}
+
+/// ECDSA verifying key: a SEC1-encoded [`PublicKey`] along with its
+/// [`Verifier`]
+///
+/// The public key is decoded into an affine point and checked to be on the
+/// curve once, when the verifying key is constructed. The decoded point is
+/// then reused by every verification, amortizing the decoding cost when many
+/// signatures are checked against the same key.
+#[cfg(feature = "verifier")]
+#[cfg_attr(docsrs, doc(cfg(feature = "verifier")))]
+pub struct VerifyingKey<C>
+where
+    C: Curve + Arithmetic,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    public_key: PublicKey<C>,
+    verifier: Verifier<C>,
+}
+
+#[cfg(feature = "verifier")]
+impl<C> VerifyingKey<C>
+where
+    C: Curve + Arithmetic,
+    C::AffinePoint: VerifyPrimitive<C> + FromPublicKey<C>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Initialize a verifying key from a SEC1-encoded public key (either
+    /// compressed or uncompressed), returning an error if it's malformed or
+    /// not a point on the curve
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        PublicKey::from_bytes(bytes)
+            .ok_or_else(|| Error::from(ErrorKind::InvalidPoint))
+            .and_then(Self::from_public_key)
+    }
+
+    /// Initialize a verifying key from a [`PublicKey`], returning an error if
+    /// it's not a point on the curve
+    pub fn from_public_key(public_key: PublicKey<C>) -> Result<Self, Error> {
+        let verifier = Verifier::new(&public_key)?;
+        Ok(Self {
+            public_key,
+            verifier,
+        })
+    }
+
+    /// Serialize this verifying key as a SEC1-encoded [`PublicKey`]
+    pub fn to_bytes(&self) -> PublicKey<C> {
+        self.public_key.clone()
+    }
+
+    /// Create a [`VerifyingStream`] which verifies signatures by this key
+    /// over a message fed to it in chunks, hashed using the digest `D`
+    pub fn verifying_stream<D>(&self) -> VerifyingStream<C, D>
+    where
+        D: Digest<OutputSize = C::ElementSize>,
+    {
+        VerifyingStream::new(&self.verifier)
+    }
+
+    /// Initialize a verifying key from a SEC1-encoded public key (either
+    /// compressed or uncompressed), returning an error if it's malformed or
+    /// not a point on the curve.
+    ///
+    /// Equivalent to [`VerifyingKey::from_bytes`].
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Self::from_bytes(bytes)
+    }
+
+    /// Serialize this verifying key as a SEC1-encoded [`PublicKey`] in the
+    /// requested form, regardless of the form it was constructed from
+    pub fn to_sec1_bytes(&self, compressed: bool) -> PublicKey<C>
+    where
+        UncompressedPoint<C>: From<C::AffinePoint>,
+    {
+        let point = *self.affine_point();
+        let mut public_key = PublicKey::from(UncompressedPoint::from(point));
+
+        if compressed {
+            public_key.compress();
+        }
+
+        public_key
+    }
+}
+
+#[cfg(feature = "verifier")]
+impl<C> VerifyingKey<C>
+where
+    C: Curve + Arithmetic,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    /// Get the decoded affine point of this verifying key
+    pub(crate) fn affine_point(&self) -> &C::AffinePoint {
+        self.verifier.affine_point()
+    }
+}
+
+#[cfg(all(feature = "verifier", feature = "rand", feature = "recoverable"))]
+impl<C> VerifyingKey<C>
+where
+    C: crate::hazmat::CurveArithmetic + DigestPrimitive,
+    C::AffinePoint: FromPublicKey<C>,
+    C::Digest: Digest<OutputSize = C::ElementSize>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPoint<C>: From<C::AffinePoint>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    recoverable::SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Verify a batch of signatures over the given messages which were all
+    /// produced by this key, using the provided RNG to select the random
+    /// scalars used to combine them.
+    ///
+    /// As the key is shared, the multi-scalar multiplication has one term per
+    /// signature, rather than the two per signature of [`BatchVerifier`].
+    /// See the [`batch`] module for details, including why recoverable
+    /// signatures are required.
+    ///
+    /// Plain [`Signature`]s can be converted using
+    /// [`recoverable::Signature::from_trial_recovery`]. Unlike
+    /// [`BatchVerifier`], this doesn't allocate, so it's also available
+    /// without the `alloc` feature.
+    ///
+    /// If the batch fails to verify, the returned [`BatchError`] identifies
+    /// the first invalid signature.
+    ///
+    /// [`BatchVerifier`]: crate::verifier::batch::BatchVerifier
+    /// [`batch`]: crate::verifier::batch
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "rand", feature = "recoverable"))))]
+    pub fn verify_batch(
+        &self,
+        batch: &[(&[u8], recoverable::Signature<C>)],
+        rng: impl rand_core::CryptoRng + rand_core::RngCore,
+    ) -> Result<(), BatchError> {
+        verify_same_key(self.affine_point(), batch, rng)
+    }
+}
+
+#[cfg(feature = "verifier")]
+impl<C> VerifyingKey<C>
+where
+    C: Curve + Arithmetic + DigestPrimitive,
+    C::Scalar: NormalizeLow,
+    C::AffinePoint: VerifyPrimitive<C>,
+    C::Digest: Digest<OutputSize = C::ElementSize>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Verify the signature over the given message, additionally rejecting
+    /// signatures which aren't in "low S" form as malleable (as required by
+    /// e.g. [BIP 146][1]).
+    ///
+    /// The [`signature::Verifier`] impl for this type accepts both forms.
+    ///
+    /// [1]: https://github.com/bitcoin/bips/blob/master/bip-0146.mediawiki
+    pub fn verify_strict(&self, msg: &[u8], signature: &Signature<C>) -> Result<(), Error> {
+        signature::Verifier::verify(self, msg, signature)?;
+
+        if signature.is_low_s()? {
+            Ok(())
+        } else {
+            Err(ErrorKind::HighS.into())
+        }
+    }
+}
+
+#[cfg(feature = "verifier")]
+impl<C> VerifyingKey<C>
+where
+    C: Curve + Arithmetic + crate::CurveOrder + DigestPrimitive,
+    C::AffinePoint: VerifyPrimitive<C>,
+    C::Digest: Digest<OutputSize = C::ElementSize>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Verify the signature over the given message against each of the
+    /// candidate keys in turn, returning the index of the first key which
+    /// successfully verifies it (e.g. when keys are being rotated).
+    ///
+    /// The message is only hashed once, and no further keys are tried after
+    /// a match is found. Returns an [`ErrorKind::VerificationFailed`] error if
+    /// no key verifies the signature (including when `keys` is empty).
+    pub fn verify_any(keys: &[Self], msg: &[u8], signature: &Signature<C>) -> Result<usize, Error> {
+        let hashed_msg = C::Digest::digest(msg);
+
+        keys.iter()
+            .position(|key| key.verify_prehash(&hashed_msg, signature).is_ok())
+            .ok_or_else(|| ErrorKind::VerificationFailed.into())
+    }
+}
+
+#[cfg(feature = "verifier")]
+impl<C, D> DigestVerifier<D, Signature<C>> for VerifyingKey<C>
+where
+    C: Curve + Arithmetic,
+    D: Digest<OutputSize = C::ElementSize>,
+    C::AffinePoint: VerifyPrimitive<C>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn verify_digest(&self, digest: D, signature: &Signature<C>) -> Result<(), Error> {
+        self.verifier.verify_digest(digest, signature)
+    }
+}
+
+#[cfg(feature = "verifier")]
+impl<C> PrehashVerifier<Signature<C>> for VerifyingKey<C>
+where
+    C: Curve + Arithmetic + crate::CurveOrder,
+    C::AffinePoint: VerifyPrimitive<C>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn verify_prehash(&self, prehash: &[u8], signature: &Signature<C>) -> Result<(), Error> {
+        self.verifier.verify_prehash(prehash, signature)
+    }
+}
+
+#[cfg(feature = "verifier")]
+impl<C> signature::Verifier<Signature<C>> for VerifyingKey<C>
+where
+    C: Curve + Arithmetic + DigestPrimitive,
+    C::AffinePoint: VerifyPrimitive<C>,
+    C::Digest: Digest<OutputSize = C::ElementSize>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn verify(&self, msg: &[u8], signature: &Signature<C>) -> Result<(), Error> {
+        self.verifier.verify(msg, signature)
+    }
+}
+
+#[cfg(all(test, feature = "signer", feature = "verifier"))]
+mod tests {
+    use super::{Keypair, SigningKey, SigningOptions, VerifyingKey};
+    use crate::test_curves::TestRng;
+    use crate::NormalizedSigner;
+    use elliptic_curve::{weierstrass::PublicKey, FromBytes};
+    use hex_literal::hex;
+    use k256::Secp256k1;
+    use p256::NistP256;
+    use signature::{Signature, Signer, Verifier};
+
+    /// Secret key from RFC 6979 A.2.5 (P-256)
+    const SECRET_KEY: [u8; 32] =
+        hex!("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+
+    /// Public key from RFC 6979 A.2.5 (P-256)
+    const PUBLIC_KEY: [u8; 65] = hex!(
+        "04 60FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6
+            7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299"
+    );
+
+    /// RFC 6979 A.2.5 signature of "sample" using SHA-256
+    const SIGNATURE: [u8; 64] = hex!(
+        "EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716
+         F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8"
+    );
+
+    /// Compile-time check that keys and signatures for the standard curves
+    /// can be shared between threads
+    const _: fn() = || {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<crate::Signature<NistP256>>();
+        assert_send_sync::<SigningKey<NistP256>>();
+        assert_send_sync::<VerifyingKey<NistP256>>();
+        assert_send_sync::<crate::Signature<Secp256k1>>();
+        assert_send_sync::<SigningKey<Secp256k1>>();
+        assert_send_sync::<VerifyingKey<Secp256k1>>();
+    };
+
+    #[test]
+    fn signing_key_random() {
+        let mut rng = TestRng(42);
+        let mut keys = [elliptic_curve::ElementBytes::<NistP256>::default(); 32];
+
+        for key in keys.iter_mut() {
+            *key = SigningKey::<NistP256>::random(&mut rng).to_bytes();
+        }
+
+        for (i, key) in keys.iter().enumerate() {
+            // In `[1, n)`
+            let scalar = p256::Scalar::from_bytes(key);
+            assert!(bool::from(scalar.is_some()));
+            assert_ne!(key.as_slice(), &[0u8; 32][..]);
+
+            // Distinct from every other key
+            assert!(keys[..i].iter().all(|other| other != key));
+        }
+
+        let signing_key = SigningKey::<NistP256>::random(&mut rng);
+        let signature: crate::Signature<NistP256> = signing_key.sign(b"example message");
+        assert!(signing_key
+            .verifying_key()
+            .verify(b"example message", &signature)
+            .is_ok());
+    }
+
+    #[test]
+    fn signing_key_round_trip() {
+        let signing_key = SigningKey::<NistP256>::from_bytes(&SECRET_KEY).unwrap();
+        assert_eq!(signing_key.to_bytes().as_slice(), &SECRET_KEY[..]);
+    }
+
+    #[test]
+    fn signing_key_rejects_invalid_bytes() {
+        assert!(SigningKey::<NistP256>::from_bytes(&SECRET_KEY[..31]).is_err());
+        assert!(SigningKey::<NistP256>::from_bytes(&[0xFF; 32]).is_err());
+    }
+
+    #[test]
+    fn verifying_key() {
+        let signing_key = SigningKey::<NistP256>::from_bytes(&SECRET_KEY).unwrap();
+        let verifying_key = signing_key.verifying_key();
+        assert_eq!(verifying_key.to_bytes().as_bytes(), &PUBLIC_KEY[..]);
+
+        let verifying_key = VerifyingKey::<NistP256>::from_bytes(&PUBLIC_KEY).unwrap();
+        assert_eq!(verifying_key.to_bytes().as_bytes(), &PUBLIC_KEY[..]);
+    }
+
+    #[test]
+    fn verifying_key_sec1_bytes() {
+        const COMPRESSED_PUBLIC_KEY: [u8; 33] =
+            hex!("0360FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6");
+
+        for bytes in &[&PUBLIC_KEY[..], &COMPRESSED_PUBLIC_KEY[..]] {
+            let verifying_key = VerifyingKey::<NistP256>::from_sec1_bytes(bytes).unwrap();
+            assert_eq!(verifying_key.to_bytes().as_bytes(), *bytes);
+            assert_eq!(
+                verifying_key.to_sec1_bytes(false).as_bytes(),
+                &PUBLIC_KEY[..]
+            );
+            assert_eq!(
+                verifying_key.to_sec1_bytes(true).as_bytes(),
+                &COMPRESSED_PUBLIC_KEY[..]
+            );
+        }
+
+        let mut not_on_curve = PUBLIC_KEY;
+        not_on_curve[64] ^= 1;
+        assert!(VerifyingKey::<NistP256>::from_sec1_bytes(&not_on_curve).is_err());
+        assert!(VerifyingKey::<NistP256>::from_sec1_bytes(&COMPRESSED_PUBLIC_KEY[..32]).is_err());
+    }
+
+    #[test]
+    fn verifying_key_rejects_invalid_bytes() {
+        assert!(VerifyingKey::<NistP256>::from_bytes(&PUBLIC_KEY[..64]).is_err());
+
+        let mut not_on_curve = PUBLIC_KEY;
+        not_on_curve[64] ^= 1;
+        assert!(VerifyingKey::<NistP256>::from_bytes(&not_on_curve).is_err());
+    }
+
+    #[test]
+    fn verify_any_finds_matching_key() {
+        let other_key = |byte| {
+            SigningKey::<NistP256>::from_bytes(&[byte; 32])
+                .unwrap()
+                .verifying_key()
+        };
+        let keys = [other_key(1), other_key(2), other_key(3)];
+
+        let signing_key = SigningKey::<NistP256>::from_bytes(&SECRET_KEY).unwrap();
+        let signature = signing_key.sign(b"rotated");
+
+        let candidates = [other_key(1), signing_key.verifying_key(), other_key(3)];
+        assert_eq!(
+            VerifyingKey::verify_any(&candidates, b"rotated", &signature).unwrap(),
+            1
+        );
+
+        assert!(VerifyingKey::verify_any(&keys, b"rotated", &signature).is_err());
+        assert!(VerifyingKey::verify_any(&candidates, b"other", &signature).is_err());
+        assert!(VerifyingKey::<NistP256>::verify_any(&[], b"rotated", &signature).is_err());
+    }
+
+    #[test]
+    fn verifying_key_rejects_invalid_point_at_construction() {
+        let mut not_on_curve = PUBLIC_KEY;
+        not_on_curve[64] ^= 1;
+
+        // Well-formed SEC1 encoding, but not a point on the curve
+        let public_key = PublicKey::<NistP256>::from_bytes(&not_on_curve[..]).unwrap();
+        let result = VerifyingKey::from_public_key(public_key);
+
+        #[cfg(feature = "std")]
+        assert_eq!(
+            crate::error::tests::kind(result.err().unwrap()),
+            crate::ErrorKind::InvalidPoint
+        );
+        #[cfg(not(feature = "std"))]
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let signing_key = SigningKey::<NistP256>::from_bytes(&SECRET_KEY).unwrap();
+        let signature = signing_key.sign(b"sample");
+        assert_eq!(signature.as_ref(), &SIGNATURE[..]);
+
+        let verifying_key = signing_key.verifying_key();
+        assert!(verifying_key.verify(b"sample", &signature).is_ok());
+        assert!(verifying_key.verify(b"test", &signature).is_err());
+    }
+
+    #[test]
+    fn signature_verify() {
+        let verifying_key = VerifyingKey::<NistP256>::from_bytes(&PUBLIC_KEY).unwrap();
+        let signature = crate::Signature::from_bytes(&SIGNATURE).unwrap();
+        assert!(signature.verify(&verifying_key, b"sample").is_ok());
+        assert!(signature.verify(&verifying_key, b"test").is_err());
+    }
+
+    #[test]
+    fn signature_verify_recovering() {
+        use sha2::{Digest, Sha256};
+
+        let verifying_key = VerifyingKey::<NistP256>::from_bytes(&PUBLIC_KEY).unwrap();
+        let signature = crate::Signature::from_bytes(&SIGNATURE).unwrap();
+
+        let x = signature
+            .verify_recovering(&verifying_key, &Sha256::digest(b"sample"))
+            .unwrap();
+        assert_eq!(&x, signature.r());
+        assert!(signature
+            .verify_recovering(&verifying_key, &Sha256::digest(b"test"))
+            .is_err());
+    }
+
+    #[test]
+    fn compressed_verifying_key() {
+        let signing_key = SigningKey::<Secp256k1>::from_bytes(&SECRET_KEY).unwrap();
+        let verifying_key = signing_key.verifying_key();
+        assert_eq!(verifying_key.to_bytes().as_bytes().len(), 33);
+
+        let signature = signing_key.sign(b"sample");
+        let verifying_key =
+            VerifyingKey::<Secp256k1>::from_bytes(verifying_key.to_bytes().as_bytes()).unwrap();
+        assert!(verifying_key.verify(b"sample", &signature).is_ok());
+    }
+
+    /// Sign a message with any signer, and verify it under its own verifying key
+    fn sign_and_verify_keypair<K, S>(keypair: &K, msg: &[u8]) -> S
+    where
+        K: Keypair<S> + Signer<S>,
+        S: Signature,
+    {
+        let signature = keypair.sign(msg);
+        assert!(keypair.verifying_key().verify(msg, &signature).is_ok());
+        signature
+    }
+
+    #[test]
+    fn keypair() {
+        let signing_key = SigningKey::<NistP256>::from_bytes(&SECRET_KEY).unwrap();
+        let signature = sign_and_verify_keypair(&signing_key, b"sample");
+        assert_eq!(signature.as_ref(), &SIGNATURE[..]);
+
+        let signing_key = SigningKey::<Secp256k1>::from_bytes(&SECRET_KEY).unwrap();
+        sign_and_verify_keypair(&signing_key, b"sample");
+    }
+
+    #[test]
+    fn verify_strict() {
+        let signing_key = SigningKey::<Secp256k1>::from_bytes(&SECRET_KEY).unwrap();
+        let verifying_key = signing_key.verifying_key();
+
+        let low_s = signing_key.sign(b"sample").normalized_s().unwrap();
+        let s = k256::Scalar::from_bytes(low_s.s()).unwrap();
+        let high_s = crate::Signature::from_scalars(low_s.r(), &(-s).into());
+        assert!(!high_s.is_low_s().unwrap());
+
+        assert!(verifying_key.verify(b"sample", &low_s).is_ok());
+        assert!(verifying_key.verify_strict(b"sample", &low_s).is_ok());
+
+        assert!(verifying_key.verify(b"sample", &high_s).is_ok());
+        let result = verifying_key.verify_strict(b"sample", &high_s);
+        assert!(result.is_err());
+
+        #[cfg(feature = "std")]
+        assert_eq!(
+            crate::error::tests::kind(result.unwrap_err()),
+            crate::ErrorKind::HighS
+        );
+
+        // Invalid signatures are still rejected as such
+        assert!(verifying_key.verify_strict(b"test", &low_s).is_err());
+    }
+
+    #[test]
+    fn sign_with_default_options() {
+        let signing_key = SigningKey::<Secp256k1>::from_bytes(&SECRET_KEY).unwrap();
+        let options = SigningOptions::default();
+
+        assert_eq!(
+            signing_key.sign_with_options(b"sample", &options).unwrap(),
+            signing_key.sign(b"sample")
+        );
+
+        // The RNG is unused when signing deterministically
+        assert_eq!(
+            signing_key
+                .sign_with_options_and_rng(TestRng(1), b"sample", &options)
+                .unwrap(),
+            signing_key.sign(b"sample")
+        );
+    }
+
+    #[test]
+    fn sign_with_normalize_s_option() {
+        let signing_key = SigningKey::<Secp256k1>::from_bytes(&SECRET_KEY).unwrap();
+        let verifying_key = signing_key.verifying_key();
+        let options = SigningOptions::new().normalize_s(true);
+
+        for msg in &[&b"sample"[..], b"test", b"foobar"] {
+            let signature = signing_key.sign_with_options(msg, &options).unwrap();
+            assert!(signature.is_low_s().unwrap());
+            assert_eq!(signature, signing_key.sign(msg).normalized_s().unwrap());
+            assert!(verifying_key.verify_strict(msg, &signature).is_ok());
+        }
+    }
+
+    #[test]
+    fn sign_with_extra_entropy_option() {
+        let signing_key = SigningKey::<Secp256k1>::from_bytes(&SECRET_KEY).unwrap();
+        let options = SigningOptions::new().extra_entropy(b"extra entropy");
+
+        let signature = signing_key.sign_with_options(b"sample", &options).unwrap();
+        assert_ne!(signature, signing_key.sign(b"sample"));
+        assert_eq!(
+            signature,
+            signing_key.sign_with_options(b"sample", &options).unwrap()
+        );
+
+        let verifying_key = signing_key.verifying_key();
+        assert!(verifying_key.verify(b"sample", &signature).is_ok());
+    }
+
+    #[test]
+    fn sign_with_blinding_option() {
+        let signing_key = SigningKey::<Secp256k1>::from_bytes(&SECRET_KEY).unwrap();
+        let verifying_key = signing_key.verifying_key();
+        let options = SigningOptions::new().blinding(true);
+
+        let result = signing_key.sign_with_options(b"sample", &options);
+        assert!(result.is_err());
+
+        #[cfg(feature = "std")]
+        assert_eq!(
+            crate::error::tests::kind(result.unwrap_err()),
+            crate::ErrorKind::RngRequired
+        );
+
+        // Blinding doesn't change signatures computed with the same
+        // deterministic ephemeral scalar
+        let unblinded = signing_key.sign(b"sample");
+
+        for seed in 1..4 {
+            let blinded = signing_key
+                .sign_with_options_and_rng(TestRng(seed), b"sample", &options)
+                .unwrap();
+
+            assert_eq!(blinded, unblinded);
+            assert!(verifying_key.verify(b"sample", &blinded).is_ok());
+        }
+
+        // Blinding combined with non-deterministic signing
+        let options = options.deterministic(false);
+        let signature = signing_key
+            .sign_with_options_and_rng(TestRng(1), b"sample", &options)
+            .unwrap();
+        assert!(verifying_key.verify(b"sample", &signature).is_ok());
+    }
+
+    #[test]
+    fn sign_with_non_deterministic_option() {
+        let signing_key = SigningKey::<Secp256k1>::from_bytes(&SECRET_KEY).unwrap();
+        let verifying_key = signing_key.verifying_key();
+        let options = SigningOptions::new().deterministic(false);
+
+        let result = signing_key.sign_with_options(b"sample", &options);
+        assert!(result.is_err());
+
+        #[cfg(feature = "std")]
+        assert_eq!(
+            crate::error::tests::kind(result.unwrap_err()),
+            crate::ErrorKind::RngRequired
+        );
+
+        let sign = |seed, options: &SigningOptions<'_>| {
+            signing_key
+                .sign_with_options_and_rng(TestRng(seed), b"sample", options)
+                .unwrap()
+        };
+
+        let (signature1, signature2) = (sign(1, &options), sign(2, &options));
+        assert_ne!(signature1, signature2);
+        assert_eq!(signature1, sign(1, &options));
+
+        // Extra entropy is combined with the RNG output
+        let with_entropy = options.extra_entropy(b"extra entropy");
+        assert_ne!(sign(1, &with_entropy), signature1);
+
+        for signature in &[signature1, signature2, sign(1, &with_entropy)] {
+            assert!(verifying_key.verify(b"sample", signature).is_ok());
+        }
+    }
+
+    #[test]
+    fn normalized_signer_produces_low_s() {
+        let signing_key = SigningKey::<Secp256k1>::from_bytes(&SECRET_KEY).unwrap();
+        let verifying_key = signing_key.verifying_key();
+        let normalized = NormalizedSigner::new(signing_key);
+        let mut saw_high_s = false;
+
+        for i in 0..16u8 {
+            let msg = [i; 8];
+            let inner: crate::Signature<Secp256k1> = normalized.inner().sign(&msg);
+            let signature: crate::Signature<Secp256k1> = normalized.sign(&msg);
+
+            saw_high_s |= !inner.is_low_s().unwrap();
+            assert!(signature.is_low_s().unwrap());
+            assert_eq!(signature, inner.normalized_s().unwrap());
+            assert!(verifying_key.verify(&msg, &signature).is_ok());
+        }
+
+        assert!(saw_high_s);
+    }
+
+    #[test]
+    fn signing_stream_large_message() {
+        use sha2::{Digest, Sha256};
+
+        let signing_key = SigningKey::<NistP256>::from_bytes(&SECRET_KEY).unwrap();
+        let verifying_key = VerifyingKey::<NistP256>::from_bytes(&PUBLIC_KEY).unwrap();
+
+        // 4 MiB message
+        let msg = (0..(4u32 << 20))
+            .map(|i| (i % 251) as u8)
+            .collect::<std::vec::Vec<u8>>();
+
+        let mut stream = signing_key.signing_stream::<Sha256>();
+        for chunk in msg.chunks(1000) {
+            stream.update(chunk);
+        }
+        let signature = stream.finalize_sign().unwrap();
+        assert_eq!(signature, signing_key.sign(&msg));
+
+        let mut stream = verifying_key.verifying_stream::<Sha256>();
+        for chunk in msg.chunks(4096) {
+            stream.update(chunk);
+        }
+        assert!(stream.finalize_verify(&signature).is_ok());
+
+        let digest = Sha256::new().chain(&msg[1..]);
+        assert!(
+            signature::DigestVerifier::verify_digest(&verifying_key, digest, &signature).is_err()
+        );
+    }
+}
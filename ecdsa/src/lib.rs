@@ -21,22 +21,49 @@
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![forbid(unsafe_code)]
-#![warn(missing_docs, rust_2018_idioms, intra_doc_link_resolution_failure)]
+#![warn(missing_docs, rust_2018_idioms)]
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/RustCrypto/meta/master/logo_small.png",
     html_root_url = "https://docs.rs/ecdsa/0.7.2"
 )]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
 pub mod asn1;
 
-#[cfg(feature = "dev")]
+#[cfg(any(feature = "jws", feature = "pem"))]
+mod base64;
+mod error;
+
+mod hex;
+
+#[cfg(feature = "pem")]
+mod pem;
+
+#[cfg(any(feature = "dev", test))]
 #[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
+#[macro_use]
 pub mod dev;
 
 #[cfg(feature = "hazmat")]
 #[cfg_attr(docsrs, doc(cfg(feature = "hazmat")))]
 pub mod hazmat;
 
+#[cfg(any(feature = "signer", feature = "verifier"))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "signer", feature = "verifier"))))]
+pub mod keys;
+
+#[cfg(feature = "recoverable")]
+#[cfg_attr(docsrs, doc(cfg(feature = "recoverable")))]
+pub mod recoverable;
+
+#[cfg(feature = "rfc6979")]
+mod rfc6979;
+
 #[cfg(feature = "signer")]
 #[cfg_attr(docsrs, doc(cfg(feature = "signer")))]
 pub mod signer;
@@ -45,6 +72,9 @@ pub mod signer;
 #[cfg_attr(docsrs, doc(cfg(feature = "verifier")))]
 pub mod verifier;
 
+#[cfg(test)]
+mod test_curves;
+
 // Re-export the `elliptic-curve` crate (and select types)
 pub use elliptic_curve::{
     self, generic_array,
@@ -55,19 +85,67 @@ pub use elliptic_curve::{
 // Re-export the `signature` crate (and select types)
 pub use signature::{self, Error};
 
+pub use error::ErrorKind;
+
+#[cfg(feature = "serde")]
+use serde::{de, ser, Deserialize, Serialize};
+
 #[cfg(feature = "signer")]
-pub use signer::Signer;
+pub use {
+    keys::SigningKey,
+    signer::{NormalizedSigner, PrehashSigner, Signer, SigningOptions, SigningStream},
+};
+
+#[cfg(all(feature = "signer", feature = "verifier"))]
+pub use keys::Keypair;
 
 #[cfg(feature = "verifier")]
-pub use verifier::Verifier;
+pub use {
+    keys::VerifyingKey,
+    verifier::{
+        add_public_keys, validate_public_key, verify_aggregated, verify_with_sec1_key,
+        PrehashVerifier, Verifier, VerifyingStream,
+    },
+};
 
 use core::{
+    cmp::Ordering,
     convert::TryFrom,
     fmt::{self, Debug},
+    hash::{Hash, Hasher},
     ops::Add,
+    slice,
+    str::FromStr,
+};
+
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+use elliptic_curve::{
+    subtle::{Choice, ConstantTimeEq},
+    Arithmetic, ElementBytes, FromBytes,
+};
+use generic_array::{
+    typenum::{Unsigned, U32},
+    ArrayLength, GenericArray,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(any(feature = "jws", feature = "pem"))]
+use alloc::string::String;
+
+#[cfg(feature = "hazmat")]
+use elliptic_curve::subtle::ConditionallySelectable;
+
+#[cfg(feature = "zeroize")]
+use elliptic_curve::zeroize::Zeroize;
+
+#[cfg(feature = "verifier")]
+use elliptic_curve::{
+    consts::U1,
+    weierstrass::point::{CompressedPointSize, UncompressedPointSize},
 };
-use elliptic_curve::{Arithmetic, ElementBytes, FromBytes};
-use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
 
 /// Size of a fixed sized signature for the given elliptic curve.
 pub type SignatureSize<C> = <<C as elliptic_curve::Curve>::ElementSize as Add>::Output;
@@ -89,9 +167,13 @@ pub type SignatureBytes<C> = GenericArray<u8, SignatureSize<C>>;
 /// secp256k1, `r` and `s` will both be 32-bytes, resulting in a signature
 /// with a total of 64-bytes.
 ///
-/// ASN.1 is also supported via the [`Signature::from_asn1`] and
-/// [`Signature::to_asn1`] methods.
-#[derive(Clone, Eq, PartialEq)]
+/// ASN.1 DER is also supported via the [`Signature::from_der`] and
+/// [`Signature::to_der`] methods, and PEM-encapsulated DER via
+/// `Signature::from_pem` and `Signature::to_pem` when the `pem` feature
+/// is enabled.
+///
+/// Equality comparisons between signatures are constant time.
+#[derive(Clone)]
 pub struct Signature<C: Curve>
 where
     SignatureSize<C>: ArrayLength<u8>,
@@ -112,24 +194,265 @@ where
         Signature { bytes }
     }
 
+    /// Size of this signature type when serialized as `r || s` in bytes,
+    /// i.e. [`SignatureSize`] as a `usize`
+    pub fn byte_len() -> usize {
+        SignatureSize::<C>::to_usize()
+    }
+
+    /// Create a [`Signature`] from `r` and `s` components serialized as
+    /// **little endian** integers, each padded with trailing (i.e. most
+    /// significant) zeroes to the curve's field size.
+    ///
+    /// Signatures are always framed as big endian, so the bytes of each
+    /// component are reversed. Returns an error if either component is not
+    /// exactly the field size.
+    pub fn from_scalars_le(r: &[u8], s: &[u8]) -> Result<Self, Error> {
+        let scalar_size = C::ElementSize::to_usize();
+
+        if r.len() != scalar_size || s.len() != scalar_size {
+            return Err(ErrorKind::InvalidLength.into());
+        }
+
+        let mut bytes = SignatureBytes::<C>::default();
+
+        for (out, byte) in bytes.iter_mut().zip(r.iter().rev().chain(s.iter().rev())) {
+            *out = *byte;
+        }
+
+        Ok(Signature { bytes })
+    }
+
+    /// Serialize the `r` and `s` components of this signature as **little
+    /// endian** integers, i.e. with the bytes of [`Signature::r`] and
+    /// [`Signature::s`] reversed.
+    pub fn to_scalars_le(&self) -> (ElementBytes<C>, ElementBytes<C>) {
+        let mut r = self.r().clone();
+        let mut s = self.s().clone();
+        r.reverse();
+        s.reverse();
+        (r, s)
+    }
+
+    /// Parse a signature in the IEEE P1363 format, i.e. the fixed-size
+    /// concatenation `r || s` of big endian scalars, each left-padded to the
+    /// curve's field size. This is also the format used by JOSE/JWS.
+    ///
+    /// This is **not** ASN.1 DER! DER signatures are variable-length and
+    /// begin with a `SEQUENCE` tag (`0x30`): use [`Signature::from_der`] to
+    /// parse them instead.
+    ///
+    /// Equivalent to [`Signature::try_from`], returning an error if `bytes`
+    /// isn't exactly [`Signature::byte_len`] bytes long.
+    pub fn from_p1363(bytes: &[u8]) -> Result<Self, Error> {
+        Self::try_from(bytes)
+    }
+
+    /// Serialize this signature in the IEEE P1363 format, i.e. the
+    /// fixed-size concatenation `r || s` of big endian scalars, each
+    /// left-padded to the curve's field size.
+    ///
+    /// This is **not** ASN.1 DER! Use [`Signature::to_der`] for protocols
+    /// (e.g. X.509 or TLS) which expect DER.
+    ///
+    /// Equivalent to [`AsRef<[u8]>`](AsRef) for this type.
+    pub fn to_p1363(&self) -> SignatureBytes<C> {
+        self.bytes.clone()
+    }
+
+    /// Borrow the fixed-size `r || s` serialization of this signature as a
+    /// [`SignatureBytes`] array
+    pub fn as_array(&self) -> &SignatureBytes<C> {
+        &self.bytes
+    }
+
+    /// Convert this signature into its fixed-size `r || s` serialization as a
+    /// [`SignatureBytes`] array, without copying
+    pub fn into_bytes(self) -> SignatureBytes<C> {
+        self.bytes
+    }
+
+    /// Parse a signature from ASN.1 DER
+    pub fn from_der(bytes: &[u8]) -> Result<Self, Error>
+    where
+        C::ElementSize: Add + ArrayLength<u8>,
+        asn1::MaxSize<C>: ArrayLength<u8>,
+        <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
+    {
+        asn1::Signature::<C>::try_from(bytes)
+            .map_err(Into::into)
+            .and_then(Self::try_from)
+    }
+
+    /// Parse a signature which may be either ASN.1 DER or fixed-size (i.e.
+    /// IEEE P1363) encoded, detecting the encoding from its contents.
+    ///
+    /// Input which starts with a DER `SEQUENCE` header whose declared length
+    /// matches the length of the input is parsed as DER, and anything else
+    /// which is exactly [`SignatureSize`] bytes is parsed as fixed-size.
+    ///
+    /// Returns an [`ErrorKind::InvalidEncoding`] error if the input is
+    /// ambiguous, i.e. a valid DER signature which is also exactly
+    /// [`SignatureSize`] bytes. Otherwise, malformed DER results in an
+    /// [`ErrorKind::Asn1`] error and input of any other length in an
+    /// [`ErrorKind::InvalidLength`] error.
+    pub fn from_bytes_auto(bytes: &[u8]) -> Result<Self, Error>
+    where
+        C::ElementSize: Add + ArrayLength<u8>,
+        asn1::MaxSize<C>: ArrayLength<u8>,
+        <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
+    {
+        if !asn1::is_sequence(bytes) {
+            return Self::try_from(bytes);
+        }
+
+        let is_fixed_size = bytes.len() == <SignatureSize<C>>::to_usize();
+
+        match Self::from_der(bytes) {
+            Ok(_) if is_fixed_size => Err(ErrorKind::InvalidEncoding.into()),
+            Err(_) if is_fixed_size => Self::try_from(bytes),
+            result => result,
+        }
+    }
+
+    /// Serialize this signature as ASN.1 DER.
+    ///
+    /// The returned [`asn1::Signature`] is a fixed-size stack buffer; use
+    /// [`asn1::Signature::as_bytes`] to access the encoded DER bytes.
+    pub fn to_der(&self) -> asn1::Signature<C>
+    where
+        C::ElementSize: Add + ArrayLength<u8>,
+        asn1::MaxSize<C>: ArrayLength<u8>,
+        <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
+    {
+        asn1::Signature::from_scalars(self.r(), self.s())
+    }
+
     /// Parse a signature from ASN.1 DER
+    #[deprecated(note = "use `Signature::from_der` instead")]
     pub fn from_asn1(bytes: &[u8]) -> Result<Self, Error>
     where
         C::ElementSize: Add + ArrayLength<u8>,
         asn1::MaxSize<C>: ArrayLength<u8>,
         <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
     {
-        asn1::Signature::<C>::try_from(bytes).map(Into::into)
+        Self::from_der(bytes)
     }
 
     /// Serialize this signature as ASN.1 DER
+    #[deprecated(note = "use `Signature::to_der` instead")]
     pub fn to_asn1(&self) -> asn1::Signature<C>
     where
         C::ElementSize: Add + ArrayLength<u8>,
         asn1::MaxSize<C>: ArrayLength<u8>,
         <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
     {
-        asn1::Signature::from_scalars(self.r(), self.s())
+        self.to_der()
+    }
+
+    /// Serialize this signature as a heap-allocated `r || s` byte vector
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.bytes.to_vec()
+    }
+
+    /// Serialize this signature as a heap-allocated ASN.1 DER byte vector
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn to_der_vec(&self) -> Vec<u8>
+    where
+        C::ElementSize: Add + ArrayLength<u8>,
+        asn1::MaxSize<C>: ArrayLength<u8>,
+        <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
+    {
+        self.to_der().as_bytes().to_vec()
+    }
+
+    /// Parse a signature from PEM-encapsulated ASN.1 DER with the
+    /// `ECDSA SIGNATURE` label, rejecting any other label
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn from_pem(s: &str) -> Result<Self, Error>
+    where
+        C::ElementSize: Add + ArrayLength<u8>,
+        asn1::MaxSize<C>: ArrayLength<u8>,
+        <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
+    {
+        Self::from_der(&pem::decode(s)?)
+    }
+
+    /// Serialize this signature as PEM-encapsulated ASN.1 DER with the
+    /// `ECDSA SIGNATURE` label
+    #[cfg(feature = "pem")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pem")))]
+    pub fn to_pem(&self) -> String
+    where
+        C::ElementSize: Add + ArrayLength<u8>,
+        asn1::MaxSize<C>: ArrayLength<u8>,
+        <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
+    {
+        pem::encode(self.to_der().as_bytes())
+    }
+
+    /// Parse a signature in the JSON Web Signature (RFC 7515) format, i.e.
+    /// the fixed-size `r || s` encoding as unpadded base64url.
+    ///
+    /// Rejects the standard base64 alphabet, padding, and encodings of the
+    /// wrong length for this curve.
+    #[cfg(feature = "jws")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "jws")))]
+    pub fn from_jws(s: &str) -> Result<Self, Error> {
+        let bytes = base64::decode(s.as_bytes(), base64::Alphabet::UrlSafeNoPad)?;
+        Self::try_from(bytes.as_slice())
+    }
+
+    /// Serialize this signature in the JSON Web Signature (RFC 7515) format,
+    /// i.e. the fixed-size `r || s` encoding as unpadded base64url.
+    #[cfg(feature = "jws")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "jws")))]
+    pub fn to_jws(&self) -> String {
+        base64::encode(&self.bytes, base64::Alphabet::UrlSafeNoPad)
+    }
+
+    /// Parse a signature in the format used by COSE (RFC 8152 § 8.1), i.e.
+    /// the fixed-size `r || s` encoding carried in the signature byte string
+    /// of e.g. an ES256 or ES384 `COSE_Sign1` structure.
+    ///
+    /// Returns an error unless the input is exactly twice the curve's field
+    /// size, e.g. 64 bytes for ES256 (P-256) or 96 bytes for ES384 (P-384).
+    #[cfg(feature = "cose")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cose")))]
+    pub fn from_cose_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Self::try_from(bytes)
+    }
+
+    /// Serialize this signature in the format used by COSE (RFC 8152 § 8.1),
+    /// i.e. the fixed-size `r || s` encoding, to be carried in the signature
+    /// byte string of a `COSE_Sign1` structure.
+    #[cfg(feature = "cose")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cose")))]
+    pub fn to_cose_bytes(&self) -> SignatureBytes<C> {
+        self.bytes.clone()
+    }
+
+    /// Encode this signature as lowercase hexadecimal `r || s` into the
+    /// given buffer without allocating, returning the encoded string.
+    ///
+    /// Writes `2 * SignatureSize<C>` characters to the beginning of `out`,
+    /// and returns an [`ErrorKind::InvalidLength`] error if it's too small.
+    pub fn encode_hex_to<'a>(&self, out: &'a mut [u8]) -> Result<&'a str, Error> {
+        hex::encode(&self.bytes, out)
+    }
+
+    /// Decode a signature from hexadecimal `r || s` (either case) without
+    /// allocating.
+    ///
+    /// The input must be exactly `2 * SignatureSize<C>` characters long.
+    pub fn decode_hex(hex: &[u8]) -> Result<Self, Error> {
+        let mut bytes = SignatureBytes::<C>::default();
+        hex::decode(hex, &mut bytes)?;
+        Ok(Self { bytes })
     }
 
     /// Get the `r` component of this signature
@@ -141,6 +464,74 @@ where
     pub fn s(&self) -> &ElementBytes<C> {
         ElementBytes::<C>::from_slice(&self.bytes[C::ElementSize::to_usize()..])
     }
+
+    /// Get the `r` and `s` components of this signature, in that order, e.g.
+    /// for processing them uniformly in a loop
+    pub fn scalar_bytes(&self) -> [&ElementBytes<C>; 2] {
+        [self.r(), self.s()]
+    }
+
+    /// Iterate over the `r || s` bytes of this signature
+    pub fn iter(&self) -> slice::Iter<'_, u8> {
+        self.bytes.iter()
+    }
+}
+
+impl<C> Signature<C>
+where
+    C: Curve + Arithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Create a [`Signature`] from `r` and `s` components which have already
+    /// been decoded as scalars, e.g. by [`Signature::split_scalars`].
+    ///
+    /// Like [`Signature::from_scalars`], zero components aren't rejected.
+    pub fn from_typed_scalars(r: &C::Scalar, s: &C::Scalar) -> Self {
+        Self::from_scalars(&(*r).into(), &(*s).into())
+    }
+
+    /// Create a [`Signature`] from the serialized `r` and `s` components,
+    /// validating that each is a field-sized big endian scalar.
+    ///
+    /// Unlike [`Signature::from_scalars`], this returns an error if either
+    /// component is zero or not less than the order of the curve.
+    pub fn try_from_scalars(r: &[u8], s: &[u8]) -> Result<Self, Error> {
+        let scalar_size = C::ElementSize::to_usize();
+
+        if r.len() != scalar_size || s.len() != scalar_size {
+            return Err(ErrorKind::InvalidLength.into());
+        }
+
+        let signature = Self::from_scalars(
+            ElementBytes::<C>::from_slice(r),
+            ElementBytes::<C>::from_slice(s),
+        );
+
+        let (r, s) = signature.split_scalars()?;
+        Ok(Self::from_typed_scalars(&r, &s))
+    }
+
+    /// Decode the `r` and `s` components of this signature as scalars.
+    ///
+    /// Returns an error if either component is zero or not less than the
+    /// order of the curve.
+    pub fn split_scalars(&self) -> Result<(C::Scalar, C::Scalar), Error> {
+        let r = C::Scalar::from_bytes(self.r());
+        let s = C::Scalar::from_bytes(self.s());
+
+        if r.is_none().into() || s.is_none().into() {
+            return Err(ErrorKind::ScalarOutOfRange.into());
+        }
+
+        let (r, s) = (r.unwrap(), s.unwrap());
+        let zero = C::Scalar::default();
+
+        if (r.ct_eq(&zero) | s.ct_eq(&zero)).into() {
+            return Err(ErrorKind::ZeroScalar.into());
+        }
+
+        Ok((r, s))
+    }
 }
 
 impl<C> Signature<C>
@@ -152,7 +543,13 @@ where
     /// Normalize signature into "low S" form as described in
     /// [BIP 0062: Dealing with Malleability][1].
     ///
+    /// Returns `true` if `s` was high and has been negated, or `false` if the
+    /// signature was already in "low S" form. Callers which only need the
+    /// signature to be in "low S" form afterwards should use
+    /// [`Signature::normalize_s_if_high`] instead.
+    ///
     /// [1]: https://github.com/bitcoin/bips/blob/master/bip-0062.mediawiki
+    #[must_use = "use `normalize_s_if_high` if whether `s` was negated isn't needed"]
     pub fn normalize_s(&mut self) -> Result<bool, Error> {
         let s_bytes = GenericArray::from_mut_slice(&mut self.bytes[C::ElementSize::to_usize()..]);
         let s_option = C::Scalar::from_bytes(s_bytes);
@@ -168,8 +565,211 @@ where
                 Ok(false)
             }
         } else {
-            Err(Error::new())
+            Err(ErrorKind::ScalarOutOfRange.into())
+        }
+    }
+
+    /// Normalize signature into "low S" form as described in
+    /// [BIP 0062: Dealing with Malleability][1], without reporting whether
+    /// any change was made.
+    ///
+    /// Unlike [`Signature::normalize_s`], there's no result to check other
+    /// than an error if `s` is not a valid scalar: once this returns `Ok`,
+    /// the signature is guaranteed to be in "low S" form.
+    ///
+    /// [1]: https://github.com/bitcoin/bips/blob/master/bip-0062.mediawiki
+    pub fn normalize_s_if_high(&mut self) -> Result<(), Error> {
+        self.normalize_s().map(|_| ())
+    }
+
+    /// Return a copy of this signature normalized into "low S" form as
+    /// described in [BIP 0062: Dealing with Malleability][1], leaving the
+    /// original unchanged.
+    ///
+    /// [1]: https://github.com/bitcoin/bips/blob/master/bip-0062.mediawiki
+    pub fn normalized_s(&self) -> Result<Self, Error> {
+        let mut normalized = self.clone();
+        normalized.normalize_s_if_high()?;
+        Ok(normalized)
+    }
+
+    /// Is the `s` component of this signature in the lower half of the
+    /// curve's order, i.e. is this signature already in "low S" form as
+    /// described in [BIP 0062: Dealing with Malleability][1]?
+    ///
+    /// Returns an error if `s` is not a valid scalar.
+    ///
+    /// [1]: https://github.com/bitcoin/bips/blob/master/bip-0062.mediawiki
+    pub fn is_low_s(&self) -> Result<bool, Error> {
+        let s_option = C::Scalar::from_bytes(self.s());
+
+        if s_option.is_some().into() {
+            let (_, was_high) = s_option.unwrap().normalize_low();
+            Ok(!was_high)
+        } else {
+            Err(ErrorKind::ScalarOutOfRange.into())
+        }
+    }
+
+    /// Parse a signature from ASN.1 DER, additionally rejecting signatures
+    /// which aren't in "low S" form as malleable (as required by e.g.
+    /// [BIP 146][1]).
+    ///
+    /// Returns an [`ErrorKind::HighS`] error for high-S signatures, or the
+    /// same errors as [`Signature::from_der`] and [`Signature::is_low_s`].
+    ///
+    /// [1]: https://github.com/bitcoin/bips/blob/master/bip-0146.mediawiki
+    pub fn from_der_low_s(bytes: &[u8]) -> Result<Self, Error>
+    where
+        C::ElementSize: Add + ArrayLength<u8>,
+        asn1::MaxSize<C>: ArrayLength<u8>,
+        <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
+    {
+        let signature = Self::from_der(bytes)?;
+
+        if signature.is_low_s()? {
+            Ok(signature)
+        } else {
+            Err(ErrorKind::HighS.into())
+        }
+    }
+}
+
+#[cfg(feature = "hazmat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "hazmat")))]
+impl<C> Signature<C>
+where
+    C: hazmat::CurveArithmetic,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Normalize signature into "low S" form as described in
+    /// [BIP 0062: Dealing with Malleability][1], in constant time.
+    ///
+    /// Unlike [`Signature::normalize_s`], which branches on whether `s` is
+    /// high and relies on the curve's [`NormalizeLow`] impl, this method
+    /// always computes `-s`, compares it against `s` without data-dependent
+    /// branches, and selects the result using
+    /// [`ConditionallySelectable`][2]. Use it when the signature has not yet
+    /// been released, e.g. as part of a signing pipeline.
+    ///
+    /// Returns a [`Choice`] which is set if `s` was high and has been
+    /// negated, or an error if `s` is not a valid scalar.
+    ///
+    /// [1]: https://github.com/bitcoin/bips/blob/master/bip-0062.mediawiki
+    /// [2]: https://docs.rs/subtle/latest/subtle/trait.ConditionallySelectable.html
+    #[must_use = "the returned `Choice` reports whether `s` was negated"]
+    pub fn normalize_s_ct(&mut self) -> Result<Choice, Error> {
+        let s_bytes = GenericArray::from_mut_slice(&mut self.bytes[C::ElementSize::to_usize()..]);
+        let s_option = C::Scalar::from_bytes(s_bytes);
+
+        if s_option.is_none().into() {
+            return Err(ErrorKind::ScalarOutOfRange.into());
         }
+
+        let s = s_option.unwrap();
+        let s_neg = C::negate_scalar(&s);
+        let was_high = ct_gt_be(&s.into(), &s_neg.into());
+        let s_low = C::Scalar::conditional_select(&s, &s_neg, was_high);
+        s_bytes.copy_from_slice(&s_low.into());
+        Ok(was_high)
+    }
+}
+
+/// Constant-time `a > b` comparison of two big endian integers
+#[cfg(feature = "hazmat")]
+fn ct_gt_be<N: ArrayLength<u8>>(a: &GenericArray<u8, N>, b: &GenericArray<u8, N>) -> Choice {
+    let mut gt = Choice::from(0);
+
+    // Scan from least to most significant byte, so the most significant
+    // differing byte is the last one to update the result
+    for (x, y) in a.iter().zip(b.iter()).rev() {
+        let x_gt_y = (u16::from(*y).wrapping_sub(u16::from(*x)) >> 8) as u8 & 1;
+        gt = Choice::from(x_gt_y) | (x.ct_eq(y) & gt);
+    }
+
+    gt
+}
+
+#[cfg(feature = "verifier")]
+#[cfg_attr(docsrs, doc(cfg(feature = "verifier")))]
+impl<C> Signature<C>
+where
+    C: Curve + Arithmetic + hazmat::DigestPrimitive,
+    C::AffinePoint: hazmat::VerifyPrimitive<C>,
+    C::Digest: signature::digest::Digest<OutputSize = C::ElementSize>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Verify this signature over the given message using the given
+    /// [`VerifyingKey`]. Equivalent to [`signature::Verifier::verify`] with
+    /// the arguments in the opposite order.
+    ///
+    /// ```
+    /// use ecdsa::{Signature, VerifyingKey};
+    /// # use ecdsa::{
+    /// #     elliptic_curve::{
+    /// #         consts::U1,
+    /// #         generic_array::ArrayLength,
+    /// #         weierstrass::point::{CompressedPointSize, UncompressedPointSize},
+    /// #         Arithmetic,
+    /// #     },
+    /// #     hazmat::{DigestPrimitive, VerifyPrimitive},
+    /// #     signature::digest::Digest,
+    /// #     Curve, SignatureSize,
+    /// # };
+    /// # use core::ops::Add;
+    ///
+    /// fn is_authentic<C>(key: &VerifyingKey<C>, msg: &[u8], signature: &Signature<C>) -> bool
+    /// # where
+    /// #     C: Curve + Arithmetic + DigestPrimitive,
+    /// #     C::AffinePoint: VerifyPrimitive<C>,
+    /// #     C::Digest: Digest<OutputSize = C::ElementSize>,
+    /// #     C::ElementSize: Add<U1>,
+    /// #     <C::ElementSize as Add>::Output: Add<U1>,
+    /// #     CompressedPointSize<C>: ArrayLength<u8>,
+    /// #     UncompressedPointSize<C>: ArrayLength<u8>,
+    /// #     SignatureSize<C>: ArrayLength<u8>,
+    /// {
+    ///     signature.verify(key, msg).is_ok()
+    /// }
+    /// ```
+    pub fn verify(&self, key: &VerifyingKey<C>, msg: &[u8]) -> Result<(), Error> {
+        signature::Verifier::verify(key, msg, self)
+    }
+}
+
+#[cfg(feature = "verifier")]
+#[cfg_attr(docsrs, doc(cfg(feature = "verifier")))]
+impl<C> Signature<C>
+where
+    C: hazmat::CurveArithmetic,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Verify this signature over the given prehashed message using the given
+    /// [`VerifyingKey`], returning the affine x-coordinate of the point `𝐑`
+    /// computed during verification, i.e. the value which reduces to `r`.
+    ///
+    /// Intended for building schemes with message recovery on top of ECDSA;
+    /// see [`hazmat::verify_prehashed_recovering`]. The prehash is converted
+    /// to an integer as described in [`hazmat::bits2int`], using
+    /// [`CurveOrder::order_bits`].
+    pub fn verify_recovering(
+        &self,
+        key: &VerifyingKey<C>,
+        prehash: &[u8],
+    ) -> Result<ElementBytes<C>, Error> {
+        hazmat::verify_prehashed_recovering::<C>(
+            key.affine_point(),
+            &hazmat::bits2int::<C>(prehash, C::order_bits()),
+            self,
+        )
     }
 }
 
@@ -205,46 +805,490 @@ where
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "ecdsa::Signature<{:?}>({:?})",
-            C::default(),
+            "ecdsa::Signature<{}>({:?})",
+            core::any::type_name::<C>(),
             self.as_ref()
         )
     }
 }
 
-impl<C: Curve> TryFrom<&[u8]> for Signature<C>
+impl<C: Curve> ConstantTimeEq for Signature<C>
 where
     SignatureSize<C>: ArrayLength<u8>,
 {
-    type Error = Error;
-
-    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
-        if bytes.len() == <SignatureSize<C>>::to_usize() {
-            Ok(Self {
-                bytes: GenericArray::clone_from_slice(bytes),
-            })
-        } else {
-            Err(Error::new())
-        }
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_ref().ct_eq(other.as_ref())
     }
 }
 
-impl<C> From<asn1::Signature<C>> for Signature<C>
+impl<C: Curve> PartialEq for Signature<C>
 where
-    C: Curve,
-    C::ElementSize: Add + ArrayLength<u8>,
-    asn1::MaxSize<C>: ArrayLength<u8>,
-    <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
+    SignatureSize<C>: ArrayLength<u8>,
 {
-    fn from(doc: asn1::Signature<C>) -> Signature<C> {
-        let mut bytes = SignatureBytes::<C>::default();
-        let scalar_size = C::ElementSize::to_usize();
-        let r_begin = scalar_size.checked_sub(doc.r().len()).unwrap();
-        let s_begin = bytes.len().checked_sub(doc.s().len()).unwrap();
-
-        bytes[r_begin..scalar_size].copy_from_slice(doc.r());
-        bytes[s_begin..].copy_from_slice(doc.s());
-        Signature { bytes }
+    /// Compare signatures in constant time
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<C: Curve> Eq for Signature<C> where SignatureSize<C>: ArrayLength<u8> {}
+
+impl<C: Curve> Hash for Signature<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().hash(state)
+    }
+}
+
+impl<C: Curve> IntoIterator for Signature<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    type Item = u8;
+    type IntoIter = generic_array::iter::GenericArrayIter<u8, SignatureSize<C>>;
+
+    /// Iterate over the `r || s` bytes of this signature
+    fn into_iter(self) -> Self::IntoIter {
+        self.bytes.into_iter()
+    }
+}
+
+impl<'a, C: Curve> IntoIterator for &'a Signature<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    type Item = &'a u8;
+    type IntoIter = slice::Iter<'a, u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<C: Curve> PartialOrd for Signature<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Curve> Ord for Signature<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Compare the `r || s` bytes of signatures lexicographically.
+    ///
+    /// Unlike equality comparisons this is NOT constant time, and is intended
+    /// for use with public signatures, e.g. as keys in a `BTreeSet`.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_ref().cmp(other.as_ref())
+    }
+}
+
+impl<C: Curve> fmt::Display for Signature<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Display this signature as lowercase hexadecimal `r || s`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::Hex(self.as_ref()))
+    }
+}
+
+impl<C: Curve> fmt::LowerHex for Signature<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Format this signature as lowercase hexadecimal `r || s`, prefixed with
+    /// `0x` when the alternate flag (`{:#x}`) is set
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        for byte in self.as_ref() {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: Curve> fmt::UpperHex for Signature<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Format this signature as uppercase hexadecimal `r || s`, prefixed with
+    /// `0x` when the alternate flag (`{:#X}`) is set
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+
+        for byte in self.as_ref() {
+            write!(f, "{:02X}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: Curve> FromStr for Signature<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    type Err = Error;
+
+    /// Parse a signature from hexadecimal `r || s` (either case)
+    fn from_str(hex_str: &str) -> Result<Self, Error> {
+        Self::decode_hex(hex_str.as_bytes())
+    }
+}
+
+impl<C: Curve> TryFrom<&[u8]> for Signature<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() == <SignatureSize<C>>::to_usize() {
+            Ok(Self {
+                bytes: GenericArray::clone_from_slice(bytes),
+            })
+        } else {
+            Err(ErrorKind::InvalidLength.into())
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zeroize")))]
+impl<C: Curve> Zeroize for Signature<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Overwrite the signature's `r || s` bytes with zeroes.
+    ///
+    /// To zeroize a signature automatically when it's dropped, wrap it in
+    /// [`Zeroizing`](elliptic_curve::zeroize::Zeroizing).
+    fn zeroize(&mut self) {
+        self.bytes.as_mut_slice().zeroize();
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<C: Curve> Serialize for Signature<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Serialize this signature as a lowercase hex string when using a
+    /// human-readable format, or as raw bytes otherwise
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&hex::Hex(self.as_ref()))
+        } else {
+            serializer.serialize_bytes(self.as_ref())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de, C: Curve> Deserialize<'de> for Signature<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SignatureVisitor<C>(PhantomData<C>);
+
+        impl<'de, C: Curve> de::Visitor<'de> for SignatureVisitor<C>
+        where
+            SignatureSize<C>: ArrayLength<u8>,
+        {
+            type Value = Signature<C>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    formatter,
+                    "{}-byte ECDSA signature",
+                    <SignatureSize<C>>::to_usize()
+                )
+            }
+
+            fn visit_str<E: de::Error>(self, hex_str: &str) -> Result<Signature<C>, E> {
+                let mut bytes = SignatureBytes::<C>::default();
+
+                hex::decode(hex_str.as_bytes(), &mut bytes)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Str(hex_str), &self))?;
+
+                Ok(Signature { bytes })
+            }
+
+            fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Signature<C>, E> {
+                Signature::try_from(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(SignatureVisitor(PhantomData))
+        } else {
+            deserializer.deserialize_bytes(SignatureVisitor(PhantomData))
+        }
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+impl<'a, C: Curve> arbitrary::Arbitrary<'a> for Signature<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Take exactly [`SignatureSize`] bytes from the input as `r || s`.
+    ///
+    /// Any bytes are a syntactically valid fixed-size signature, although
+    /// `r` and `s` may be zero or out of range.
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.bytes(Self::byte_len())
+            .map(|bytes| Signature::from(GenericArray::from_slice(bytes)))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (Self::byte_len(), Some(Self::byte_len()))
+    }
+}
+
+impl<C: Curve> From<&SignatureBytes<C>> for Signature<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn from(bytes: &SignatureBytes<C>) -> Signature<C> {
+        Signature {
+            bytes: bytes.clone(),
+        }
+    }
+}
+
+impl<C: Curve> From<Signature<C>> for SignatureBytes<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn from(signature: Signature<C>) -> SignatureBytes<C> {
+        signature.bytes
+    }
+}
+
+impl<C: Curve> From<(&ElementBytes<C>, &ElementBytes<C>)> for Signature<C>
+where
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Create a [`Signature`] from the serialized `(r, s)` components, i.e.
+    /// [`Signature::from_scalars`]
+    fn from((r, s): (&ElementBytes<C>, &ElementBytes<C>)) -> Signature<C> {
+        Self::from_scalars(r, s)
+    }
+}
+
+impl<C> From<[u8; 64]> for Signature<C>
+where
+    C: Curve<ElementSize = U32>,
+{
+    fn from(bytes: [u8; 64]) -> Signature<C> {
+        Signature {
+            bytes: GenericArray::clone_from_slice(&bytes),
+        }
+    }
+}
+
+impl<C> From<Signature<C>> for [u8; 64]
+where
+    C: Curve<ElementSize = U32>,
+{
+    fn from(signature: Signature<C>) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(signature.as_ref());
+        bytes
+    }
+}
+
+impl<C> TryFrom<asn1::Signature<C>> for Signature<C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    asn1::MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
+{
+    type Error = Error;
+
+    /// Convert an ASN.1 DER signature into the fixed-size form, returning an
+    /// error if either `r` or `s` is longer than the curve's field size
+    fn try_from(doc: asn1::Signature<C>) -> Result<Signature<C>, Error> {
+        let scalar_size = C::ElementSize::to_usize();
+        let r_begin = scalar_size.checked_sub(doc.r().len());
+        let s_begin = scalar_size
+            .checked_sub(doc.s().len())
+            .and_then(|offset| offset.checked_add(scalar_size));
+
+        let (r_begin, s_begin) = match (r_begin, s_begin) {
+            (Some(r_begin), Some(s_begin)) => (r_begin, s_begin),
+            _ => return Err(asn1::ParseError::IntegerTooLarge.into()),
+        };
+
+        let mut bytes = SignatureBytes::<C>::default();
+        bytes[r_begin..scalar_size].copy_from_slice(doc.r());
+        bytes[s_begin..].copy_from_slice(doc.s());
+        Ok(Signature { bytes })
+    }
+}
+
+impl<C> From<Signature<C>> for asn1::Signature<C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    asn1::MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
+{
+    fn from(signature: Signature<C>) -> asn1::Signature<C> {
+        signature.to_der()
+    }
+}
+
+/// ECDSA signature which is either ASN.1 DER or fixed-size (i.e. IEEE P1363)
+/// encoded, retaining the encoding it was parsed from.
+///
+/// This allows signatures to be accepted in either form and later passed
+/// through or re-emitted unchanged, e.g. by a proxy.
+pub enum EncodedSignature<C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    asn1::MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
+{
+    /// ASN.1 DER-encoded signature
+    Der(asn1::Signature<C>),
+
+    /// Fixed-size `r || s` signature
+    Fixed(Signature<C>),
+}
+
+impl<C> EncodedSignature<C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    asn1::MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
+{
+    /// Parse a signature which may be either ASN.1 DER or fixed-size encoded,
+    /// detecting the encoding from its contents.
+    ///
+    /// Encodings are detected, and ambiguous input rejected, in the same way as
+    /// [`Signature::from_bytes_auto`].
+    pub fn parse(bytes: &[u8]) -> Result<Self, Error> {
+        if !asn1::is_sequence(bytes) {
+            return Signature::try_from(bytes).map(EncodedSignature::Fixed);
+        }
+
+        let is_fixed_size = bytes.len() == <SignatureSize<C>>::to_usize();
+
+        match asn1::Signature::try_from(bytes) {
+            Ok(_) if is_fixed_size => Err(ErrorKind::InvalidEncoding.into()),
+            Ok(der) => Ok(EncodedSignature::Der(der)),
+            Err(_) if is_fixed_size => Signature::try_from(bytes).map(EncodedSignature::Fixed),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Get the encoded signature bytes, in whichever form they were given
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            EncodedSignature::Der(der) => der.as_bytes(),
+            EncodedSignature::Fixed(fixed) => fixed.as_ref(),
+        }
+    }
+
+    /// Is this signature ASN.1 DER encoded?
+    pub fn is_der(&self) -> bool {
+        match self {
+            EncodedSignature::Der(_) => true,
+            EncodedSignature::Fixed(_) => false,
+        }
+    }
+
+    /// Convert this signature into the fixed-size form
+    pub fn to_fixed(&self) -> Signature<C> {
+        match self {
+            EncodedSignature::Der(der) => Signature::try_from(der.clone())
+                .expect("ASN.1 signature integers fit in field size"),
+            EncodedSignature::Fixed(fixed) => fixed.clone(),
+        }
+    }
+
+    /// Convert this signature into ASN.1 DER
+    pub fn to_der(&self) -> asn1::Signature<C> {
+        match self {
+            EncodedSignature::Der(der) => der.clone(),
+            EncodedSignature::Fixed(fixed) => fixed.to_der(),
+        }
+    }
+}
+
+impl<C> Clone for EncodedSignature<C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    asn1::MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
+{
+    fn clone(&self) -> Self {
+        match self {
+            EncodedSignature::Der(der) => EncodedSignature::Der(der.clone()),
+            EncodedSignature::Fixed(fixed) => EncodedSignature::Fixed(fixed.clone()),
+        }
+    }
+}
+
+impl<C> Debug for EncodedSignature<C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    asn1::MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodedSignature::Der(der) => f.debug_tuple("Der").field(der).finish(),
+            EncodedSignature::Fixed(fixed) => f.debug_tuple("Fixed").field(fixed).finish(),
+        }
+    }
+}
+
+impl<C> From<Signature<C>> for EncodedSignature<C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    asn1::MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
+{
+    fn from(signature: Signature<C>) -> Self {
+        EncodedSignature::Fixed(signature)
+    }
+}
+
+impl<C> From<asn1::Signature<C>> for EncodedSignature<C>
+where
+    C: Curve,
+    C::ElementSize: Add + ArrayLength<u8>,
+    asn1::MaxSize<C>: ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<asn1::MaxOverhead> + ArrayLength<u8>,
+{
+    fn from(signature: asn1::Signature<C>) -> Self {
+        EncodedSignature::Der(signature)
     }
 }
 
@@ -261,3 +1305,827 @@ pub trait NormalizeLow: Sized {
     /// May be implemented to work in variable time.
     fn normalize_low(&self) -> (Self, bool);
 }
+
+/// Order `n` of a curve's scalar field, i.e. of the group generated by its
+/// base point.
+///
+/// This allows protocol-level checks such as `s <= n/2` to be performed
+/// generically, without hardcoding each curve's order. The values are
+/// returned as big endian integers, as `n` itself is not a valid scalar.
+pub trait CurveOrder: Curve {
+    /// Get the order `n` of the curve's scalar field
+    fn order() -> ElementBytes<Self>;
+
+    /// Get the bit length of the order `n`.
+    ///
+    /// This can be less than the size of the curve's field elements in bits
+    /// when `n` isn't a multiple of 8 bits long: e.g. NIST P-521 has 66-byte
+    /// (528-bit) field elements, but a 521-bit order. Conversions such as
+    /// FIPS 186-4's `bits2int` must use this length rather than assuming
+    /// byte-aligned orders (see [`hazmat::bits2int`]).
+    fn order_bits() -> usize {
+        let order = Self::order();
+        let bits = Self::ElementSize::to_usize() * 8;
+
+        match order.iter().position(|&byte| byte != 0) {
+            Some(i) => bits - i * 8 - order[i].leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Get `⌊n/2⌋`, i.e. the largest "low S" value as described in
+    /// [BIP 0062: Dealing with Malleability][1].
+    ///
+    /// [1]: https://github.com/bitcoin/bips/blob/master/bip-0062.mediawiki
+    fn half_order() -> ElementBytes<Self> {
+        let mut half = Self::order();
+        let mut carry = 0u8;
+
+        for byte in half.iter_mut() {
+            let next_carry = *byte << 7;
+            *byte = (*byte >> 1) | carry;
+            carry = next_carry;
+        }
+
+        half
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dev::MockCurve;
+    use crate::test_curves::p384::NistP384;
+    use core::str::FromStr;
+    use elliptic_curve::ElementBytes;
+    use hex_literal::hex;
+    use signature::Signature as _;
+    use std::{format, string::ToString};
+
+    type Signature = crate::Signature<MockCurve>;
+    type EncodedSignature = crate::EncodedSignature<MockCurve>;
+
+    const EXAMPLE_SIGNATURE: [u8; 64] = [
+        0xf3, 0xac, 0x80, 0x61, 0xb5, 0x14, 0x79, 0x5b, 0x88, 0x43, 0xe3, 0xd6, 0x62, 0x95, 0x27,
+        0xed, 0x2a, 0xfd, 0x6b, 0x1f, 0x6a, 0x55, 0x5a, 0x7a, 0xca, 0xbb, 0x5e, 0x6f, 0x79, 0xc8,
+        0xc2, 0xac, 0x8b, 0xf7, 0x78, 0x19, 0xca, 0x5, 0xa6, 0xb2, 0x78, 0x6c, 0x76, 0x26, 0x2b,
+        0xf7, 0x37, 0x1c, 0xef, 0x97, 0xb2, 0x18, 0xe9, 0x6f, 0x17, 0x5a, 0x3c, 0xcd, 0xda, 0x2a,
+        0xcc, 0x5, 0x89, 0x3,
+    ];
+
+    const EXAMPLE_SIGNATURE_HEX: &str = "f3ac8061b514795b8843e3d6629527ed2afd6b1f6a555a7acabb5e6f79c8c2ac8bf77819ca05a6b2786c76262bf7371cef97b218e96f175a3ccdda2acc058903";
+
+    #[test]
+    fn test_debug() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        let debug = format!("{:?}", signature);
+        assert!(debug.starts_with("ecdsa::Signature<"));
+        assert!(debug.contains("MockCurve"));
+        assert!(debug.ends_with(", 137, 3])"));
+    }
+
+    #[test]
+    fn test_encoded_signature_roundtrip() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        let der = signature.to_der();
+
+        let fixed = EncodedSignature::parse(&EXAMPLE_SIGNATURE).unwrap();
+        assert!(!fixed.is_der());
+        assert_eq!(fixed.as_bytes(), &EXAMPLE_SIGNATURE[..]);
+        assert_eq!(fixed.to_fixed(), signature);
+        assert_eq!(fixed.to_der().as_bytes(), der.as_bytes());
+
+        let encoded = EncodedSignature::parse(der.as_bytes()).unwrap();
+        assert!(encoded.is_der());
+        assert_eq!(encoded.as_bytes(), der.as_bytes());
+        assert_eq!(encoded.to_fixed(), signature);
+        assert_eq!(encoded.to_der().as_bytes(), der.as_bytes());
+    }
+
+    #[test]
+    fn test_encoded_signature_parse_invalid() {
+        let der = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap().to_der();
+        let mut bytes = [0u8; 72];
+        bytes[..der.len()].copy_from_slice(der.as_bytes());
+
+        // Malformed DER, and fixed-size signatures of the wrong length
+        bytes[2] = 0x03;
+        assert!(EncodedSignature::parse(&bytes[..der.len()]).is_err());
+        assert!(EncodedSignature::parse(&EXAMPLE_SIGNATURE[..63]).is_err());
+    }
+
+    #[test]
+    fn test_eq() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        assert_eq!(
+            signature,
+            Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap()
+        );
+
+        // Signatures differing only in the last byte of `s`
+        let mut bytes = EXAMPLE_SIGNATURE;
+        bytes[63] ^= 1;
+        assert_ne!(signature, Signature::from_bytes(&bytes).unwrap());
+
+        // Signatures differing only in the first byte of `r`
+        let mut bytes = EXAMPLE_SIGNATURE;
+        bytes[0] ^= 1;
+        assert_ne!(signature, Signature::from_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_iter() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        let bytes = signature.iter().copied().collect::<std::vec::Vec<_>>();
+        assert_eq!(bytes, signature.as_ref());
+        assert!((&signature).into_iter().eq(EXAMPLE_SIGNATURE.iter()));
+        assert!(signature.into_iter().eq(EXAMPLE_SIGNATURE.iter().copied()));
+    }
+
+    #[test]
+    fn test_hash() {
+        let mut other = EXAMPLE_SIGNATURE;
+        other[63] ^= 1;
+
+        let mut set = std::collections::HashSet::new();
+        assert!(set.insert(Signature::from(EXAMPLE_SIGNATURE)));
+        assert!(!set.insert(Signature::from(EXAMPLE_SIGNATURE)));
+        assert!(set.insert(Signature::from(other)));
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_ord() {
+        let mut bytes = [[0u8; 64]; 4];
+        bytes[0][0] = 2;
+        bytes[1][63] = 1;
+        bytes[2][31] = 1;
+        bytes[3][32] = 0xff;
+
+        let mut signatures = [
+            Signature::from(bytes[0]),
+            Signature::from(bytes[1]),
+            Signature::from(bytes[2]),
+            Signature::from(bytes[3]),
+        ];
+        signatures.sort();
+
+        let expected = [bytes[1], bytes[3], bytes[2], bytes[0]];
+
+        for (signature, bytes) in signatures.iter().zip(expected.iter()) {
+            assert_eq!(signature.as_ref(), &bytes[..]);
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_zeroize() {
+        use elliptic_curve::zeroize::Zeroize;
+
+        let mut signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        signature.zeroize();
+        assert!(signature.as_ref().iter().all(|&byte| byte == 0));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_to_vec() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        assert_eq!(signature.to_vec(), &EXAMPLE_SIGNATURE[..]);
+        assert_eq!(signature.to_der_vec(), signature.to_der().as_bytes());
+    }
+
+    /// ES256 signature from RFC 7515 Appendix A.3
+    #[cfg(feature = "jws")]
+    const RFC7515_JWS: &str =
+        "DtEhU3ljbEg8L38VWAfUAqOyKAM6-Xx-F4GawxaepmXFCgfTjDxw5djxLa8ISlSApmWQxfKTUJqPP3-Kg6NU1Q";
+
+    #[cfg(feature = "jws")]
+    #[test]
+    fn test_jws() {
+        let signature = Signature::from_jws(RFC7515_JWS).unwrap();
+        assert_eq!(signature.r()[..4], [14, 209, 33, 83]);
+        assert_eq!(signature.s()[..4], [197, 10, 7, 211]);
+        assert_eq!(signature.to_jws(), RFC7515_JWS);
+    }
+
+    #[cfg(feature = "jws")]
+    #[test]
+    fn test_from_jws_rejects_bad_input() {
+        // Standard base64 alphabet
+        let standard = RFC7515_JWS.replace('-', "+").replace('_', "/");
+        assert!(Signature::from_jws(&standard).is_err());
+
+        // Padding
+        assert!(Signature::from_jws(&format!("{}==", RFC7515_JWS)).is_err());
+
+        // Wrong length
+        assert!(Signature::from_jws(&RFC7515_JWS[..84]).is_err());
+        assert!(Signature::from_jws(&format!("{}AAAA", RFC7515_JWS)).is_err());
+    }
+
+    #[cfg(feature = "cose")]
+    #[test]
+    fn test_cose_bytes() {
+        // ES256 (P-256)
+        let signature = Signature::from_cose_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        assert_eq!(signature.to_cose_bytes().as_slice(), &EXAMPLE_SIGNATURE[..]);
+        assert!(Signature::from_cose_bytes(&EXAMPLE_SIGNATURE[..63]).is_err());
+        assert!(Signature::from_cose_bytes(signature.to_der().as_bytes()).is_err());
+
+        // ES384 (P-384)
+        let bytes = [0x42u8; 96];
+        let signature = crate::Signature::<NistP384>::from_cose_bytes(&bytes).unwrap();
+        assert_eq!(signature.to_cose_bytes().as_slice(), &bytes[..]);
+        assert!(crate::Signature::<NistP384>::from_cose_bytes(&bytes[..64]).is_err());
+        assert!(crate::Signature::<NistP384>::from_cose_bytes(&[0x42; 97]).is_err());
+    }
+
+    #[cfg(feature = "pem")]
+    #[test]
+    fn test_pem_round_trip() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        let pem = signature.to_pem();
+        assert!(pem.starts_with("-----BEGIN ECDSA SIGNATURE-----\n"));
+        assert!(pem.ends_with("-----END ECDSA SIGNATURE-----\n"));
+        assert_eq!(Signature::from_pem(&pem).unwrap(), signature);
+    }
+
+    #[cfg(feature = "pem")]
+    #[test]
+    fn test_from_pem_rejects_bad_input() {
+        let pem = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap().to_pem();
+        let wrong_label = pem.replace("ECDSA SIGNATURE", "EC PRIVATE KEY");
+        let bad_base64 = pem.replacen("-----\n", "-----\n!", 1);
+        assert!(Signature::from_pem(&wrong_label).is_err());
+        assert!(Signature::from_pem(&bad_base64).is_err());
+    }
+
+    #[test]
+    fn test_byte_len() {
+        assert_eq!(Signature::byte_len(), 64);
+        assert_eq!(crate::Signature::<k256::Secp256k1>::byte_len(), 64);
+    }
+
+    #[test]
+    fn test_p1363() {
+        let signature = Signature::from_p1363(&EXAMPLE_SIGNATURE).unwrap();
+        assert_eq!(signature.as_ref(), &EXAMPLE_SIGNATURE[..]);
+        assert_eq!(signature.to_p1363().as_slice(), &EXAMPLE_SIGNATURE[..]);
+
+        // DER is a different wire format, and must not be accepted
+        assert!(Signature::from_p1363(signature.to_der().as_bytes()).is_err());
+        assert!(Signature::from_p1363(&EXAMPLE_SIGNATURE[..63]).is_err());
+    }
+
+    #[test]
+    fn test_from_bytes_auto() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        let der = signature.to_der();
+
+        assert_eq!(
+            Signature::from_bytes_auto(der.as_bytes()).unwrap(),
+            signature
+        );
+        assert_eq!(
+            Signature::from_bytes_auto(&EXAMPLE_SIGNATURE).unwrap(),
+            signature
+        );
+
+        // Fixed-size signature which happens to start with a `SEQUENCE` header
+        let mut bytes = EXAMPLE_SIGNATURE;
+        bytes[..2].copy_from_slice(&[0x30, 0x3e]);
+        assert_eq!(
+            Signature::from_bytes_auto(&bytes).unwrap().as_ref(),
+            &bytes[..]
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_auto_malformed() {
+        let der = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap().to_der();
+
+        // Truncated DER
+        let truncated = &der.as_bytes()[..der.len() - 1];
+        assert!(Signature::from_bytes_auto(truncated).is_err());
+
+        // Corrupted DER: the second `INTEGER` tag is replaced
+        let mut corrupted = [0u8; 72];
+        corrupted.copy_from_slice(der.as_bytes());
+        corrupted[37] = 0x04;
+        assert!(Signature::from_bytes_auto(&corrupted).is_err());
+
+        assert!(Signature::from_bytes_auto(&[]).is_err());
+        assert!(Signature::from_bytes_auto(&EXAMPLE_SIGNATURE[..63]).is_err());
+    }
+
+    #[test]
+    fn test_from_bytes_auto_ambiguous() {
+        // Valid DER signature which is also exactly `SignatureSize` bytes
+        let mut der = [0x11u8; 64];
+        der[..4].copy_from_slice(&[0x30, 0x3e, 0x02, 0x1d]);
+        der[33..35].copy_from_slice(&[0x02, 0x1d]);
+        assert!(Signature::from_der(&der).is_ok());
+
+        let result = Signature::from_bytes_auto(&der);
+
+        #[cfg(feature = "std")]
+        assert_eq!(
+            crate::error::tests::kind(result.unwrap_err()),
+            crate::ErrorKind::InvalidEncoding
+        );
+        #[cfg(not(feature = "std"))]
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_tuple_conversion() {
+        let (r, s) = EXAMPLE_SIGNATURE.split_at(32);
+        let (r, s) = (
+            ElementBytes::<MockCurve>::from_slice(r),
+            ElementBytes::<MockCurve>::from_slice(s),
+        );
+
+        let signature = Signature::from((r, s));
+        assert_eq!(signature, Signature::from_scalars(r, s));
+        assert_eq!(signature.as_ref(), &EXAMPLE_SIGNATURE[..]);
+    }
+
+    #[test]
+    fn test_array_conversions() {
+        let signature = Signature::from(EXAMPLE_SIGNATURE);
+        assert_eq!(signature.as_ref(), &EXAMPLE_SIGNATURE[..]);
+
+        let array: [u8; 64] = signature.into();
+        assert_eq!(&array[..], &EXAMPLE_SIGNATURE[..]);
+
+        let bytes = crate::SignatureBytes::<MockCurve>::from(signature);
+        assert_eq!(Signature::from(&bytes), signature);
+    }
+
+    #[test]
+    fn test_display_fromstr_roundtrip() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        assert_eq!(signature.to_string(), EXAMPLE_SIGNATURE_HEX);
+        assert_eq!(
+            Signature::from_str(&signature.to_string()).unwrap(),
+            signature
+        );
+    }
+
+    #[test]
+    fn test_split_scalars() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        let (r, s) = signature.split_scalars().unwrap();
+        assert_eq!(ElementBytes::<MockCurve>::from(r), *signature.r());
+        assert_eq!(ElementBytes::<MockCurve>::from(s), *signature.s());
+    }
+
+    #[test]
+    fn test_split_scalars_out_of_range() {
+        // `s = n`, the order of the curve
+        let signature = MockCurve::signature(&EXAMPLE_SIGNATURE[..32], &crate::dev::MOCK_ORDER);
+        assert!(signature.split_scalars().is_err());
+    }
+
+    #[test]
+    fn test_split_scalars_zero() {
+        let signature = MockCurve::signature(&[], &EXAMPLE_SIGNATURE[32..]);
+        assert!(signature.split_scalars().is_err());
+    }
+
+    #[test]
+    fn test_from_typed_scalars() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        let (r, s) = signature.split_scalars().unwrap();
+        let typed = Signature::from_typed_scalars(&r, &s);
+
+        assert_eq!(typed, Signature::from_scalars(signature.r(), signature.s()));
+        assert_eq!(typed.as_ref(), &EXAMPLE_SIGNATURE[..]);
+    }
+
+    #[test]
+    fn test_try_from_scalars() {
+        let (r, s) = EXAMPLE_SIGNATURE.split_at(32);
+        let signature = Signature::try_from_scalars(r, s).unwrap();
+        assert_eq!(signature.as_ref(), &EXAMPLE_SIGNATURE[..]);
+    }
+
+    #[test]
+    fn test_try_from_scalars_zero() {
+        let (r, s) = EXAMPLE_SIGNATURE.split_at(32);
+        assert!(Signature::try_from_scalars(&[0; 32], s).is_err());
+        assert!(Signature::try_from_scalars(r, &[0; 32]).is_err());
+    }
+
+    #[test]
+    fn test_try_from_scalars_out_of_range() {
+        let r = &EXAMPLE_SIGNATURE[..32];
+        assert!(Signature::try_from_scalars(r, &crate::dev::MOCK_ORDER).is_err());
+        assert!(Signature::try_from_scalars(&[0xFF; 32], r).is_err());
+    }
+
+    #[test]
+    fn test_try_from_scalars_wrong_length() {
+        let (r, s) = EXAMPLE_SIGNATURE.split_at(32);
+        assert!(Signature::try_from_scalars(&r[1..], s).is_err());
+        assert!(Signature::try_from_scalars(r, &EXAMPLE_SIGNATURE[31..]).is_err());
+    }
+
+    /// High-S signature and its normalized low-S counterpart over secp256k1
+    /// (generated using rust-secp256k1)
+    const SECP256K1_HIGH_S: [u8; 64] = hex!(
+        "20c01a910ebb2610af2d763fa09b3b30923c8e408b11df2c61ad76d970a2f1bc
+         ee2f11ef8cb00a49617d1357f4d55641090a48f201e9b959c48f6f6bec6f938f"
+    );
+    const SECP256K1_LOW_S: [u8; 64] = hex!(
+        "20c01a910ebb2610af2d763fa09b3b30923c8e408b11df2c61ad76d970a2f1bc
+         11d0ee10734ff5b69e82eca80b2aa9bdb1a493f4ad5ee6e1fb42ef20e3c6adb2"
+    );
+
+    #[test]
+    fn test_is_low_s() {
+        let high = crate::Signature::<k256::Secp256k1>::from_bytes(&SECP256K1_HIGH_S).unwrap();
+        let low = crate::Signature::<k256::Secp256k1>::from_bytes(&SECP256K1_LOW_S).unwrap();
+        assert!(!high.is_low_s().unwrap());
+        assert!(low.is_low_s().unwrap());
+
+        let mut normalized = high;
+        assert!(normalized.normalize_s().unwrap());
+        assert!(normalized.is_low_s().unwrap());
+        assert_eq!(normalized, low);
+    }
+
+    #[test]
+    fn test_normalized_s() {
+        let high = crate::Signature::<k256::Secp256k1>::from_bytes(&SECP256K1_HIGH_S).unwrap();
+        let low = crate::Signature::<k256::Secp256k1>::from_bytes(&SECP256K1_LOW_S).unwrap();
+
+        let normalized = high.normalized_s().unwrap();
+        assert!(normalized.is_low_s().unwrap());
+        assert_eq!(normalized, low);
+        assert_eq!(high.as_ref(), &SECP256K1_HIGH_S[..]);
+
+        assert_eq!(low.normalized_s().unwrap(), low);
+    }
+
+    #[test]
+    fn test_half_order() {
+        use crate::CurveOrder;
+
+        let order = MockCurve::order();
+        let half = MockCurve::half_order();
+
+        // `n` is odd, so `2 × ⌊n/2⌋ + 1 = n`
+        let mut doubled = ElementBytes::<MockCurve>::default();
+        let mut carry = 1u16;
+
+        for (out, byte) in doubled.iter_mut().zip(half.iter()).rev() {
+            let sum = (u16::from(*byte) << 1) + carry;
+            *out = sum as u8;
+            carry = sum >> 8;
+        }
+
+        assert_eq!(carry, 0);
+        assert_eq!(doubled, order);
+    }
+
+    #[test]
+    fn test_half_order_is_low_s_boundary() {
+        use crate::{dev::MockScalar, CurveOrder, NormalizeLow};
+        use elliptic_curve::FromBytes;
+
+        let half = MockCurve::half_order();
+        let mut above = half;
+        *above.last_mut().unwrap() += 1;
+
+        let (_, half_is_high) = MockScalar::from_bytes(&half).unwrap().normalize_low();
+        let (_, above_is_high) = MockScalar::from_bytes(&above).unwrap().normalize_low();
+        assert!(!half_is_high);
+        assert!(above_is_high);
+    }
+
+    #[cfg(feature = "hazmat")]
+    #[test]
+    fn test_normalize_s_ct() {
+        for bytes in [SECP256K1_HIGH_S, SECP256K1_LOW_S].iter() {
+            let signature = crate::Signature::<k256::Secp256k1>::from_bytes(bytes).unwrap();
+
+            let mut vartime = signature;
+            let mut ct = signature;
+            let vartime_was_high = vartime.normalize_s().unwrap();
+            let ct_was_high = ct.normalize_s_ct().unwrap();
+
+            assert_eq!(vartime, ct);
+            assert_eq!(vartime_was_high, bool::from(ct_was_high));
+        }
+
+        let mut bytes = SECP256K1_LOW_S;
+        bytes[32..].copy_from_slice(&[0xFF; 32]);
+        let mut signature = crate::Signature::<k256::Secp256k1>::from_bytes(&bytes).unwrap();
+        assert!(signature.normalize_s_ct().is_err());
+    }
+
+    #[cfg(feature = "hazmat")]
+    #[test]
+    fn test_ct_gt_be() {
+        use crate::generic_array::{typenum::U2, GenericArray};
+        let a = GenericArray::<u8, U2>::from([0x01, 0x00]);
+        let b = GenericArray::<u8, U2>::from([0x00, 0xFF]);
+        assert!(bool::from(super::ct_gt_be(&a, &b)));
+        assert!(!bool::from(super::ct_gt_be(&b, &a)));
+        assert!(!bool::from(super::ct_gt_be(&a, &a)));
+    }
+
+    #[test]
+    fn test_from_der_low_s() {
+        let high = crate::Signature::<k256::Secp256k1>::from_bytes(&SECP256K1_HIGH_S).unwrap();
+        let low = crate::Signature::<k256::Secp256k1>::from_bytes(&SECP256K1_LOW_S).unwrap();
+
+        let parsed = crate::Signature::<k256::Secp256k1>::from_der_low_s(low.to_der().as_bytes());
+        assert_eq!(parsed.unwrap(), low);
+
+        let result = crate::Signature::<k256::Secp256k1>::from_der_low_s(high.to_der().as_bytes());
+        #[cfg(feature = "std")]
+        assert_eq!(
+            crate::error::tests::kind(result.unwrap_err()),
+            crate::ErrorKind::HighS
+        );
+        #[cfg(not(feature = "std"))]
+        assert!(result.is_err());
+
+        // Still accepted by the non-strict parser
+        let der = high.to_der();
+        assert_eq!(
+            crate::Signature::<k256::Secp256k1>::from_der(der.as_bytes()).unwrap(),
+            high
+        );
+    }
+
+    #[test]
+    fn test_is_low_s_invalid() {
+        let mut bytes = SECP256K1_LOW_S;
+        bytes[32..].copy_from_slice(&[0xFF; 32]);
+
+        let signature = crate::Signature::<k256::Secp256k1>::from_bytes(&bytes).unwrap();
+        assert!(signature.is_low_s().is_err());
+    }
+
+    #[test]
+    fn test_scalar_bytes() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+
+        for (scalar, expected) in signature
+            .scalar_bytes()
+            .iter()
+            .zip(EXAMPLE_SIGNATURE.chunks(32))
+        {
+            assert_eq!(scalar.as_slice(), expected);
+        }
+
+        assert_eq!(signature.scalar_bytes(), [signature.r(), signature.s()]);
+    }
+
+    #[test]
+    fn test_as_array_and_into_bytes() {
+        use crate::generic_array::{typenum::U64, GenericArray};
+
+        fn checksum(bytes: &GenericArray<u8, U64>) -> u8 {
+            bytes.iter().fold(0, |acc, byte| acc ^ byte)
+        }
+
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        let expected = EXAMPLE_SIGNATURE.iter().fold(0, |acc, byte| acc ^ byte);
+        assert_eq!(checksum(signature.as_array()), expected);
+
+        let bytes: GenericArray<u8, U64> = signature.into_bytes();
+        assert_eq!(checksum(&bytes), expected);
+        assert_eq!(Signature::from(&bytes), signature);
+    }
+
+    #[test]
+    fn test_scalars_le_roundtrip() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        let (r, s) = signature.to_scalars_le();
+        assert_eq!(Signature::from_scalars_le(&r, &s).unwrap(), signature);
+
+        let mut r_be = r;
+        r_be.reverse();
+        assert_eq!(&r_be, signature.r());
+
+        assert!(Signature::from_scalars_le(&r[1..], &s).is_err());
+        assert!(Signature::from_scalars_le(&r, &EXAMPLE_SIGNATURE[31..]).is_err());
+    }
+
+    #[test]
+    fn test_scalars_le_differs_from_be() {
+        let (r, s) = EXAMPLE_SIGNATURE.split_at(32);
+        let le = Signature::from_scalars_le(r, s).unwrap();
+        let be = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        assert_ne!(le, be);
+        assert_eq!(le.r()[0], r[31]);
+        assert_eq!(le.s()[31], s[0]);
+    }
+
+    #[test]
+    fn test_encode_hex_to() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+
+        let mut exact = [0u8; 128];
+        let hex_str = signature.encode_hex_to(&mut exact).unwrap();
+        assert_eq!(hex_str, EXAMPLE_SIGNATURE_HEX);
+        assert_eq!(Signature::decode_hex(&exact).unwrap(), signature);
+
+        let mut larger = [0xffu8; 130];
+        assert_eq!(
+            signature.encode_hex_to(&mut larger).unwrap(),
+            EXAMPLE_SIGNATURE_HEX
+        );
+        assert_eq!(&larger[128..], &[0xff, 0xff]);
+
+        let mut undersized = [0u8; 127];
+        assert!(signature.encode_hex_to(&mut undersized).is_err());
+    }
+
+    #[test]
+    fn test_decode_hex_invalid() {
+        let hex_str = EXAMPLE_SIGNATURE_HEX.as_bytes();
+        assert!(Signature::decode_hex(&hex_str[..126]).is_err());
+
+        let mut invalid = [0u8; 128];
+        invalid.copy_from_slice(hex_str);
+        invalid[0] = b'g';
+        assert!(Signature::decode_hex(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_lower_hex() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        assert_eq!(format!("{:x}", signature), EXAMPLE_SIGNATURE_HEX);
+        assert_eq!(
+            format!("{:#x}", signature),
+            format!("0x{}", EXAMPLE_SIGNATURE_HEX)
+        );
+    }
+
+    #[test]
+    fn test_upper_hex() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        let upper = EXAMPLE_SIGNATURE_HEX.to_uppercase();
+        assert_eq!(format!("{:X}", signature), upper);
+        assert_eq!(format!("{:#X}", signature), format!("0x{}", upper));
+    }
+
+    #[test]
+    fn test_fromstr_uppercase() {
+        let hex_str = EXAMPLE_SIGNATURE_HEX.to_uppercase();
+        assert_eq!(
+            Signature::from_str(&hex_str).unwrap().as_ref(),
+            &EXAMPLE_SIGNATURE[..]
+        );
+    }
+
+    #[test]
+    fn test_fromstr_invalid() {
+        // Odd length
+        assert!(Signature::from_str(&EXAMPLE_SIGNATURE_HEX[1..]).is_err());
+
+        // Wrong length
+        assert!(Signature::from_str(&EXAMPLE_SIGNATURE_HEX[2..]).is_err());
+
+        // Non-hex characters
+        let hex_str = EXAMPLE_SIGNATURE_HEX.replace("f3", "g3");
+        assert!(Signature::from_str(&hex_str).is_err());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut input = [0u8; 70];
+        input[..64].copy_from_slice(&EXAMPLE_SIGNATURE);
+
+        let mut u = Unstructured::new(&input);
+        let signature = Signature::arbitrary(&mut u).unwrap();
+        assert_eq!(signature.as_ref(), &EXAMPLE_SIGNATURE[..]);
+        assert_eq!(u.len(), 6);
+        assert!(Signature::arbitrary(&mut u).is_err());
+
+        assert_eq!(Signature::size_hint(0), (64, Some(64)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json_roundtrip() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        let json = serde_json::to_string(&signature).unwrap();
+        assert_eq!(json, format!("\"{}\"", EXAMPLE_SIGNATURE_HEX));
+
+        let decoded: Signature = serde_json::from_str(&json).unwrap();
+        assert_eq!(signature, decoded);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json_wrong_length() {
+        let json = format!("\"{}\"", &EXAMPLE_SIGNATURE_HEX[2..]);
+        assert!(serde_json::from_str::<Signature>(&json).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_bincode_roundtrip() {
+        let signature = Signature::from_bytes(&EXAMPLE_SIGNATURE).unwrap();
+        let encoded = bincode::serialize(&signature).unwrap();
+        assert_eq!(&encoded[8..], &EXAMPLE_SIGNATURE[..]);
+
+        let decoded: Signature = bincode::deserialize(&encoded).unwrap();
+        assert_eq!(signature, decoded);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_bincode_wrong_length() {
+        let encoded = bincode::serialize(&EXAMPLE_SIGNATURE[1..]).unwrap();
+        assert!(bincode::deserialize::<Signature>(&encoded).is_err());
+    }
+
+    /// RFC 6979 A.2.6 signature of "sample" using P-384 and SHA-384
+    const P384_SIGNATURE: [u8; 96] = hex!(
+        "94EDBB92A5ECB8AAD4736E56C691916B3F88140666CE9FA73D64C4EA95AD133C
+         81A648152E44ACF96E36DD1E80FABE46
+         99EF4AEB15F178CEA1FE40DB2603138F130E740A19624526203B6351D0A3A94F
+         A329C145786E679E7B82C71A38628AC8"
+    );
+
+    /// `P384_SIGNATURE` normalized into low-S form, i.e. with `s` negated
+    const P384_SIGNATURE_LOW_S: [u8; 96] = hex!(
+        "94EDBB92A5ECB8AAD4736E56C691916B3F88140666CE9FA73D64C4EA95AD133C
+         81A648152E44ACF96E36DD1E80FABE46
+         6610B514EA0E87315E01BF24D9FCEC70ECF18BF5E69DBAD9A727EA302393848F
+         B4F04C6CD0423FDC7169525094629EAB"
+    );
+
+    type P384Signature = crate::Signature<NistP384>;
+
+    #[test]
+    fn test_p384_from_scalars() {
+        let r = ElementBytes::<NistP384>::from_slice(&P384_SIGNATURE[..48]);
+        let s = ElementBytes::<NistP384>::from_slice(&P384_SIGNATURE[48..]);
+        let signature = P384Signature::from_scalars(r, s);
+
+        assert_eq!(P384Signature::byte_len(), 96);
+        assert_eq!(signature.as_ref(), &P384_SIGNATURE[..]);
+        assert_eq!(signature.r(), r);
+        assert_eq!(signature.s(), s);
+        assert_eq!(
+            P384Signature::from_bytes(&P384_SIGNATURE).unwrap(),
+            signature
+        );
+        assert!(P384Signature::from_bytes(&P384_SIGNATURE[..64]).is_err());
+    }
+
+    #[test]
+    fn test_p384_split_scalars() {
+        let signature = P384Signature::from_bytes(&P384_SIGNATURE).unwrap();
+        let (r, s) = signature.split_scalars().unwrap();
+        assert_eq!(ElementBytes::<NistP384>::from(r), *signature.r());
+        assert_eq!(ElementBytes::<NistP384>::from(s), *signature.s());
+
+        // `s` larger than the P-384 order (but valid for a 32-byte curve)
+        let mut bytes = P384_SIGNATURE;
+        bytes[48..].copy_from_slice(&[0xFF; 48]);
+        let signature = P384Signature::from_bytes(&bytes).unwrap();
+        assert!(signature.split_scalars().is_err());
+    }
+
+    #[test]
+    fn test_p384_normalize_s() {
+        let high = P384Signature::from_bytes(&P384_SIGNATURE).unwrap();
+        let low = P384Signature::from_bytes(&P384_SIGNATURE_LOW_S).unwrap();
+        assert!(!high.is_low_s().unwrap());
+        assert!(low.is_low_s().unwrap());
+
+        let mut normalized = high;
+        assert!(normalized.normalize_s().unwrap());
+        assert_eq!(normalized, low);
+        assert!(!normalized.normalize_s().unwrap());
+        assert_eq!(normalized, low);
+    }
+
+    #[test]
+    fn test_p384_normalize_s_if_high() {
+        let low = P384Signature::from_bytes(&P384_SIGNATURE_LOW_S).unwrap();
+
+        for &bytes in &[P384_SIGNATURE, P384_SIGNATURE_LOW_S] {
+            let mut signature = P384Signature::from_bytes(&bytes).unwrap();
+            signature.normalize_s_if_high().unwrap();
+            assert_eq!(signature, low);
+        }
+
+        let mut bytes = P384_SIGNATURE;
+        bytes[48..].copy_from_slice(&[0xFF; 48]);
+        let mut signature = P384Signature::from_bytes(&bytes).unwrap();
+        assert!(signature.normalize_s_if_high().is_err());
+    }
+}
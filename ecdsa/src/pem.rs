@@ -0,0 +1,142 @@
+//! PEM encoding support for ASN.1 DER signatures
+//!
+//! Signatures are armored with the `ECDSA SIGNATURE` label, e.g.:
+//!
+//! ```text
+//! -----BEGIN ECDSA SIGNATURE-----
+//! MEUCIQDv1IsqrLao/RFA3ZzUXoHWnSyHe1aq+ZHDTQ6oTq83FgIg98sclC1lfEHU
+//! NsehtuKfZfPpANu5r/QGTcSrL4Q6zag=
+//! -----END ECDSA SIGNATURE-----
+//! ```
+
+use crate::{
+    base64::{self, Alphabet},
+    Error, ErrorKind,
+};
+use alloc::{string::String, vec::Vec};
+
+/// Label used in the PEM encapsulation boundaries
+const LABEL: &str = "ECDSA SIGNATURE";
+
+/// Maximum length of a line of base64 in the encapsulated text
+const LINE_WIDTH: usize = 64;
+
+/// Encode the given DER bytes as PEM
+pub(crate) fn encode(der: &[u8]) -> String {
+    let encoded = base64::encode(der, Alphabet::Standard);
+    let mut pem = String::new();
+
+    pem.push_str("-----BEGIN ");
+    pem.push_str(LABEL);
+    pem.push_str("-----\n");
+
+    for line in encoded.as_bytes().chunks(LINE_WIDTH) {
+        // base64 output is always ASCII
+        pem.push_str(core::str::from_utf8(line).unwrap());
+        pem.push('\n');
+    }
+
+    pem.push_str("-----END ");
+    pem.push_str(LABEL);
+    pem.push_str("-----\n");
+    pem
+}
+
+/// Decode PEM with the expected label into DER bytes.
+///
+/// Leading and trailing whitespace is ignored, as is whitespace (including
+/// `\r\n` line endings) within the encapsulated base64.
+pub(crate) fn decode(pem: &str) -> Result<Vec<u8>, Error> {
+    let pem = pem.trim();
+    let body = strip_boundary(pem, "-----BEGIN ", false)
+        .and_then(|body| strip_boundary(body, "-----END ", true))
+        .ok_or(ErrorKind::InvalidEncoding)?;
+
+    let encoded: Vec<u8> = body
+        .bytes()
+        .filter(|byte| !byte.is_ascii_whitespace())
+        .collect();
+
+    base64::decode(&encoded, Alphabet::Standard)
+}
+
+/// Strip the encapsulation boundary with the given prefix from the start
+/// (or end, if `at_end` is set) of the input, checking the label matches
+fn strip_boundary<'a>(pem: &'a str, prefix: &str, at_end: bool) -> Option<&'a str> {
+    let len = prefix.len() + LABEL.len() + 5;
+    let split = if at_end {
+        pem.len().checked_sub(len)?
+    } else {
+        len
+    };
+
+    let (boundary, rest) = if at_end {
+        (pem.get(split..)?, pem.get(..split)?)
+    } else {
+        (pem.get(..split)?, pem.get(split..)?)
+    };
+
+    if boundary.starts_with(prefix)
+        && boundary[prefix.len()..].starts_with(LABEL)
+        && boundary.ends_with("-----")
+    {
+        Some(rest)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode};
+    use std::{format, vec::Vec};
+
+    #[test]
+    fn wraps_long_lines() {
+        let pem = encode(&[0u8; 96]);
+        let lines: Vec<_> = pem.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[1].len(), 64);
+        assert_eq!(lines[2].len(), 64);
+        assert_eq!(decode(&pem).unwrap(), &[0u8; 96][..]);
+    }
+
+    #[test]
+    fn accepts_crlf() {
+        let pem = encode(b"foobar").replace('\n', "\r\n");
+        assert_eq!(decode(&pem).unwrap(), b"foobar");
+    }
+
+    #[test]
+    fn reject_mismatched_label() {
+        let pem = encode(b"foobar");
+
+        for label in &["CERTIFICATE", "ECDSA SIGNATUR", "ECDSA SIGNATURES"] {
+            let begin = pem.replacen("ECDSA SIGNATURE", label, 1);
+            assert!(decode(&begin).is_err(), "BEGIN {}", label);
+
+            let end = pem.replace("END ECDSA SIGNATURE", &format!("END {}", label));
+            assert!(decode(&end).is_err(), "END {}", label);
+        }
+    }
+
+    #[test]
+    fn reject_non_ascii() {
+        assert!(decode(
+            "-----BEGIN ECDSA SIGNATUR\u{e9}-----\nZm9vYmFy\n-----END ECDSA SIGNATURE-----"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn reject_invalid_base64() {
+        let pem = encode(b"foobar").replace("Zm9vYmFy", "Zm9v+mF!");
+        assert!(decode(&pem).is_err());
+    }
+
+    #[test]
+    fn reject_missing_boundaries() {
+        assert!(decode("Zm9vYmFy").is_err());
+        assert!(decode("-----BEGIN ECDSA SIGNATURE-----\nZm9vYmFy\n").is_err());
+    }
+}
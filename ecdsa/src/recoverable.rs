@@ -0,0 +1,774 @@
+//! Recoverable ECDSA signatures, which carry an additional "recovery ID"
+//! allowing the signer's public key to be computed from the signature and
+//! the signed message.
+//!
+//! This is the signature format used by e.g. Bitcoin's "compact" signatures
+//! and Ethereum's `eth_sign`.
+
+use crate::{Error, ErrorKind};
+use core::{
+    convert::{TryFrom, TryInto},
+    fmt::{self, Debug},
+    ops::Add,
+};
+use elliptic_curve::{
+    consts::{U1, U32},
+    generic_array::{typenum::Unsigned, ArrayLength, GenericArray},
+    weierstrass::Curve,
+};
+
+#[cfg(feature = "hazmat")]
+use {
+    crate::hazmat::CurveArithmetic,
+    elliptic_curve::{
+        weierstrass::{
+            point::{
+                CompressedPoint, CompressedPointSize, UncompressedPoint, UncompressedPointSize,
+            },
+            public_key::{FromPublicKey, PublicKey},
+        },
+        ElementBytes, FromBytes,
+    },
+};
+
+#[cfg(all(feature = "digest", feature = "hazmat"))]
+use {crate::hazmat::DigestPrimitive, signature::digest::Digest};
+
+/// Size of a recoverable signature for the given elliptic curve: the
+/// fixed-size signature followed by a 1-byte recovery ID.
+pub type SignatureSize<C> = <crate::SignatureSize<C> as Add<U1>>::Output;
+
+/// Fixed-size byte array containing a recoverable ECDSA signature
+pub type SignatureBytes<C> = GenericArray<u8, SignatureSize<C>>;
+
+/// Offset added to a [`RecoveryId`] to obtain a legacy Ethereum `v` value
+const ETH_V_OFFSET: u8 = 27;
+
+/// Offset added to a [`RecoveryId`] (along with twice the chain ID) to obtain
+/// an [EIP-155] Ethereum `v` value
+///
+/// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+const EIP155_V_OFFSET: u8 = 35;
+
+/// Recovery IDs, a.k.a. "recid".
+///
+/// This is an integer value `0`, `1`, `2`, or `3` included along with a
+/// signature which is used during the recovery process to select the correct
+/// public key from the signature.
+///
+/// It consists of two bits of information:
+///
+/// - low bit (0/1): was the y-coordinate of the affine point resulting from
+///   the fixed-base multiplication 𝑘×𝑮 odd? This part of the algorithm
+///   functions similar to point decompression.
+/// - hi bit (2/3): did the affine x-coordinate of 𝑘×𝑮 overflow the curve
+///   order? This is very unlikely in practice.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct RecoveryId(u8);
+
+impl RecoveryId {
+    /// Maximum supported value for the recovery ID (inclusive).
+    pub const MAX: u8 = 3;
+
+    /// Create a new [`RecoveryId`] from the given byte, returning an error
+    /// if it's out of range.
+    pub fn new(byte: u8) -> Result<Self, Error> {
+        if byte <= Self::MAX {
+            Ok(Self(byte))
+        } else {
+            Err(ErrorKind::InvalidRecoveryId.into())
+        }
+    }
+
+    /// Was the affine x-coordinate of 𝑘×𝑮 larger than the curve order?
+    pub fn is_x_reduced(self) -> bool {
+        (self.0 & 0b10) != 0
+    }
+
+    /// Was the affine y-coordinate of 𝑘×𝑮 odd?
+    pub fn is_y_odd(self) -> bool {
+        (self.0 & 1) != 0
+    }
+
+    /// Serialize this [`RecoveryId`] as a byte
+    pub fn to_byte(self) -> u8 {
+        self.0
+    }
+}
+
+#[cfg(feature = "hazmat")]
+impl RecoveryId {
+    /// Compute the [`RecoveryId`] for the given signature from the signer's
+    /// ephemeral public key, i.e. the point `𝐑 = k×𝑮`.
+    ///
+    /// Each candidate ID is checked by reconstructing `𝐑` from `r` in the
+    /// same way as during recovery, including adding the curve order `n` to
+    /// `r` for the x-reduced candidates, so the result is also correct when
+    /// the x-coordinate of `𝐑` is not less than `n`.
+    ///
+    /// Returns an error if no candidate matches, i.e. if `r` isn't the
+    /// x-coordinate of `𝐑` reduced modulo `n`.
+    #[cfg_attr(docsrs, doc(cfg(feature = "hazmat")))]
+    pub fn from_signature_and_key<C>(
+        signature: &crate::Signature<C>,
+        r_point: &C::AffinePoint,
+    ) -> Result<Self, Error>
+    where
+        C: CurveArithmetic,
+        C::AffinePoint: FromPublicKey<C>,
+        C::ElementSize: Add<U1>,
+        <C::ElementSize as Add>::Output: Add<U1>,
+        CompressedPointSize<C>: ArrayLength<u8>,
+        UncompressedPoint<C>: From<C::AffinePoint>,
+        UncompressedPointSize<C>: ArrayLength<u8>,
+        crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+        SignatureSize<C>: ArrayLength<u8>,
+    {
+        let expected_point = UncompressedPoint::from(*r_point);
+
+        for id in 0..=Self::MAX {
+            let recovery_id = RecoveryId(id);
+
+            if let Ok(point) = Signature::new(signature, recovery_id).r_point() {
+                if UncompressedPoint::from(point) == expected_point {
+                    return Ok(recovery_id);
+                }
+            }
+        }
+
+        Err(ErrorKind::InvalidRecoveryId.into())
+    }
+}
+
+impl TryFrom<u8> for RecoveryId {
+    type Error = Error;
+
+    fn try_from(byte: u8) -> Result<Self, Error> {
+        Self::new(byte)
+    }
+}
+
+impl From<RecoveryId> for u8 {
+    fn from(recovery_id: RecoveryId) -> u8 {
+        recovery_id.0
+    }
+}
+
+/// Recoverable ECDSA signatures.
+///
+/// Generic over elliptic curve types.
+///
+/// These are serialized as a fixed-size [`crate::Signature`] (i.e. `r || s`)
+/// followed by a 1-byte [`RecoveryId`]. For example, in a curve with a
+/// 256-bit modulus like secp256k1, the resulting signature is 65-bytes.
+#[derive(Clone, Eq, PartialEq)]
+pub struct Signature<C: Curve>
+where
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    bytes: SignatureBytes<C>,
+}
+
+impl<C: Curve> Signature<C>
+where
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Create a new recoverable ECDSA signature from a fixed-size signature
+    /// and a [`RecoveryId`].
+    pub fn new(signature: &crate::Signature<C>, recovery_id: RecoveryId) -> Self {
+        let mut bytes = SignatureBytes::<C>::default();
+        let recovery_id_offset = <crate::SignatureSize<C>>::to_usize();
+        bytes[..recovery_id_offset].copy_from_slice(signature.as_ref());
+        bytes[recovery_id_offset] = recovery_id.to_byte();
+        Self { bytes }
+    }
+
+    /// Get the [`RecoveryId`] for this signature
+    pub fn recovery_id(&self) -> RecoveryId {
+        RecoveryId(self.bytes[<crate::SignatureSize<C>>::to_usize()])
+    }
+
+    /// Get the fixed-size signature this recoverable signature contains
+    /// (i.e. with the [`RecoveryId`] removed)
+    pub fn signature(&self) -> crate::Signature<C> {
+        self.bytes[..<crate::SignatureSize<C>>::to_usize()]
+            .try_into()
+            .expect("signature size mismatch")
+    }
+}
+
+impl<C> Signature<C>
+where
+    C: Curve<ElementSize = U32>,
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Serialize this signature in the 65-byte `r || s || v` format used by
+    /// Ethereum's `eth_sign`, where `v` is `27` plus the [`RecoveryId`].
+    ///
+    /// Note that Ethereum itself only accepts `v` values of `27` and `28`,
+    /// i.e. it doesn't support recovery IDs with the x-reduced bit set.
+    pub fn to_eth_bytes(&self) -> [u8; 65] {
+        let mut bytes = [0u8; 65];
+        bytes.copy_from_slice(&self.bytes);
+        bytes[64] += ETH_V_OFFSET;
+        bytes
+    }
+
+    /// Parse a signature in the 65-byte `r || s || v` format used by
+    /// Ethereum's `eth_sign` and transaction signatures.
+    ///
+    /// Accepts both legacy `v` values (`27` plus the [`RecoveryId`]) and
+    /// [EIP-155] values (`chain_id * 2 + 35` plus the y-parity bit), and
+    /// returns an error for `v` values which don't correspond to either.
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    pub fn from_eth_bytes(bytes: &[u8; 65]) -> Result<Self, Error> {
+        let recovery_id = match bytes[64] {
+            v @ ETH_V_OFFSET..=30 => v - ETH_V_OFFSET,
+            v if v >= EIP155_V_OFFSET => (v - EIP155_V_OFFSET) & 1,
+            _ => return Err(ErrorKind::InvalidRecoveryId.into()),
+        };
+
+        let mut bytes = *bytes;
+        bytes[64] = recovery_id;
+        Self::try_from(&bytes[..])
+    }
+}
+
+#[cfg(feature = "hazmat")]
+impl<C> Signature<C>
+where
+    C: CurveArithmetic,
+    C::AffinePoint: FromPublicKey<C>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPoint<C>: From<C::AffinePoint>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Recover the public key used to create this signature over the given
+    /// prehashed message (i.e. the output of a digest function).
+    ///
+    /// Returns an error if the [`RecoveryId`] doesn't correspond to a valid
+    /// curve point, or if the recovered key is the point at infinity.
+    #[cfg_attr(docsrs, doc(cfg(feature = "hazmat")))]
+    pub fn recover_verifying_key_from_prehash(
+        &self,
+        prehash: &ElementBytes<C>,
+    ) -> Result<PublicKey<C>, Error> {
+        self.recover_point(prehash).map(to_public_key)
+    }
+
+    /// Recover the affine point of the signer's public key from the given
+    /// prehashed message, i.e. compute `r⁻¹(s×𝐑 - z×𝑮)`.
+    fn recover_point(&self, prehash: &ElementBytes<C>) -> Result<C::AffinePoint, Error> {
+        let signature = self.signature();
+        let (r, s) = (
+            C::Scalar::from_bytes(signature.r()),
+            C::Scalar::from_bytes(signature.s()),
+        );
+
+        if r.is_none().into() || s.is_none().into() {
+            return Err(ErrorKind::ScalarOutOfRange.into());
+        }
+
+        let (r, s) = (r.unwrap(), s.unwrap());
+        let r_inverse = C::invert_scalar(&r);
+
+        if r_inverse.is_none().into() {
+            return Err(ErrorKind::ZeroScalar.into());
+        }
+
+        let r_inverse = r_inverse.unwrap();
+        let r_point = self.r_point()?;
+
+        let z = C::reduce_scalar(prehash);
+        let u1 = C::negate_scalar(&C::mul_scalars(&r_inverse, &z));
+        let u2 = C::mul_scalars(&r_inverse, &s);
+
+        let u1_g = C::mul_base(&u1);
+        let u2_r = C::mul_point(&r_point, &u2);
+
+        if u1_g.is_none().into() || u2_r.is_none().into() {
+            return Err(ErrorKind::InvalidPoint.into());
+        }
+
+        let point = C::add_points(&u1_g.unwrap(), &u2_r.unwrap());
+
+        if point.is_some().into() {
+            Ok(point.unwrap())
+        } else {
+            Err(ErrorKind::InvalidPoint.into())
+        }
+    }
+
+    /// Reconstruct the ephemeral point `𝐑 = k×𝑮` from the `r` component of
+    /// this signature and its [`RecoveryId`].
+    pub(crate) fn r_point(&self) -> Result<C::AffinePoint, Error> {
+        let signature = self.signature();
+        let recovery_id = self.recovery_id();
+
+        // Reconstruct 𝐑 from its (possibly reduced) x-coordinate
+        let mut x = signature.r().clone();
+
+        if recovery_id.is_x_reduced() {
+            x = add_order::<C>(&x).ok_or(ErrorKind::InvalidRecoveryId)?;
+        }
+
+        let mut r_point_bytes = GenericArray::default();
+        r_point_bytes[0] = if recovery_id.is_y_odd() { 0x03 } else { 0x02 };
+        r_point_bytes[1..].copy_from_slice(&x);
+
+        let r_point = CompressedPoint::from_bytes(r_point_bytes)
+            .map(PublicKey::Compressed)
+            .map(|point| C::AffinePoint::from_public_key(&point))
+            .ok_or(ErrorKind::InvalidPoint)?;
+
+        if r_point.is_some().into() {
+            Ok(r_point.unwrap())
+        } else {
+            Err(ErrorKind::InvalidPoint.into())
+        }
+    }
+}
+
+#[cfg(all(feature = "digest", feature = "hazmat"))]
+impl<C> Signature<C>
+where
+    C: CurveArithmetic + DigestPrimitive,
+    C::AffinePoint: FromPublicKey<C>,
+    C::Digest: Digest<OutputSize = C::ElementSize>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPoint<C>: From<C::AffinePoint>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Given a public key, message, and signature, use trial recovery to
+    /// determine the [`RecoveryId`], i.e. try each recovery ID in turn until
+    /// one of them recovers the expected public key.
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "digest", feature = "hazmat"))))]
+    pub fn from_trial_recovery(
+        public_key: &PublicKey<C>,
+        msg: &[u8],
+        signature: &crate::Signature<C>,
+    ) -> Result<Self, Error> {
+        let expected_point = C::AffinePoint::from_public_key(public_key);
+
+        if expected_point.is_none().into() {
+            return Err(ErrorKind::InvalidPoint.into());
+        }
+
+        let expected_point = UncompressedPoint::from(expected_point.unwrap());
+        let prehash = C::Digest::digest(msg);
+
+        for id in 0..=RecoveryId::MAX {
+            let recoverable_signature = Signature::new(signature, RecoveryId(id));
+
+            if let Ok(point) = recoverable_signature.recover_point(&prehash) {
+                if UncompressedPoint::from(point) == expected_point {
+                    return Ok(recoverable_signature);
+                }
+            }
+        }
+
+        Err(ErrorKind::VerificationFailed.into())
+    }
+
+    /// Recover the public key used to create this signature over the given
+    /// message, hashing it with the curve's [`DigestPrimitive`].
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "digest", feature = "hazmat"))))]
+    pub fn recover_verifying_key(&self, msg: &[u8]) -> Result<PublicKey<C>, Error> {
+        self.recover_verifying_key_from_prehash(&C::Digest::digest(msg))
+    }
+
+    /// Recover the public key used to create this signature over the given
+    /// message as in [`Signature::recover_verifying_key`], then check the
+    /// signature verifies under the recovered key before returning it.
+    ///
+    /// Recovery succeeds for almost any signature and message, yielding
+    /// *some* public key, so callers must still compare the returned key
+    /// against the one they expect (e.g. an Ethereum address).
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "digest", feature = "hazmat"))))]
+    pub fn verify_recover(&self, msg: &[u8]) -> Result<PublicKey<C>, Error> {
+        let prehash = C::Digest::digest(msg);
+        let point = self.recover_point(&prehash)?;
+        crate::hazmat::verify_prehashed::<C>(&point, &prehash, &self.signature())?;
+        Ok(to_public_key(point))
+    }
+}
+
+impl<C: Curve> signature::Signature for Signature<C>
+where
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        Self::try_from(bytes)
+    }
+}
+
+impl<C: Curve> AsRef<[u8]> for Signature<C>
+where
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn as_ref(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+}
+
+impl<C: Curve> Copy for Signature<C>
+where
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    SignatureSize<C>: ArrayLength<u8>,
+    <SignatureSize<C> as ArrayLength<u8>>::ArrayType: Copy,
+{
+}
+
+impl<C: Curve> Debug for Signature<C>
+where
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ecdsa::recoverable::Signature<{}>({:?})",
+            core::any::type_name::<C>(),
+            self.as_ref()
+        )
+    }
+}
+
+impl<C: Curve> TryFrom<&[u8]> for Signature<C>
+where
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != <SignatureSize<C>>::to_usize() {
+            return Err(ErrorKind::InvalidLength.into());
+        }
+
+        RecoveryId::new(bytes[<crate::SignatureSize<C>>::to_usize()])?;
+
+        Ok(Self {
+            bytes: GenericArray::clone_from_slice(bytes),
+        })
+    }
+}
+
+impl<C: Curve> From<Signature<C>> for SignatureBytes<C>
+where
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn from(signature: Signature<C>) -> SignatureBytes<C> {
+        signature.bytes
+    }
+}
+
+impl<C: Curve> From<Signature<C>> for crate::Signature<C>
+where
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn from(signature: Signature<C>) -> crate::Signature<C> {
+        signature.signature()
+    }
+}
+
+/// Convert a recovered point into a public key, compressing it if the curve
+/// uses compressed points
+#[cfg(feature = "hazmat")]
+fn to_public_key<C>(point: C::AffinePoint) -> PublicKey<C>
+where
+    C: CurveArithmetic,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPoint<C>: From<C::AffinePoint>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    let mut public_key = PublicKey::from(UncompressedPoint::from(point));
+
+    if C::COMPRESS_POINTS {
+        public_key.compress();
+    }
+
+    public_key
+}
+
+/// Compute `x + n`, where `n` is the order of the curve, returning `None` if
+/// the result overflows the field element size.
+#[cfg(feature = "hazmat")]
+fn add_order<C: CurveArithmetic>(x: &ElementBytes<C>) -> Option<ElementBytes<C>> {
+    // `n - 1` is the additive inverse of `1`
+    let mut one = ElementBytes::<C>::default();
+    *one.last_mut().unwrap() = 1;
+    let n_minus_one: ElementBytes<C> =
+        C::negate_scalar(&C::Scalar::from_bytes(&one).unwrap()).into();
+
+    // Big endian `x + (n - 1) + 1`
+    let mut result = ElementBytes::<C>::default();
+    let mut carry = 1u16;
+
+    for i in (0..result.len()).rev() {
+        let sum = u16::from(x[i]) + u16::from(n_minus_one[i]) + carry;
+        result[i] = sum as u8;
+        carry = sum >> 8;
+    }
+
+    if carry == 0 {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RecoveryId;
+    use crate::dev::MockCurve;
+    use core::convert::TryFrom;
+
+    type Signature = super::Signature<MockCurve>;
+
+    #[test]
+    fn recovery_id_range() {
+        for byte in 0..=3 {
+            let recovery_id = RecoveryId::new(byte).unwrap();
+            assert_eq!(recovery_id.is_y_odd(), byte & 1 == 1);
+            assert_eq!(recovery_id.is_x_reduced(), byte & 2 == 2);
+        }
+
+        assert!(RecoveryId::new(4).is_err());
+        assert!(RecoveryId::try_from(0xFF).is_err());
+    }
+
+    #[test]
+    fn bytes_roundtrip() {
+        let mut bytes = [0u8; 65];
+        bytes[..64].copy_from_slice(&[0x42; 64]);
+        bytes[64] = 1;
+
+        let signature = Signature::try_from(&bytes[..]).unwrap();
+        assert_eq!(signature.recovery_id(), RecoveryId::new(1).unwrap());
+        assert_eq!(signature.signature().as_ref(), &bytes[..64]);
+        assert_eq!(signature.as_ref(), &bytes[..]);
+
+        let fixed = crate::Signature::<MockCurve>::from(signature);
+        assert_eq!(
+            Signature::new(&fixed, RecoveryId::new(1).unwrap()),
+            signature
+        );
+    }
+
+    #[test]
+    fn invalid_bytes() {
+        let mut bytes = [0u8; 65];
+        bytes[64] = 4;
+        assert!(Signature::try_from(&bytes[..]).is_err());
+        assert!(Signature::try_from(&bytes[..64]).is_err());
+    }
+
+    #[test]
+    fn eth_bytes_roundtrip() {
+        let mut bytes = [0x42u8; 65];
+        bytes[64] = 1;
+
+        let signature = super::Signature::<k256::Secp256k1>::try_from(&bytes[..]).unwrap();
+        let eth_bytes = signature.to_eth_bytes();
+        assert_eq!(&eth_bytes[..64], &bytes[..64]);
+        assert_eq!(eth_bytes[64], 28);
+
+        let parsed = super::Signature::<k256::Secp256k1>::from_eth_bytes(&eth_bytes).unwrap();
+        assert_eq!(parsed, signature);
+    }
+
+    #[test]
+    fn eth_bytes_invalid_v() {
+        let mut bytes = [0x42u8; 65];
+
+        for &v in &[0, 1, 26, 31, 34] {
+            bytes[64] = v;
+            assert!(super::Signature::<k256::Secp256k1>::from_eth_bytes(&bytes).is_err());
+        }
+    }
+
+    /// EIP-155 example transaction signature (chain ID 1, `v = 37`) by the
+    /// secp256k1 key `d = 0x4646...46`
+    #[cfg(feature = "hazmat")]
+    #[test]
+    fn eth_bytes_eip155_recovery() {
+        use hex_literal::hex;
+        use k256::{PublicKey, Secp256k1};
+
+        let public_key = PublicKey::from_bytes(
+            &hex!("024BC2A31265153F07E70E0BAB08724E6B85E217F8CD628CEB62974247BB493382")[..],
+        )
+        .unwrap();
+
+        let signing_hash = hex!("DAF5A779AE972F972197303D7B574746C7EF83EADAC0F2791AD23DB92E4C8E53");
+
+        let signature = super::Signature::<Secp256k1>::from_eth_bytes(&hex!(
+            "28EF61340BD939BC2195FE537567866003E1A15D3C71FF63E1590620AA636276
+             67CBE9D8997F761AECB703304B3800CCF555C9F3DC64214B297FB1966A3B6D83
+             25"
+        ))
+        .unwrap();
+
+        assert_eq!(signature.recovery_id(), RecoveryId::new(0).unwrap());
+        assert_eq!(signature.to_eth_bytes()[64], 27);
+        assert_eq!(
+            signature
+                .recover_verifying_key_from_prehash(&signing_hash.into())
+                .unwrap(),
+            public_key
+        );
+    }
+
+    #[cfg(all(feature = "digest", feature = "hazmat"))]
+    #[test]
+    fn trial_recovery() {
+        use hex_literal::hex;
+        use p256::{NistP256, PublicKey};
+
+        // RFC 6979 A.2.5: P-256 with SHA-256, message "sample"
+        let public_key = PublicKey::from_bytes(
+            &hex!(
+                "0460FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6
+             7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299"
+            )[..],
+        )
+        .unwrap();
+
+        let signature = crate::Signature::<NistP256>::from_scalars(
+            &hex!("EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716").into(),
+            &hex!("F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8").into(),
+        );
+
+        let recoverable_signature =
+            super::Signature::from_trial_recovery(&public_key, b"sample", &signature).unwrap();
+
+        assert_eq!(recoverable_signature.signature(), signature);
+        assert!(!recoverable_signature.recovery_id().is_x_reduced());
+
+        // Wrong message
+        assert!(super::Signature::from_trial_recovery(&public_key, b"test", &signature).is_err());
+    }
+
+    #[cfg(all(feature = "digest", feature = "hazmat"))]
+    #[test]
+    fn recover_verifying_key() {
+        use hex_literal::hex;
+        use k256::{PublicKey, Secp256k1};
+
+        // Signature over "example message" (SHA-256) by the secp256k1 key
+        // d = 1, i.e. a public key of 𝑮
+        let public_key = PublicKey::from_bytes(
+            &hex!("0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798")[..],
+        )
+        .unwrap();
+
+        let signature = super::Signature::<Secp256k1>::try_from(
+            &hex!(
+                "24653EAC434488002CC06BBFB7F10FE18991E35F9FE4302DBEA6D2353DC0AB1C
+                 8D7C23E003C717DF75E8BA5362D889CA5A9370C82509FB774D25BA99D2B6DFFA
+                 01"
+            )[..],
+        )
+        .unwrap();
+
+        assert_eq!(
+            signature.recover_verifying_key(b"example message").unwrap(),
+            public_key
+        );
+    }
+
+    #[cfg(all(feature = "digest", feature = "hazmat"))]
+    #[test]
+    fn verify_recover() {
+        use hex_literal::hex;
+        use k256::{PublicKey, Secp256k1};
+
+        // Same signature as in `recover_verifying_key`, by the key d = 1
+        let public_key = PublicKey::from_bytes(
+            &hex!("0279BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798")[..],
+        )
+        .unwrap();
+
+        let signature = super::Signature::<Secp256k1>::try_from(
+            &hex!(
+                "24653EAC434488002CC06BBFB7F10FE18991E35F9FE4302DBEA6D2353DC0AB1C
+                 8D7C23E003C717DF75E8BA5362D889CA5A9370C82509FB774D25BA99D2B6DFFA
+                 01"
+            )[..],
+        )
+        .unwrap();
+
+        assert_eq!(
+            signature.verify_recover(b"example message").unwrap(),
+            public_key
+        );
+
+        // A different message recovers a different key
+        assert_ne!(
+            signature.verify_recover(b"other message").unwrap(),
+            public_key
+        );
+
+        // Signature with a zero `s`
+        let mut bytes = [0u8; 65];
+        bytes[..32].copy_from_slice(&signature.as_ref()[..32]);
+        bytes[64] = 1;
+        let invalid = super::Signature::<Secp256k1>::try_from(&bytes[..]).unwrap();
+        assert!(invalid.verify_recover(b"example message").is_err());
+    }
+
+    #[cfg(feature = "hazmat")]
+    #[test]
+    fn recover_verifying_key_invalid_point() {
+        use hex_literal::hex;
+        use p256::NistP256;
+
+        let prehash = hex!("AF2BDBE1AA9B6EC1E2ADE1D694F41FC71A831D0268E9891562113D8A62ADD1BF");
+
+        // The x-coordinate `r = 1` doesn't correspond to a point on P-256
+        let mut r = [0u8; 32];
+        r[31] = 1;
+        let signature = crate::Signature::<NistP256>::from_scalars(&r.into(), &[0x42; 32].into());
+
+        for id in &[0, 1] {
+            let recoverable_signature =
+                super::Signature::new(&signature, RecoveryId::new(*id).unwrap());
+
+            assert!(recoverable_signature
+                .recover_verifying_key_from_prehash(&prehash.into())
+                .is_err());
+        }
+
+        // `r` from RFC 6979 A.2.5: `r + n` overflows the field, so an ID with
+        // the x-reduced bit set can't correspond to a valid point
+        let signature = crate::Signature::<NistP256>::from_scalars(
+            &hex!("EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716").into(),
+            &hex!("F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8").into(),
+        );
+
+        let recoverable_signature = super::Signature::new(&signature, RecoveryId::new(2).unwrap());
+
+        assert!(recoverable_signature
+            .recover_verifying_key_from_prehash(&prehash.into())
+            .is_err());
+    }
+}
@@ -0,0 +1,102 @@
+//! HMAC-DRBG as used by RFC 6979 deterministic nonce generation
+//!
+//! <https://tools.ietf.org/html/rfc6979#section-3.2>
+
+use elliptic_curve::{
+    generic_array::{ArrayLength, GenericArray},
+    zeroize::Zeroize,
+};
+use hmac::{
+    digest::{BlockInput, FixedOutput, Reset, Update},
+    Hmac, Mac, NewMac,
+};
+
+/// Internal `K` and `V` state of the HMAC-DRBG described in RFC 6979 §3.2
+pub(crate) struct HmacDrbg<D>
+where
+    D: Update + BlockInput + FixedOutput + Reset + Default + Clone,
+    D::BlockSize: ArrayLength<u8>,
+{
+    /// HMAC key `K` (see RFC 6979 §3.2.c)
+    k: GenericArray<u8, D::OutputSize>,
+
+    /// Chaining value `V` (see RFC 6979 §3.2.b)
+    v: GenericArray<u8, D::OutputSize>,
+}
+
+impl<D> HmacDrbg<D>
+where
+    D: Update + BlockInput + FixedOutput + Reset + Default + Clone,
+    D::BlockSize: ArrayLength<u8>,
+{
+    /// Initialize the DRBG (RFC 6979 §3.2.b-g) from `int2octets(x)`,
+    /// `bits2octets(h1)`, and optional additional data (RFC 6979 §3.6),
+    /// which is the concatenation of the given slices
+    pub fn new(secret: &[u8], prehash: &[u8], additional_data: &[&[u8]]) -> Self {
+        let mut drbg = Self {
+            k: GenericArray::default(),
+            v: GenericArray::default(),
+        };
+
+        for byte in drbg.v.iter_mut() {
+            *byte = 0x01;
+        }
+
+        for &separator in &[0x00, 0x01] {
+            let mut mac = drbg.hmac();
+            mac.update(&drbg.v);
+            mac.update(&[separator]);
+            mac.update(secret);
+            mac.update(prehash);
+
+            for data in additional_data {
+                mac.update(data);
+            }
+
+            drbg.k = mac.finalize().into_bytes();
+            drbg.update_v();
+        }
+
+        drbg
+    }
+
+    /// Fill `out` with the next candidate `T` value (RFC 6979 §3.2.h.1-2)
+    pub fn generate_into(&mut self, out: &mut [u8]) {
+        for chunk in out.chunks_mut(self.v.len()) {
+            self.update_v();
+            chunk.copy_from_slice(&self.v[..chunk.len()]);
+        }
+    }
+
+    /// Update the state after rejecting a candidate (RFC 6979 §3.2.h.3)
+    pub fn reseed(&mut self) {
+        let mut mac = self.hmac();
+        mac.update(&self.v);
+        mac.update(&[0x00]);
+        self.k = mac.finalize().into_bytes();
+        self.update_v();
+    }
+
+    /// Compute `V = HMAC_K(V)`
+    fn update_v(&mut self) {
+        let mut mac = self.hmac();
+        mac.update(&self.v);
+        self.v = mac.finalize().into_bytes();
+    }
+
+    /// Instantiate HMAC keyed with the current value of `K`
+    fn hmac(&self) -> Hmac<D> {
+        Hmac::new_varkey(&self.k).expect("HMAC accepts keys of any length")
+    }
+}
+
+impl<D> Drop for HmacDrbg<D>
+where
+    D: Update + BlockInput + FixedOutput + Reset + Default + Clone,
+    D::BlockSize: ArrayLength<u8>,
+{
+    fn drop(&mut self) {
+        self.k.as_mut_slice().zeroize();
+        self.v.as_mut_slice().zeroize();
+    }
+}
@@ -2,31 +2,134 @@
 //!
 //! Requires an [`elliptic_curve::Arithmetic`] impl on the curve, and a
 //! [`SignPrimitive`] impl on its associated `Scalar` type.
+//!
+//! [`Signer::sign_with_options`], the [`DigestSigner`] (and hence
+//! [`signature::Signer`]) and [`PrehashSigner`] impls, and [`SigningStream`]
+//! additionally require a [`hazmat::CurveArithmetic`] impl on the curve.
+//!
+//! [`hazmat::CurveArithmetic`]: crate::hazmat::CurveArithmetic
 
-// TODO(tarcieri): RFC 6979; support for hardware crypto accelerators
+// TODO(tarcieri): support for hardware crypto accelerators
 
 use crate::{
-    hazmat::{DigestPrimitive, SignPrimitive},
-    Error, Signature, SignatureSize,
+    hazmat::{
+        bits2int, rfc6979_generate_k, rfc6979_generate_k_with_data, sign_prehashed_blinded,
+        CurveArithmetic, DigestPrimitive, SignPrimitive,
+    },
+    Error, ErrorKind, NormalizeLow, Signature, SignatureSize,
 };
 use elliptic_curve::{
     generic_array::ArrayLength,
     ops::Invert,
     weierstrass::Curve,
     zeroize::{Zeroize, Zeroizing},
-    Arithmetic, FromBytes, SecretKey,
+    Arithmetic, ElementBytes, FromBytes, SecretKey,
 };
+use hmac::digest::{BlockInput, FixedOutput, Reset, Update};
+use signature::{digest::Digest, DigestSigner};
 
 #[cfg(feature = "rand")]
 use {
-    elliptic_curve::Generate,
+    elliptic_curve::{subtle::ConstantTimeEq, Generate},
     signature::{
-        digest::Digest,
         rand_core::{CryptoRng, RngCore},
         RandomizedDigestSigner, RandomizedSigner,
     },
 };
 
+/// Sign a message which has already been hashed (i.e. a "prehash").
+///
+/// This is useful for protocols which compute the message digest separately
+/// from the signature, such as TLS or X.509.
+pub trait PrehashSigner<S> {
+    /// Sign the given prehashed message digest, which may be of any length.
+    ///
+    /// Digests longer than the curve's order are truncated to their leftmost
+    /// [`CurveOrder::order_bits`] bits as described in FIPS 186-4.
+    ///
+    /// [`CurveOrder::order_bits`]: crate::CurveOrder::order_bits
+    fn sign_prehash(&self, prehash: &[u8]) -> Result<S, Error>;
+}
+
+/// Options for configuring how signatures are computed by
+/// [`Signer::sign_with_options`] and [`Signer::sign_with_options_and_rng`].
+///
+/// The defaults match the [`signature::Signer`] impls in this crate, i.e. a
+/// deterministic RFC 6979 ephemeral scalar with no additional data, no
+/// "low S" normalization, and no scalar blinding.
+#[derive(Copy, Clone, Debug)]
+pub struct SigningOptions<'a> {
+    deterministic: bool,
+    normalize_s: bool,
+    blinding: bool,
+    extra_entropy: &'a [u8],
+}
+
+impl<'a> SigningOptions<'a> {
+    /// Create the default signing options
+    pub fn new() -> Self {
+        Self {
+            deterministic: true,
+            normalize_s: false,
+            blinding: false,
+            extra_entropy: &[],
+        }
+    }
+
+    /// Generate the ephemeral scalar deterministically as described in
+    /// RFC 6979 (enabled by default).
+    ///
+    /// When disabled, the ephemeral scalar is "hedged" with a field element's
+    /// worth of randomness (see [`hazmat::sign_hedged`][1]), which requires
+    /// signing with [`Signer::sign_with_options_and_rng`].
+    ///
+    /// [1]: crate::hazmat::sign_hedged
+    pub fn deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+
+    /// Normalize signatures into "low S" form as described in
+    /// [BIP 0062: Dealing with Malleability][1] (disabled by default)
+    ///
+    /// [1]: https://github.com/bitcoin/bips/blob/master/bip-0062.mediawiki
+    pub fn normalize_s(mut self, normalize_s: bool) -> Self {
+        self.normalize_s = normalize_s;
+        self
+    }
+
+    /// Mask the secret and ephemeral scalars with a random blinding scalar
+    /// while computing the signature (disabled by default), as described in
+    /// [`hazmat::sign_prehashed_blinded`][1].
+    ///
+    /// This hardens signing against power and timing side channels on
+    /// hostile hardware, and requires signing with
+    /// [`Signer::sign_with_options_and_rng`]. The resulting signatures are
+    /// identical to unblinded ones.
+    ///
+    /// [1]: crate::hazmat::sign_prehashed_blinded
+    pub fn blinding(mut self, blinding: bool) -> Self {
+        self.blinding = blinding;
+        self
+    }
+
+    /// Additional data `k'` to include when generating the ephemeral scalar,
+    /// as described in RFC 6979 §3.6 (empty by default).
+    ///
+    /// When signing non-deterministically, this is appended to the random
+    /// bytes drawn from the RNG.
+    pub fn extra_entropy(mut self, extra_entropy: &'a [u8]) -> Self {
+        self.extra_entropy = extra_entropy;
+        self
+    }
+}
+
+impl Default for SigningOptions<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// ECDSA signer
 pub struct Signer<C>
 where
@@ -52,11 +155,219 @@ where
                 secret_scalar: scalar.unwrap(),
             })
         } else {
-            Err(Error::new())
+            Err(ErrorKind::ScalarOutOfRange.into())
         }
     }
 }
 
+impl<C> Signer<C>
+where
+    C: CurveArithmetic + DigestPrimitive,
+    C::Digest: BlockInput + FixedOutput + Reset + Update + Clone + Default,
+    C::Scalar: Invert<Output = C::Scalar> + NormalizeLow + SignPrimitive<C> + Zeroize,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Sign the given message using the curve's preferred digest, configured
+    /// by the given [`SigningOptions`].
+    ///
+    /// Returns an error if non-deterministic or blinded signing was
+    /// requested, as those require an RNG: use
+    /// [`Signer::sign_with_options_and_rng`] instead.
+    pub fn sign_with_options(
+        &self,
+        msg: &[u8],
+        options: &SigningOptions<'_>,
+    ) -> Result<Signature<C>, Error> {
+        if !options.deterministic || options.blinding {
+            return Err(ErrorKind::RngRequired.into());
+        }
+
+        self.sign_with_entropy(msg, &[], None, options)
+    }
+
+    /// Sign the given message using the curve's preferred digest, configured
+    /// by the given [`SigningOptions`], drawing randomness from `rng` if
+    /// non-deterministic or blinded signing was requested.
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    pub fn sign_with_options_and_rng(
+        &self,
+        mut rng: impl CryptoRng + RngCore,
+        msg: &[u8],
+        options: &SigningOptions<'_>,
+    ) -> Result<Signature<C>, Error> {
+        let blinding = if options.blinding {
+            Some(random_nonzero_scalar::<C, _>(&mut rng))
+        } else {
+            None
+        };
+
+        let blinding = blinding.as_deref();
+
+        if options.deterministic {
+            return self.sign_with_entropy(msg, &[], blinding, options);
+        }
+
+        let mut entropy = ElementBytes::<C>::default();
+        rng.fill_bytes(&mut entropy);
+
+        let result = self.sign_with_entropy(msg, &entropy, blinding, options);
+        entropy.as_mut_slice().zeroize();
+        result
+    }
+
+    /// Sign the given message, using `entropy` followed by the options' extra
+    /// entropy as the RFC 6979 additional data, and masking the secret
+    /// scalar with the `blinding` scalar if given
+    fn sign_with_entropy(
+        &self,
+        msg: &[u8],
+        entropy: &[u8],
+        blinding: Option<&C::Scalar>,
+        options: &SigningOptions<'_>,
+    ) -> Result<Signature<C>, Error> {
+        let prehash = bits2int::<C>(
+            &Digest::chain(C::Digest::new(), msg).finalize(),
+            C::order_bits(),
+        );
+        let ephemeral_scalar = rfc6979_generate_k_with_data::<C, C::Digest>(
+            &self.secret_scalar,
+            &prehash,
+            &[entropy, options.extra_entropy],
+        );
+
+        let mut signature = match blinding {
+            Some(blinding) => sign_prehashed_blinded::<C>(
+                &self.secret_scalar,
+                &ephemeral_scalar,
+                &prehash,
+                blinding,
+            )?,
+            None => self
+                .secret_scalar
+                .try_sign_prehashed(&*ephemeral_scalar, &prehash)?,
+        };
+
+        if options.normalize_s {
+            signature.normalize_s_if_high()?;
+        }
+
+        Ok(signature)
+    }
+}
+
+/// Generate a uniformly random non-zero scalar by rejection sampling
+#[cfg(feature = "rand")]
+pub(crate) fn random_nonzero_scalar<C, R>(mut rng: R) -> Zeroizing<C::Scalar>
+where
+    C: Curve + Arithmetic,
+    R: CryptoRng + RngCore,
+    C::Scalar: Zeroize,
+{
+    let mut bytes = ElementBytes::<C>::default();
+
+    loop {
+        rng.fill_bytes(&mut bytes);
+        let scalar = C::Scalar::from_bytes(&bytes);
+
+        if scalar.is_some().into() {
+            let scalar = Zeroizing::new(scalar.unwrap());
+
+            if (!scalar.ct_eq(&C::Scalar::default())).into() {
+                bytes.as_mut_slice().zeroize();
+                return scalar;
+            }
+        }
+    }
+}
+
+impl<C, D> DigestSigner<D, Signature<C>> for Signer<C>
+where
+    C: CurveArithmetic,
+    D: BlockInput + FixedOutput + Reset + Update + Clone + Default,
+    C::Scalar: Invert<Output = C::Scalar> + SignPrimitive<C> + Zeroize,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Sign the finalized output of the given digest, using a deterministic
+    /// ephemeral scalar generated as described in RFC 6979 with HMAC-`D`.
+    ///
+    /// Digests whose output is longer than the curve's order are truncated to
+    /// their leftmost [`CurveOrder::order_bits`] bits as described in
+    /// FIPS 186-4.
+    ///
+    /// [`CurveOrder::order_bits`]: crate::CurveOrder::order_bits
+    fn try_sign_digest(&self, digest: D) -> Result<Signature<C>, Error> {
+        let prehash = bits2int::<C>(&digest.finalize(), C::order_bits());
+        let ephemeral_scalar = rfc6979_generate_k::<C, D>(&self.secret_scalar, &prehash, &[]);
+
+        self.secret_scalar
+            .try_sign_prehashed(&*ephemeral_scalar, &prehash)
+    }
+}
+
+impl<C> PrehashSigner<Signature<C>> for Signer<C>
+where
+    C: CurveArithmetic + DigestPrimitive,
+    C::Digest: BlockInput + FixedOutput + Reset + Update + Clone + Default,
+    C::Scalar: Invert<Output = C::Scalar> + SignPrimitive<C> + Zeroize,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Sign the given prehash, using a deterministic ephemeral scalar
+    /// generated as described in RFC 6979 with HMAC instantiated using the
+    /// curve's preferred [`DigestPrimitive::Digest`].
+    fn sign_prehash(&self, prehash: &[u8]) -> Result<Signature<C>, Error> {
+        let prehash = bits2int::<C>(prehash, C::order_bits());
+        let ephemeral_scalar =
+            rfc6979_generate_k::<C, C::Digest>(&self.secret_scalar, &prehash, &[]);
+
+        self.secret_scalar
+            .try_sign_prehashed(&*ephemeral_scalar, &prehash)
+    }
+}
+
+/// Streaming ECDSA signer, which hashes a message fed to it in chunks using
+/// the digest `D` and then signs it.
+///
+/// This avoids buffering large messages in memory, and produces the same
+/// signature as the [`DigestSigner`] impl of the [`Signer`] it borrows, with
+/// a digest of the whole message (i.e. using a deterministic RFC 6979
+/// ephemeral scalar generated with HMAC-`D`).
+pub struct SigningStream<'a, C, D>
+where
+    C: Curve + Arithmetic,
+    C::Scalar: Invert<Output = C::Scalar> + SignPrimitive<C> + Zeroize,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    signer: &'a Signer<C>,
+    digest: D,
+}
+
+impl<'a, C, D> SigningStream<'a, C, D>
+where
+    C: CurveArithmetic,
+    D: BlockInput + FixedOutput + Reset + Update + Clone + Default,
+    C::Scalar: Invert<Output = C::Scalar> + SignPrimitive<C> + Zeroize,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Create a new streaming signer using the given signer's secret key
+    pub fn new(signer: &'a Signer<C>) -> Self {
+        Self {
+            signer,
+            digest: D::default(),
+        }
+    }
+
+    /// Feed the next chunk of the message into the digest
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.digest.update(chunk);
+    }
+
+    /// Sign the message fed in so far
+    pub fn finalize_sign(self) -> Result<Signature<C>, Error> {
+        self.signer.try_sign_digest(self.digest)
+    }
+}
+
 #[cfg(feature = "rand")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
 impl<C, D> RandomizedDigestSigner<D, Signature<C>> for Signer<C>
@@ -119,3 +430,234 @@ where
         self.zeroize();
     }
 }
+
+/// Signer wrapper which normalizes every signature produced by the inner
+/// signer `S` into "low S" form as described in
+/// [BIP 0062: Dealing with Malleability][1].
+///
+/// This is useful for protocols such as Bitcoin and Ethereum which reject
+/// "high S" signatures, and avoids having to remember to call
+/// [`Signature::normalize_s`] after every signing operation.
+///
+/// [1]: https://github.com/bitcoin/bips/blob/master/bip-0062.mediawiki
+#[derive(Clone, Debug)]
+pub struct NormalizedSigner<S> {
+    inner: S,
+}
+
+impl<S> NormalizedSigner<S> {
+    /// Wrap the given signer
+    pub fn new(inner: S) -> Self {
+        Self { inner }
+    }
+
+    /// Borrow the inner signer
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Unwrap the inner signer
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S> From<S> for NormalizedSigner<S> {
+    fn from(inner: S) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<C, S> signature::Signer<Signature<C>> for NormalizedSigner<S>
+where
+    C: Curve + Arithmetic,
+    C::Scalar: NormalizeLow,
+    S: signature::Signer<Signature<C>>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn try_sign(&self, msg: &[u8]) -> Result<Signature<C>, Error> {
+        let mut signature = self.inner.try_sign(msg)?;
+        signature.normalize_s_if_high()?;
+        Ok(signature)
+    }
+}
+
+impl<C, D, S> DigestSigner<D, Signature<C>> for NormalizedSigner<S>
+where
+    C: Curve + Arithmetic,
+    C::Scalar: NormalizeLow,
+    D: Digest,
+    S: DigestSigner<D, Signature<C>>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn try_sign_digest(&self, digest: D) -> Result<Signature<C>, Error> {
+        let mut signature = self.inner.try_sign_digest(digest)?;
+        signature.normalize_s_if_high()?;
+        Ok(signature)
+    }
+}
+
+impl<C, S> PrehashSigner<Signature<C>> for NormalizedSigner<S>
+where
+    C: Curve + Arithmetic,
+    C::Scalar: NormalizeLow,
+    S: PrehashSigner<Signature<C>>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn sign_prehash(&self, prehash: &[u8]) -> Result<Signature<C>, Error> {
+        let mut signature = self.inner.sign_prehash(prehash)?;
+        signature.normalize_s_if_high()?;
+        Ok(signature)
+    }
+}
+
+#[cfg(feature = "rand")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+impl<C, S> RandomizedSigner<Signature<C>> for NormalizedSigner<S>
+where
+    C: Curve + Arithmetic,
+    C::Scalar: NormalizeLow,
+    S: RandomizedSigner<Signature<C>>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn try_sign_with_rng(
+        &self,
+        rng: impl CryptoRng + RngCore,
+        msg: &[u8],
+    ) -> Result<Signature<C>, Error> {
+        let mut signature = self.inner.try_sign_with_rng(rng, msg)?;
+        signature.normalize_s_if_high()?;
+        Ok(signature)
+    }
+}
+
+#[cfg(all(test, feature = "verifier"))]
+mod tests {
+    use super::{random_nonzero_scalar, PrehashSigner, Signer};
+    use crate::{
+        test_curves::{
+            small::{self, SmallCurve},
+            TestRng,
+        },
+        verifier::{PrehashVerifier, Verifier},
+        SecretKey,
+    };
+    use elliptic_curve::weierstrass::PublicKey;
+    use hex_literal::hex;
+    use p256::NistP256;
+    use sha2::{Digest, Sha256, Sha512};
+    use signature::{DigestSigner, DigestVerifier};
+
+    /// Secret key from RFC 6979 A.2.5 (P-256)
+    const SECRET_KEY: [u8; 32] =
+        hex!("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+
+    /// Public key from RFC 6979 A.2.5 (P-256)
+    const PUBLIC_KEY: [u8; 65] = hex!(
+        "04 60FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6
+            7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299"
+    );
+
+    /// Number of scalars sampled in `random_nonzero_scalar_is_uniform`
+    const SAMPLES: u32 = 100_000;
+
+    #[test]
+    fn random_nonzero_scalar_is_uniform() {
+        let mut rng = TestRng(7);
+        let mut counts = [0u32; small::ORDER as usize];
+
+        for _ in 0..SAMPLES {
+            counts[usize::from(random_nonzero_scalar::<SmallCurve, _>(&mut rng).0)] += 1;
+        }
+
+        // Zero is never generated, and every other scalar is roughly equally
+        // likely, i.e. values above `n` were resampled rather than reduced
+        assert_eq!(counts[0], 0);
+
+        let expected = SAMPLES / (u32::from(small::ORDER) - 1);
+
+        for (scalar, &count) in counts.iter().enumerate().skip(1) {
+            assert!(
+                count > expected * 3 / 4 && count < expected * 5 / 4,
+                "scalar {} generated {} times",
+                scalar,
+                count
+            );
+        }
+    }
+
+    fn signer() -> Signer<NistP256> {
+        Signer::new(&SecretKey::new(SECRET_KEY.into())).unwrap()
+    }
+
+    fn verifier() -> Verifier<NistP256> {
+        Verifier::new(&PublicKey::from_bytes(&PUBLIC_KEY[..]).unwrap()).unwrap()
+    }
+
+    /// RFC 6979 A.2.5: P-256, SHA-256, message = "sample"
+    #[test]
+    fn sign_digest_sha256() {
+        let signature = signer().sign_digest(Sha256::new().chain(b"sample"));
+
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716
+                 F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8"
+            )[..]
+        );
+
+        assert!(verifier()
+            .verify_digest(Sha256::new().chain(b"sample"), &signature)
+            .is_ok());
+    }
+
+    /// RFC 6979 A.2.5: P-256, SHA-512, message = "sample", exercising
+    /// truncation of digests larger than the field size
+    #[test]
+    fn sign_digest_sha512() {
+        let signature = signer().sign_digest(Sha512::new().chain(b"sample"));
+
+        assert_eq!(
+            signature.as_ref(),
+            &hex!(
+                "8496A60B5E9B47C825488827E0495B0E3FA109EC4568FD3F8D1097678EB97F00
+                 2362AB1ADBE2B8ADF9CB9EDAB740EA6049C028114F2460F96554F61FAE3302FE"
+            )[..]
+        );
+    }
+
+    #[test]
+    fn sign_prehash_equal_to_field_size() {
+        let prehash = Sha256::digest(b"sample");
+        let signature = signer().sign_prehash(&prehash).unwrap();
+        assert_eq!(
+            signature,
+            signer().sign_digest(Sha256::new().chain(b"sample"))
+        );
+        assert!(verifier().verify_prehash(&prehash, &signature).is_ok());
+        assert!(verifier()
+            .verify_prehash(&Sha256::digest(b"test"), &signature)
+            .is_err());
+    }
+
+    #[test]
+    fn sign_prehash_longer_than_field_size() {
+        let prehash = Sha512::digest(b"sample");
+        let signature = signer().sign_prehash(&prehash).unwrap();
+        assert_eq!(signature, signer().sign_prehash(&prehash[..32]).unwrap());
+        assert!(verifier().verify_prehash(&prehash, &signature).is_ok());
+    }
+
+    #[test]
+    fn sign_prehash_shorter_than_field_size() {
+        let prehash = hex!("000102030405060708090A0B0C0D0E0F");
+        let mut padded = [0u8; 32];
+        padded[16..].copy_from_slice(&prehash);
+
+        let signature = signer().sign_prehash(&prehash).unwrap();
+        assert_eq!(signature, signer().sign_prehash(&padded).unwrap());
+        assert!(verifier().verify_prehash(&prehash, &signature).is_ok());
+        assert!(verifier().verify_prehash(&padded, &signature).is_ok());
+    }
+}
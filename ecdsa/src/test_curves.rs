@@ -0,0 +1,744 @@
+//! Glue for using the `p256` and `k256` crates' arithmetic in this crate's
+//! tests (these impls can't live in the crates themselves as they depend on
+//! a different version of `ecdsa`).
+
+use crate::{CurveOrder, NormalizeLow};
+
+#[cfg(feature = "rand")]
+use elliptic_curve::rand_core::{CryptoRng, Error, RngCore};
+
+#[cfg(feature = "hazmat")]
+use {
+    crate::hazmat::CurveArithmetic,
+    elliptic_curve::{ops::Invert, subtle::CtOption, ElementBytes},
+};
+
+#[cfg(feature = "hazmat")]
+macro_rules! impl_curve_arithmetic {
+    ($curve:ident, $krate:ident) => {
+        impl CurveArithmetic for $krate::$curve {
+            fn add_scalars(a: &$krate::Scalar, b: &$krate::Scalar) -> $krate::Scalar {
+                *a + b
+            }
+
+            fn mul_scalars(a: &$krate::Scalar, b: &$krate::Scalar) -> $krate::Scalar {
+                *a * b
+            }
+
+            fn negate_scalar(a: &$krate::Scalar) -> $krate::Scalar {
+                -*a
+            }
+
+            fn invert_scalar(a: &$krate::Scalar) -> CtOption<$krate::Scalar> {
+                a.invert()
+            }
+
+            fn reduce_scalar(bytes: &ElementBytes<Self>) -> $krate::Scalar {
+                $krate::Scalar::from_bytes_reduced(bytes)
+            }
+
+            fn add_points(
+                a: &$krate::AffinePoint,
+                b: &$krate::AffinePoint,
+            ) -> CtOption<$krate::AffinePoint> {
+                ($krate::ProjectivePoint::from(*a) + &$krate::ProjectivePoint::from(*b)).to_affine()
+            }
+
+            fn mul_point(
+                point: &$krate::AffinePoint,
+                k: &$krate::Scalar,
+            ) -> CtOption<$krate::AffinePoint> {
+                ($krate::ProjectivePoint::from(*point) * k).to_affine()
+            }
+
+            fn x_coordinate(point: &$krate::AffinePoint) -> ElementBytes<Self> {
+                let point = $krate::UncompressedPoint::from(*point);
+                ElementBytes::<Self>::clone_from_slice(&point.as_bytes()[1..33])
+            }
+
+            type ProjectivePoint = $krate::ProjectivePoint;
+
+            fn identity() -> $krate::ProjectivePoint {
+                $krate::ProjectivePoint::identity()
+            }
+
+            fn to_projective(point: &$krate::AffinePoint) -> $krate::ProjectivePoint {
+                $krate::ProjectivePoint::from(*point)
+            }
+
+            fn to_affine(point: &$krate::ProjectivePoint) -> CtOption<$krate::AffinePoint> {
+                point.to_affine()
+            }
+
+            fn add_projective(
+                a: &$krate::ProjectivePoint,
+                b: &$krate::ProjectivePoint,
+            ) -> $krate::ProjectivePoint {
+                *a + b
+            }
+
+            fn double_projective(a: &$krate::ProjectivePoint) -> $krate::ProjectivePoint {
+                a.double()
+            }
+        }
+
+        impl crate::hazmat::SignPrimitive<$krate::$curve> for $krate::Scalar {
+            fn try_sign_prehashed<K>(
+                &self,
+                ephemeral_scalar: &K,
+                hashed_msg: &ElementBytes<$krate::$curve>,
+            ) -> Result<crate::Signature<$krate::$curve>, crate::Error>
+            where
+                K: core::borrow::Borrow<$krate::Scalar> + Invert<Output = $krate::Scalar>,
+            {
+                crate::hazmat::sign_prehashed::<$krate::$curve>(
+                    self,
+                    ephemeral_scalar.borrow(),
+                    hashed_msg,
+                )
+            }
+        }
+
+        impl crate::hazmat::VerifyPrimitive<$krate::$curve> for $krate::AffinePoint {
+            fn verify_prehashed(
+                &self,
+                hashed_msg: &ElementBytes<$krate::$curve>,
+                signature: &crate::Signature<$krate::$curve>,
+            ) -> Result<(), crate::Error> {
+                crate::hazmat::verify_prehashed::<$krate::$curve>(self, hashed_msg, signature)
+            }
+        }
+
+        #[cfg(feature = "digest")]
+        impl crate::hazmat::DigestPrimitive for $krate::$curve {
+            type Digest = sha2::Sha256;
+        }
+    };
+}
+
+#[cfg(feature = "hazmat")]
+impl_curve_arithmetic!(NistP256, p256);
+
+#[cfg(feature = "hazmat")]
+impl_curve_arithmetic!(Secp256k1, k256);
+
+impl CurveOrder for p256::NistP256 {
+    fn order() -> elliptic_curve::ElementBytes<Self> {
+        hex_literal::hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551").into()
+    }
+}
+
+impl CurveOrder for k256::Secp256k1 {
+    fn order() -> elliptic_curve::ElementBytes<Self> {
+        hex_literal::hex!("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141").into()
+    }
+}
+
+impl NormalizeLow for k256::Scalar {
+    fn normalize_low(&self) -> (Self, bool) {
+        if self.is_high().into() {
+            (-self, true)
+        } else {
+            (*self, false)
+        }
+    }
+}
+
+/// Deterministic RNG for tests (NOT cryptographically secure!)
+#[cfg(feature = "rand")]
+pub struct TestRng(pub u64);
+
+#[cfg(feature = "rand")]
+impl RngCore for TestRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        // xorshift64
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for byte in dest {
+            *byte = self.next_u64() as u8;
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand")]
+impl CryptoRng for TestRng {}
+
+/// Mock NIST P-384 curve with a 48-byte field, used to check this crate's
+/// generic code doesn't implicitly assume 256-bit fields.
+///
+/// Scalars are range checked against the real P-384 group order and support
+/// "low S" normalization, but no point arithmetic is implemented.
+pub mod p384 {
+    use elliptic_curve::consts::U48;
+    use hex_literal::hex;
+
+    /// Order of the P-384 group
+    const ORDER: [u8; 48] = hex!(
+        "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF
+         C7634D81F4372DDF581A0DB248B0A77AECEC196ACCC52973"
+    );
+
+    impl_mock_curve! {
+        /// Mock NIST P-384 curve type
+        pub struct NistP384 {
+            size: U48,
+            order: ORDER,
+            scalar: Scalar,
+            point: AffinePoint,
+        }
+    }
+}
+
+/// Mock curve with a one-byte field and the (prime) group order `n = 251`,
+/// small enough to exhaustively check the distribution of scalar reductions.
+///
+/// Scalar arithmetic is implemented, but no point arithmetic: operations on
+/// points never succeed.
+#[cfg(feature = "hazmat")]
+pub mod small {
+    use crate::hazmat::CurveArithmetic;
+    use core::ops::Mul;
+    use elliptic_curve::{
+        consts::U1,
+        point::Generator,
+        scalar::NonZeroScalar,
+        subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
+        Arithmetic, ElementBytes, FromBytes,
+    };
+
+    /// Order of the mock group
+    pub const ORDER: u8 = 251;
+
+    /// Mock curve type with a small group order
+    #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+    pub struct SmallCurve;
+
+    impl elliptic_curve::Curve for SmallCurve {
+        type ElementSize = U1;
+    }
+
+    impl elliptic_curve::weierstrass::Curve for SmallCurve {
+        const COMPRESS_POINTS: bool = false;
+    }
+
+    impl Arithmetic for SmallCurve {
+        type Scalar = Scalar;
+        type AffinePoint = AffinePoint;
+    }
+
+    impl crate::CurveOrder for SmallCurve {
+        fn order() -> ElementBytes<SmallCurve> {
+            [ORDER].into()
+        }
+    }
+
+    /// Integer modulo the mock group order
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    pub struct Scalar(pub u8);
+
+    impl Scalar {
+        /// Reduce the given integer modulo `n`
+        fn reduce(n: u16) -> Self {
+            Scalar((n % u16::from(ORDER)) as u8)
+        }
+    }
+
+    #[cfg(feature = "zeroize")]
+    impl elliptic_curve::zeroize::Zeroize for Scalar {
+        fn zeroize(&mut self) {
+            self.0.zeroize();
+        }
+    }
+
+    impl ConditionallySelectable for Scalar {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+            Scalar(u8::conditional_select(&a.0, &b.0, choice))
+        }
+    }
+
+    impl ConstantTimeEq for Scalar {
+        fn ct_eq(&self, other: &Self) -> Choice {
+            self.0.ct_eq(&other.0)
+        }
+    }
+
+    impl FromBytes for Scalar {
+        type Size = U1;
+
+        fn from_bytes(bytes: &ElementBytes<SmallCurve>) -> CtOption<Self> {
+            CtOption::new(Scalar(bytes[0]), Choice::from((bytes[0] < ORDER) as u8))
+        }
+    }
+
+    impl From<Scalar> for ElementBytes<SmallCurve> {
+        fn from(scalar: Scalar) -> ElementBytes<SmallCurve> {
+            [scalar.0].into()
+        }
+    }
+
+    impl CurveArithmetic for SmallCurve {
+        fn add_scalars(a: &Scalar, b: &Scalar) -> Scalar {
+            Scalar::reduce(u16::from(a.0) + u16::from(b.0))
+        }
+
+        fn mul_scalars(a: &Scalar, b: &Scalar) -> Scalar {
+            Scalar::reduce(u16::from(a.0) * u16::from(b.0))
+        }
+
+        fn negate_scalar(a: &Scalar) -> Scalar {
+            Scalar::reduce(u16::from(ORDER) - u16::from(a.0))
+        }
+
+        fn invert_scalar(a: &Scalar) -> CtOption<Scalar> {
+            // Fermat's little theorem: a⁻¹ = a^(n - 2)
+            let mut result = Scalar(1);
+
+            for _ in 0..ORDER - 2 {
+                result = Self::mul_scalars(&result, a);
+            }
+
+            CtOption::new(result, !a.ct_eq(&Scalar(0)))
+        }
+
+        fn reduce_scalar(bytes: &ElementBytes<Self>) -> Scalar {
+            Scalar::reduce(u16::from(bytes[0]))
+        }
+
+        // Point arithmetic is unsupported, so every result is reported as the
+        // point at infinity, which generic code rejects with an error
+
+        fn add_points(_: &AffinePoint, _: &AffinePoint) -> CtOption<AffinePoint> {
+            CtOption::new(AffinePoint, Choice::from(0))
+        }
+
+        fn mul_point(_: &AffinePoint, _: &Scalar) -> CtOption<AffinePoint> {
+            CtOption::new(AffinePoint, Choice::from(0))
+        }
+
+        fn x_coordinate(_: &AffinePoint) -> ElementBytes<Self> {
+            ElementBytes::<Self>::default()
+        }
+
+        type ProjectivePoint = AffinePoint;
+
+        fn identity() -> AffinePoint {
+            AffinePoint
+        }
+
+        fn to_projective(_: &AffinePoint) -> AffinePoint {
+            AffinePoint
+        }
+
+        fn to_affine(_: &AffinePoint) -> CtOption<AffinePoint> {
+            CtOption::new(AffinePoint, Choice::from(0))
+        }
+
+        fn add_projective(_: &AffinePoint, _: &AffinePoint) -> AffinePoint {
+            AffinePoint
+        }
+    }
+
+    /// Placeholder point type (point arithmetic is unsupported)
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct AffinePoint;
+
+    impl ConditionallySelectable for AffinePoint {
+        fn conditional_select(_: &Self, _: &Self, _: Choice) -> Self {
+            AffinePoint
+        }
+    }
+
+    impl Generator for AffinePoint {
+        fn generator() -> Self {
+            AffinePoint
+        }
+    }
+
+    impl Mul<NonZeroScalar<SmallCurve>> for AffinePoint {
+        type Output = AffinePoint;
+
+        fn mul(self, _: NonZeroScalar<SmallCurve>) -> AffinePoint {
+            AffinePoint
+        }
+    }
+}
+
+/// Define a module containing a mock curve over the field of order 251, with
+/// point arithmetic (including decoding [`PublicKey`]s) implemented with
+/// plain integers.
+///
+/// [`PublicKey`]: elliptic_curve::weierstrass::PublicKey
+#[cfg(feature = "hazmat")]
+macro_rules! impl_toy_curve {
+    (
+        $(#[$attr:meta])*
+        pub mod $module:ident {
+            curve: $curve:ident,
+            a: $a:expr,
+            b: $b:expr,
+            order: $order:expr,
+            cofactor: $cofactor:expr,
+            generator: ($gx:expr, $gy:expr);
+
+            $($item:item)*
+        }
+    ) => {
+        $(#[$attr])*
+        pub mod $module {
+            use crate::hazmat::CurveArithmetic;
+            use core::ops::Mul;
+            use elliptic_curve::{
+                consts::U1,
+                point::Generator,
+                scalar::NonZeroScalar,
+                subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
+                weierstrass::{point::UncompressedPoint, public_key::FromPublicKey, PublicKey},
+                Arithmetic, ElementBytes, FromBytes,
+            };
+
+            /// Order of the base field
+            const FIELD_ORDER: u32 = 251;
+
+            /// Coefficient `a` of the curve equation
+            const A: u32 = $a;
+
+            /// Coefficient `b` of the curve equation
+            const B: u32 = $b;
+
+            /// Order of the subgroup generated by the base point
+            pub const ORDER: u8 = $order;
+
+            $($item)*
+
+            /// Mock curve type
+            #[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
+            pub struct $curve;
+
+            impl elliptic_curve::Curve for $curve {
+                type ElementSize = U1;
+            }
+
+            impl elliptic_curve::weierstrass::Curve for $curve {
+                const COMPRESS_POINTS: bool = false;
+            }
+
+            impl Arithmetic for $curve {
+                type Scalar = Scalar;
+                type AffinePoint = AffinePoint;
+            }
+
+            impl crate::CurveOrder for $curve {
+                fn order() -> ElementBytes<$curve> {
+                    [ORDER].into()
+                }
+            }
+
+            /// Integer modulo the order of the base point's subgroup
+            #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+            pub struct Scalar(pub u8);
+
+            impl Scalar {
+                /// Reduce the given integer modulo `n`
+                fn reduce(n: u16) -> Self {
+                    Scalar((n % u16::from(ORDER)) as u8)
+                }
+            }
+
+            #[cfg(feature = "zeroize")]
+            impl elliptic_curve::zeroize::Zeroize for Scalar {
+                fn zeroize(&mut self) {
+                    self.0.zeroize();
+                }
+            }
+
+            impl ConditionallySelectable for Scalar {
+                fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                    Scalar(u8::conditional_select(&a.0, &b.0, choice))
+                }
+            }
+
+            impl ConstantTimeEq for Scalar {
+                fn ct_eq(&self, other: &Self) -> Choice {
+                    self.0.ct_eq(&other.0)
+                }
+            }
+
+            impl FromBytes for Scalar {
+                type Size = U1;
+
+                fn from_bytes(bytes: &ElementBytes<$curve>) -> CtOption<Self> {
+                    CtOption::new(Scalar(bytes[0]), Choice::from((bytes[0] < ORDER) as u8))
+                }
+            }
+
+            impl From<Scalar> for ElementBytes<$curve> {
+                fn from(scalar: Scalar) -> ElementBytes<$curve> {
+                    [scalar.0].into()
+                }
+            }
+
+            impl CurveArithmetic for $curve {
+                const COFACTOR: u64 = $cofactor;
+
+                fn add_scalars(a: &Scalar, b: &Scalar) -> Scalar {
+                    Scalar::reduce(u16::from(a.0) + u16::from(b.0))
+                }
+
+                fn mul_scalars(a: &Scalar, b: &Scalar) -> Scalar {
+                    Scalar::reduce(u16::from(a.0) * u16::from(b.0))
+                }
+
+                fn negate_scalar(a: &Scalar) -> Scalar {
+                    Scalar::reduce(u16::from(ORDER) - u16::from(a.0))
+                }
+
+                fn invert_scalar(a: &Scalar) -> CtOption<Scalar> {
+                    // Fermat's little theorem: a⁻¹ = a^(n - 2)
+                    let mut result = Scalar(1);
+
+                    for _ in 0..ORDER - 2 {
+                        result = Self::mul_scalars(&result, a);
+                    }
+
+                    CtOption::new(result, !a.ct_eq(&Scalar(0)))
+                }
+
+                fn reduce_scalar(bytes: &ElementBytes<Self>) -> Scalar {
+                    Scalar::reduce(u16::from(bytes[0]))
+                }
+
+                fn add_points(a: &AffinePoint, b: &AffinePoint) -> CtOption<AffinePoint> {
+                    to_ct_option(add(Some(*a), Some(*b)))
+                }
+
+                fn mul_point(point: &AffinePoint, k: &Scalar) -> CtOption<AffinePoint> {
+                    to_ct_option(mul(*point, k.0))
+                }
+
+                fn x_coordinate(point: &AffinePoint) -> ElementBytes<Self> {
+                    [point.x].into()
+                }
+
+                // `None` represents the point at infinity
+                type ProjectivePoint = Option<AffinePoint>;
+
+                fn identity() -> Option<AffinePoint> {
+                    None
+                }
+
+                fn to_projective(point: &AffinePoint) -> Option<AffinePoint> {
+                    Some(*point)
+                }
+
+                fn to_affine(point: &Option<AffinePoint>) -> CtOption<AffinePoint> {
+                    to_ct_option(*point)
+                }
+
+                fn add_projective(
+                    a: &Option<AffinePoint>,
+                    b: &Option<AffinePoint>,
+                ) -> Option<AffinePoint> {
+                    add(*a, *b)
+                }
+            }
+
+            /// Affine point on the mock curve (the point at infinity can't be
+            /// represented)
+            #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+            pub struct AffinePoint {
+                /// Affine `x`-coordinate
+                pub x: u8,
+
+                /// Affine `y`-coordinate
+                pub y: u8,
+            }
+
+            impl Default for AffinePoint {
+                fn default() -> Self {
+                    Self::generator()
+                }
+            }
+
+            impl ConditionallySelectable for AffinePoint {
+                fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                    AffinePoint {
+                        x: u8::conditional_select(&a.x, &b.x, choice),
+                        y: u8::conditional_select(&a.y, &b.y, choice),
+                    }
+                }
+            }
+
+            impl Generator for AffinePoint {
+                fn generator() -> Self {
+                    AffinePoint { x: $gx, y: $gy }
+                }
+            }
+
+            impl Mul<NonZeroScalar<$curve>> for AffinePoint {
+                type Output = AffinePoint;
+
+                fn mul(self, scalar: NonZeroScalar<$curve>) -> AffinePoint {
+                    mul(self, scalar.as_ref().0).unwrap()
+                }
+            }
+
+            impl FromPublicKey<$curve> for AffinePoint {
+                fn from_public_key(public_key: &PublicKey<$curve>) -> CtOption<Self> {
+                    let bytes = public_key.as_bytes();
+                    let x = u32::from(bytes[1]);
+                    let y_squared = (pow(x, 3) + A * x + B) % FIELD_ORDER;
+
+                    let y = match public_key {
+                        PublicKey::Compressed(_) => {
+                            // The field order is 3 mod 4, so a square root of
+                            // `y²` is `(y²)^((p + 1) / 4)`. If the root with
+                            // the requested parity is `p`, `y` is zero and
+                            // the point is rejected below.
+                            let y = pow(y_squared, (FIELD_ORDER + 1) / 4);
+
+                            if y & 1 == u32::from(bytes[0] & 1) {
+                                y
+                            } else {
+                                FIELD_ORDER - y
+                            }
+                        }
+                        PublicKey::Uncompressed(_) => u32::from(bytes[2]),
+                    };
+
+                    let is_valid = x < FIELD_ORDER && y < FIELD_ORDER && y * y % FIELD_ORDER == y_squared;
+
+                    CtOption::new(
+                        AffinePoint {
+                            x: x as u8,
+                            y: y as u8,
+                        },
+                        Choice::from(is_valid as u8),
+                    )
+                }
+            }
+
+            impl From<AffinePoint> for UncompressedPoint<$curve> {
+                fn from(point: AffinePoint) -> UncompressedPoint<$curve> {
+                    UncompressedPoint::from_affine_coords(&[point.x].into(), &[point.y].into())
+                }
+            }
+
+            /// Convert a point to a `CtOption`, which is none for the point at
+            /// infinity
+            fn to_ct_option(point: Option<AffinePoint>) -> CtOption<AffinePoint> {
+                CtOption::new(
+                    point.unwrap_or_default(),
+                    Choice::from(point.is_some() as u8),
+                )
+            }
+
+            /// Compute `a^e` in the base field
+            fn pow(a: u32, e: u32) -> u32 {
+                (0..e).fold(1, |result, _| result * a % FIELD_ORDER)
+            }
+
+            /// Add two points, where `None` represents the point at infinity
+            fn add(a: Option<AffinePoint>, b: Option<AffinePoint>) -> Option<AffinePoint> {
+                let (a, b) = match (a, b) {
+                    (None, point) | (point, None) => return point,
+                    (Some(a), Some(b)) => (a, b),
+                };
+
+                let (x1, y1) = (u32::from(a.x), u32::from(a.y));
+                let (x2, y2) = (u32::from(b.x), u32::from(b.y));
+
+                if x1 == x2 && (y1 + y2) % FIELD_ORDER == 0 {
+                    return None;
+                }
+
+                // Slope of the line through the points, or the tangent if
+                // they're equal, computing inverses with Fermat's little
+                // theorem
+                let lambda = if x1 == x2 {
+                    (3 * x1 * x1 + A) % FIELD_ORDER * pow(2 * y1 % FIELD_ORDER, FIELD_ORDER - 2)
+                } else {
+                    (y2 + FIELD_ORDER - y1)
+                        * pow((x2 + FIELD_ORDER - x1) % FIELD_ORDER, FIELD_ORDER - 2)
+                } % FIELD_ORDER;
+
+                let x3 = (lambda * lambda + 2 * FIELD_ORDER - x1 - x2) % FIELD_ORDER;
+                let y3 = (lambda * ((x1 + FIELD_ORDER - x3) % FIELD_ORDER) + FIELD_ORDER - y1)
+                    % FIELD_ORDER;
+
+                Some(AffinePoint {
+                    x: x3 as u8,
+                    y: y3 as u8,
+                })
+            }
+
+            /// Compute `k×P` by double-and-add
+            fn mul(point: AffinePoint, k: u8) -> Option<AffinePoint> {
+                (0..8).rev().fold(None, |result, bit| {
+                    let doubled = add(result, result);
+
+                    if k >> bit & 1 == 1 {
+                        add(doubled, Some(point))
+                    } else {
+                        doubled
+                    }
+                })
+            }
+        }
+    };
+}
+
+#[cfg(feature = "hazmat")]
+impl_toy_curve! {
+    /// Mock curve `y² = x³ + 6x + 3` over the field of order 251, which has
+    /// 236 points: a subgroup of (prime) order `n = 59` generated by the base
+    /// point, with cofactor `h = 4`.
+    ///
+    /// Unlike [`small`], point arithmetic is implemented, so that public keys
+    /// outside the base point's subgroup can be tested.
+    pub mod cofactor {
+        curve: CofactorCurve,
+        a: 6,
+        b: 3,
+        order: 59,
+        cofactor: 4,
+        generator: (184, 17);
+
+        /// Point of order 2, which is outside the base point's subgroup
+        pub const SMALL_ORDER_POINT: AffinePoint = AffinePoint { x: 136, y: 0 };
+    }
+}
+
+#[cfg(feature = "hazmat")]
+impl_toy_curve! {
+    /// Mock curve `y² = x³ + 4x + 3` over the field of order 251, which has
+    /// (prime) order `n = 233`.
+    ///
+    /// As `n` is less than the field order, the x-coordinates `233..251` are
+    /// reduced modulo `n` when computing `r`, so this curve can be used to
+    /// test the "x-reduced" case of [`RecoveryId`]s, which is vanishingly
+    /// unlikely for real curves.
+    ///
+    /// [`RecoveryId`]: crate::recoverable::RecoveryId
+    pub mod reduced {
+        curve: ReducedCurve,
+        a: 4,
+        b: 3,
+        order: 233,
+        cofactor: 1,
+        generator: (0, 76);
+    }
+}
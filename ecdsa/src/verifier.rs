@@ -2,17 +2,22 @@
 //!
 //! Requires an [`elliptic_curve::Arithmetic`] impl on the curve, and a
 //! [`VerifyPrimitive`] impl on its associated `AffinePoint` type.
+//!
+//! [`add_public_keys`] and batch verification additionally require a
+//! [`hazmat::CurveArithmetic`] impl on the curve.
+//!
+//! [`hazmat::CurveArithmetic`]: crate::hazmat::CurveArithmetic
 
 use crate::{
-    hazmat::{DigestPrimitive, VerifyPrimitive},
-    Error, Signature, SignatureSize,
+    hazmat::{bits2int, check_subgroup, CurveArithmetic, DigestPrimitive, VerifyPrimitive},
+    CurveOrder, Error, ErrorKind, Signature, SignatureSize,
 };
 use core::ops::Add;
 use elliptic_curve::{
     consts::U1,
     generic_array::ArrayLength,
     weierstrass::{
-        point::{CompressedPointSize, UncompressedPointSize},
+        point::{CompressedPointSize, UncompressedPoint, UncompressedPointSize},
         public_key::{FromPublicKey, PublicKey},
         Curve,
     },
@@ -20,6 +25,27 @@ use elliptic_curve::{
 };
 use signature::{digest::Digest, DigestVerifier};
 
+#[cfg(all(feature = "rand", feature = "recoverable"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "rand", feature = "recoverable"))))]
+pub mod batch;
+
+#[cfg(all(test, feature = "wycheproof"))]
+mod wycheproof;
+
+/// Verify a signature over a message which has already been hashed (i.e. a
+/// "prehash").
+///
+/// This is useful for protocols which compute the message digest separately
+/// from the signature, such as TLS or X.509.
+pub trait PrehashVerifier<S> {
+    /// Verify the signature against the given prehashed message digest,
+    /// which may be of any length.
+    ///
+    /// Digests longer than the curve's order are truncated to their leftmost
+    /// [`CurveOrder::order_bits`] bits as described in FIPS 186-4.
+    fn verify_prehash(&self, prehash: &[u8], signature: &S) -> Result<(), Error>;
+}
+
 /// ECDSA verifier
 pub struct Verifier<C: Curve + Arithmetic> {
     public_key: C::AffinePoint,
@@ -37,15 +63,14 @@ where
 {
     /// Create a new verifier
     pub fn new(public_key: &PublicKey<C>) -> Result<Self, Error> {
-        let affine_point = C::AffinePoint::from_public_key(public_key);
-
-        if affine_point.is_some().into() {
-            Ok(Self {
-                public_key: affine_point.unwrap(),
-            })
-        } else {
-            Err(Error::new())
-        }
+        decode_public_key(public_key).map(|public_key| Self { public_key })
+    }
+}
+
+impl<C: Curve + Arithmetic> Verifier<C> {
+    /// Get the decoded affine point of the public key
+    pub(crate) fn affine_point(&self) -> &C::AffinePoint {
+        &self.public_key
     }
 }
 
@@ -56,12 +81,28 @@ where
     C::AffinePoint: VerifyPrimitive<C>,
     SignatureSize<C>: ArrayLength<u8>,
 {
+    /// Verify the signature against the finalized output of the given digest,
+    /// which is reduced modulo the curve's order by [`VerifyPrimitive`]
     fn verify_digest(&self, digest: D, signature: &Signature<C>) -> Result<(), Error> {
+        check_nonzero(signature)?;
         self.public_key
             .verify_prehashed(&digest.finalize(), signature)
     }
 }
 
+impl<C> PrehashVerifier<Signature<C>> for Verifier<C>
+where
+    C: Curve + Arithmetic + CurveOrder,
+    C::AffinePoint: VerifyPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    fn verify_prehash(&self, prehash: &[u8], signature: &Signature<C>) -> Result<(), Error> {
+        check_nonzero(signature)?;
+        self.public_key
+            .verify_prehashed(&bits2int::<C>(prehash, C::order_bits()), signature)
+    }
+}
+
 impl<C> signature::Verifier<Signature<C>> for Verifier<C>
 where
     C: Curve + Arithmetic + DigestPrimitive,
@@ -73,3 +114,458 @@ where
         self.verify_digest(C::Digest::new().chain(msg), signature)
     }
 }
+
+/// Streaming ECDSA verifier, which hashes a message fed to it in chunks
+/// using the digest `D` and then verifies a signature over it.
+///
+/// This avoids buffering large messages in memory, and is equivalent to
+/// verifying the signature using the [`DigestVerifier`] impl of the
+/// [`Verifier`] it was created from, with a digest of the whole message.
+pub struct VerifyingStream<C: Curve + Arithmetic, D> {
+    verifier: Verifier<C>,
+    digest: D,
+}
+
+impl<C, D> VerifyingStream<C, D>
+where
+    C: Curve + Arithmetic,
+    D: Digest<OutputSize = C::ElementSize>,
+    C::AffinePoint: VerifyPrimitive<C>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Create a new streaming verifier for signatures by the given
+    /// verifier's public key
+    pub fn new(verifier: &Verifier<C>) -> Self {
+        Self {
+            verifier: Verifier {
+                public_key: verifier.public_key,
+            },
+            digest: D::new(),
+        }
+    }
+
+    /// Feed the next chunk of the message into the digest
+    pub fn update(&mut self, chunk: &[u8]) {
+        self.digest.update(chunk);
+    }
+
+    /// Verify the signature over the message fed in so far
+    pub fn finalize_verify(self, signature: &Signature<C>) -> Result<(), Error> {
+        self.verifier.verify_digest(self.digest, signature)
+    }
+}
+
+/// Check that the given public key is a valid point on the curve `C` and a
+/// member of the subgroup generated by the base point, returning an
+/// [`ErrorKind::InvalidPoint`] error otherwise.
+///
+/// The point is decoded as in [`Verifier::new`], using the curve's
+/// [`FromPublicKey`] impl:
+///
+/// - the encoded coordinates must be valid field elements which satisfy the
+///   curve equation (or, for compressed keys, the `x`-coordinate must have
+///   a corresponding `y`-coordinate);
+/// - the point at infinity has no SEC1 encoding as a [`PublicKey`], and
+///   encodings commonly used in its place such as `(0, 0)` are rejected as
+///   they are not on the curve.
+///
+/// It's then checked to be in the base point's subgroup using
+/// [`hazmat::check_subgroup`], which is a no-op on prime-order curves such
+/// as P-256 and secp256k1. [`Verifier::new`] and [`verify_with_sec1_key`]
+/// only decode the point, as they only need [`Arithmetic`]: on curves with
+/// a cofactor, the subgroup check is instead performed when verifying by
+/// [`hazmat::verify_prehashed`].
+///
+/// [`hazmat::check_subgroup`]: crate::hazmat::check_subgroup
+/// [`hazmat::verify_prehashed`]: crate::hazmat::verify_prehashed
+pub fn validate_public_key<C>(public_key: &PublicKey<C>) -> Result<(), Error>
+where
+    C: CurveArithmetic,
+    C::AffinePoint: FromPublicKey<C>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    decode_public_key(public_key).and_then(|point| check_subgroup::<C>(&point))
+}
+
+/// Verify the signature over the given message using a SEC1-encoded public
+/// key (either compressed or uncompressed) and the curve's preferred digest.
+///
+/// The public key is decoded and validated as described in
+/// [`validate_public_key`] before verifying, and an
+/// [`ErrorKind::InvalidPoint`] error is returned if it's malformed or not a
+/// point on the curve.
+pub fn verify_with_sec1_key<C>(
+    sec1: &[u8],
+    msg: &[u8],
+    signature: &Signature<C>,
+) -> Result<(), Error>
+where
+    C: Curve + Arithmetic + DigestPrimitive,
+    C::AffinePoint: VerifyPrimitive<C> + FromPublicKey<C>,
+    C::Digest: Digest<OutputSize = C::ElementSize>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    let public_key =
+        PublicKey::from_bytes(sec1).ok_or_else(|| Error::from(ErrorKind::InvalidPoint))?;
+
+    signature::Verifier::verify(&Verifier::new(&public_key)?, msg, signature)
+}
+
+/// Verify a signature produced by a threshold or multi-party signing scheme
+/// over the given message, using the aggregated public key and the curve's
+/// preferred digest.
+///
+/// Schemes in which the parties' shares combine into a single secret scalar
+/// `d = d₁ + d₂ + ⋯`, and which produce a standard ECDSA signature `(r, s)`
+/// under `d`, need no special verification: the signature verifies against
+/// the public key `𝐐 = d×𝑮 = 𝐐₁ + 𝐐₂ + ⋯`, which can be computed from the
+/// parties' public keys using [`add_public_keys`]. This function is therefore
+/// exactly [`verify_with_sec1_key`] with a [`PublicKey`], and exists to
+/// document that composition.
+///
+/// Aggregating the signature itself is left to the signing scheme: this
+/// crate doesn't combine partial signatures, and verifying a partial
+/// signature against a party's own public key will fail.
+pub fn verify_aggregated<C>(
+    agg_key: &PublicKey<C>,
+    msg: &[u8],
+    signature: &Signature<C>,
+) -> Result<(), Error>
+where
+    C: Curve + Arithmetic + DigestPrimitive,
+    C::AffinePoint: VerifyPrimitive<C> + FromPublicKey<C>,
+    C::Digest: Digest<OutputSize = C::ElementSize>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    signature::Verifier::verify(&Verifier::new(agg_key)?, msg, signature)
+}
+
+/// Add two public keys, i.e. compute `𝐐₁ + 𝐐₂`, corresponding to the sum of
+/// their secret scalars (e.g. to aggregate the public keys of a threshold
+/// signing scheme for [`verify_aggregated`]).
+///
+/// The result is serialized in the curve's preferred form (i.e. compressed
+/// if [`Curve::COMPRESS_POINTS`] is set). Returns an
+/// [`ErrorKind::InvalidPoint`] error if either key is invalid, or if the
+/// keys sum to the point at infinity (i.e. their secret scalars sum to zero).
+pub fn add_public_keys<C>(a: &PublicKey<C>, b: &PublicKey<C>) -> Result<PublicKey<C>, Error>
+where
+    C: CurveArithmetic,
+    C::AffinePoint: FromPublicKey<C>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPoint<C>: From<C::AffinePoint>,
+{
+    let sum = C::add_points(&decode_public_key(a)?, &decode_public_key(b)?);
+
+    if sum.is_none().into() {
+        return Err(ErrorKind::InvalidPoint.into());
+    }
+
+    let mut public_key = PublicKey::from(UncompressedPoint::from(sum.unwrap()));
+
+    if C::COMPRESS_POINTS {
+        public_key.compress();
+    }
+
+    Ok(public_key)
+}
+
+/// Decode the given public key into an affine point on the curve
+fn decode_public_key<C>(public_key: &PublicKey<C>) -> Result<C::AffinePoint, Error>
+where
+    C: Curve + Arithmetic,
+    C::AffinePoint: FromPublicKey<C>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+{
+    let affine_point = C::AffinePoint::from_public_key(public_key);
+
+    if affine_point.is_some().into() {
+        Ok(affine_point.unwrap())
+    } else {
+        Err(ErrorKind::InvalidPoint.into())
+    }
+}
+
+/// Reject signatures where `r` or `s` is zero before calling into the curve's
+/// [`VerifyPrimitive`] impl, as such signatures are trivially invalid.
+///
+/// Not constant time, but we're operating on public values.
+fn check_nonzero<C>(signature: &Signature<C>) -> Result<(), Error>
+where
+    C: Curve,
+    SignatureSize<C>: ArrayLength<u8>,
+{
+    let is_zero = |bytes: &[u8]| bytes.iter().all(|&byte| byte == 0);
+
+    if is_zero(signature.r()) || is_zero(signature.s()) {
+        Err(ErrorKind::ZeroScalar.into())
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        add_public_keys, validate_public_key, verify_aggregated, verify_with_sec1_key,
+        PrehashVerifier, Verifier, VerifyingStream,
+    };
+    use crate::Signature;
+    #[cfg(feature = "std")]
+    use crate::{error::tests::kind, ErrorKind};
+    use elliptic_curve::weierstrass::PublicKey;
+    use hex_literal::hex;
+    use p256::NistP256;
+    use sha2::{Digest, Sha256};
+    use signature::DigestVerifier;
+
+    /// Public key from RFC 6979 A.2.5 (P-256)
+    const PUBLIC_KEY: [u8; 65] = hex!(
+        "04 60FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6
+            7903FE1008B8BC99A41AE9E95628BC64F2F1B20C2D7E9F5177A3C294D4462299"
+    );
+
+    /// RFC 6979 A.2.5 signature of "sample" using SHA-256
+    const SIGNATURE: [u8; 64] = hex!(
+        "EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716
+         F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8"
+    );
+
+    fn verifier() -> Verifier<NistP256> {
+        Verifier::new(&PublicKey::from_bytes(&PUBLIC_KEY[..]).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn verify_digest() {
+        let signature = Signature::from_scalars(SIGNATURE[..32].into(), SIGNATURE[32..].into());
+        let digest = Sha256::new().chain(b"sample");
+        assert!(verifier().verify_digest(digest, &signature).is_ok());
+    }
+
+    #[test]
+    fn verify_digest_wrong_message() {
+        let signature = Signature::from_scalars(SIGNATURE[..32].into(), SIGNATURE[32..].into());
+        let digest = Sha256::new().chain(b"test");
+        assert!(verifier().verify_digest(digest, &signature).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn verify_digest_wrong_message_kind() {
+        let signature = Signature::from_scalars(SIGNATURE[..32].into(), SIGNATURE[32..].into());
+        let digest = Sha256::new().chain(b"test");
+        let err = verifier().verify_digest(digest, &signature).unwrap_err();
+        assert_eq!(kind(err), ErrorKind::VerificationFailed);
+    }
+
+    /// ES256 example from RFC 7515 Appendix A.3
+    #[cfg(feature = "jws")]
+    #[test]
+    fn verify_rfc7515_jws() {
+        const PUBLIC_KEY: [u8; 65] = hex!(
+            "04 7fcdce2770f6c45d4183cbee6fdb4b7b580733357be9ef13bacf6e3c7bd15445
+                c7f144cd1bbd9b7e872cdfedb9eeb9f4b3695d6ea90b24ad8a4623288588e5ad"
+        );
+
+        const SIGNING_INPUT: &[u8] = b"eyJhbGciOiJFUzI1NiJ9.eyJpc3MiOiJqb2UiLA0KICJleHAiOjEzMDA4MTkzODAsDQogImh0dHA6Ly9leGFtcGxlLmNvbS9pc19yb290Ijp0cnVlfQ";
+
+        let verifier =
+            Verifier::<NistP256>::new(&PublicKey::from_bytes(&PUBLIC_KEY[..]).unwrap()).unwrap();
+        let signature = Signature::from_jws(
+            "DtEhU3ljbEg8L38VWAfUAqOyKAM6-Xx-F4GawxaepmXFCgfTjDxw5djxLa8ISlSApmWQxfKTUJqPP3-Kg6NU1Q",
+        )
+        .unwrap();
+
+        assert!(signature::Verifier::verify(&verifier, SIGNING_INPUT, &signature).is_ok());
+    }
+
+    #[test]
+    fn reject_zero_scalars() {
+        let (r, s) = SIGNATURE.split_at(32);
+        let zero = [0u8; 32];
+
+        for &(r, s) in &[(&zero[..], s), (r, &zero[..]), (&zero[..], &zero[..])] {
+            let signature = Signature::from_scalars(r.into(), s.into());
+            let digest = Sha256::new().chain(b"sample");
+            let result = verifier().verify_digest(digest, &signature);
+
+            #[cfg(feature = "std")]
+            assert_eq!(kind(result.unwrap_err()), ErrorKind::ZeroScalar);
+            #[cfg(not(feature = "std"))]
+            assert!(result.is_err());
+
+            let prehash = Sha256::digest(b"sample");
+            assert!(verifier().verify_prehash(&prehash, &signature).is_err());
+        }
+    }
+
+    #[test]
+    fn validate_public_key_valid() {
+        let public_key = PublicKey::<NistP256>::from_bytes(&PUBLIC_KEY[..]).unwrap();
+        assert!(validate_public_key(&public_key).is_ok());
+
+        let mut compressed = public_key;
+        compressed.compress();
+        assert!(validate_public_key(&compressed).is_ok());
+    }
+
+    #[test]
+    fn validate_public_key_identity() {
+        // The point at infinity has no SEC1 public key encoding...
+        assert!(PublicKey::<NistP256>::from_bytes([0x00]).is_none());
+
+        // ...and the `(0, 0)` placeholder sometimes used for it isn't on the curve
+        let mut bytes = [0u8; 65];
+        bytes[0] = 0x04;
+        let public_key = PublicKey::<NistP256>::from_bytes(&bytes[..]).unwrap();
+        let result = validate_public_key(&public_key);
+
+        #[cfg(feature = "std")]
+        assert_eq!(kind(result.unwrap_err()), ErrorKind::InvalidPoint);
+        #[cfg(not(feature = "std"))]
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn validate_public_key_subgroup() {
+        use crate::{
+            hazmat::CurveArithmetic,
+            test_curves::cofactor::{self, CofactorCurve},
+        };
+        use elliptic_curve::weierstrass::point::UncompressedPoint;
+
+        let public_key = |point| PublicKey::from(UncompressedPoint::from(point));
+        let point = CofactorCurve::mul_base(&cofactor::Scalar(7)).unwrap();
+        assert!(validate_public_key(&public_key(point)).is_ok());
+
+        let offset_point = CofactorCurve::add_points(&point, &cofactor::SMALL_ORDER_POINT).unwrap();
+
+        for &point in &[cofactor::SMALL_ORDER_POINT, offset_point] {
+            let result = validate_public_key(&public_key(point));
+
+            #[cfg(feature = "std")]
+            assert_eq!(kind(result.unwrap_err()), ErrorKind::InvalidPoint);
+            #[cfg(not(feature = "std"))]
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn validate_public_key_off_curve() {
+        let mut bytes = PUBLIC_KEY;
+        bytes[64] ^= 1;
+        let public_key = PublicKey::<NistP256>::from_bytes(&bytes[..]).unwrap();
+        assert!(validate_public_key(&public_key).is_err());
+    }
+
+    #[test]
+    fn verifying_stream_large_message() {
+        use crate::hazmat::sign_prehashed;
+        use elliptic_curve::FromBytes;
+
+        const SECRET_KEY: [u8; 32] =
+            hex!("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+
+        // 4 MiB message
+        let msg = (0..(4u32 << 20))
+            .map(|i| (i % 251) as u8)
+            .collect::<std::vec::Vec<u8>>();
+
+        let secret_scalar = p256::Scalar::from_bytes(&SECRET_KEY.into()).unwrap();
+        let ephemeral_scalar = p256::Scalar::from_bytes(&Sha256::digest(b"nonce")).unwrap();
+        let signature =
+            sign_prehashed::<NistP256>(&secret_scalar, &ephemeral_scalar, &Sha256::digest(&msg))
+                .unwrap();
+
+        let mut stream = VerifyingStream::<NistP256, Sha256>::new(&verifier());
+        for chunk in msg.chunks(1000) {
+            stream.update(chunk);
+        }
+        assert!(stream.finalize_verify(&signature).is_ok());
+
+        // Missing the last byte
+        let mut stream = VerifyingStream::<NistP256, Sha256>::new(&verifier());
+        for chunk in msg[..msg.len() - 1].chunks(1000) {
+            stream.update(chunk);
+        }
+        assert!(stream.finalize_verify(&signature).is_err());
+    }
+
+    #[test]
+    fn verify_with_sec1_key_encodings() {
+        let signature =
+            Signature::<NistP256>::from_scalars(SIGNATURE[..32].into(), SIGNATURE[32..].into());
+
+        let mut compressed = PublicKey::<NistP256>::from_bytes(&PUBLIC_KEY[..]).unwrap();
+        compressed.compress();
+
+        for sec1 in &[&PUBLIC_KEY[..], compressed.as_bytes()] {
+            assert!(verify_with_sec1_key(sec1, b"sample", &signature).is_ok());
+            assert!(verify_with_sec1_key(sec1, b"test", &signature).is_err());
+        }
+    }
+
+    #[test]
+    fn verify_with_sec1_key_invalid_point() {
+        let signature =
+            Signature::<NistP256>::from_scalars(SIGNATURE[..32].into(), SIGNATURE[32..].into());
+
+        let mut off_curve = PUBLIC_KEY;
+        off_curve[64] ^= 1;
+        let mut bad_tag = PUBLIC_KEY;
+        bad_tag[0] = 0x05;
+
+        for sec1 in &[&off_curve[..], &bad_tag[..], &PUBLIC_KEY[..64], &[0x00]] {
+            let result = verify_with_sec1_key(sec1, b"sample", &signature);
+
+            #[cfg(feature = "std")]
+            assert_eq!(kind(result.unwrap_err()), ErrorKind::InvalidPoint);
+            #[cfg(not(feature = "std"))]
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn verify_aggregated_two_keys() {
+        use crate::hazmat::{sign_prehashed, CurveArithmetic};
+        use elliptic_curve::{weierstrass::point::UncompressedPoint, FromBytes};
+        use p256::Scalar;
+
+        let public_key = |secret: &Scalar| {
+            PublicKey::from(UncompressedPoint::from(NistP256::mul_base(secret).unwrap()))
+        };
+
+        let d1 = Scalar::from_bytes(&Sha256::digest(b"party 1")).unwrap();
+        let d2 = Scalar::from_bytes(&Sha256::digest(b"party 2")).unwrap();
+        let agg_key = add_public_keys(&public_key(&d1), &public_key(&d2)).unwrap();
+        assert_eq!(agg_key, public_key(&(d1 + &d2)));
+
+        let k = Scalar::from_bytes(&Sha256::digest(b"nonce")).unwrap();
+        let signature =
+            sign_prehashed::<NistP256>(&(d1 + &d2), &k, &Sha256::digest(b"aggregated")).unwrap();
+
+        assert!(verify_aggregated(&agg_key, b"aggregated", &signature).is_ok());
+        assert!(verify_aggregated(&agg_key, b"other", &signature).is_err());
+        assert!(verify_aggregated(&public_key(&d1), b"aggregated", &signature).is_err());
+
+        // Keys whose secret scalars sum to zero
+        assert!(add_public_keys(&public_key(&d1), &public_key(&-d1)).is_err());
+    }
+}
@@ -0,0 +1,681 @@
+//! Batch verification of recoverable ECDSA signatures.
+//!
+//! Rather than checking each signature individually, a batch of `N`
+//! signatures is verified by choosing random scalars `aᵢ` and checking:
+//!
+//! `(∑ aᵢ·zᵢ)×𝑮 + ∑ (aᵢ·rᵢ)×𝐐ᵢ - ∑ (aᵢ·sᵢ)×𝐑ᵢ = 𝒪`
+//!
+//! which folds all `N` multiplications by the generator into one. The random
+//! scalars prevent an attacker from crafting invalid signatures whose errors
+//! cancel each other out.
+//!
+//! The remaining `2N` terms are summed with a single multi-scalar
+//! multiplication ([`multiscalar_mul_vartime`]), which shares the point
+//! doublings between all of them, so each term costs a fraction of an
+//! individual scalar multiplication.
+//!
+//! A plain ECDSA signature only encodes the x-coordinate of `𝐑`, which
+//! leaves the sign of `𝐑` ambiguous. Batching therefore operates on
+//! [`recoverable::Signature`]s, whose [`RecoveryId`] identifies `𝐑` exactly.
+//! Plain signatures can be queued with [`BatchVerifier::queue_signature`],
+//! but determining their `𝐑` costs about as much as verifying them
+//! individually, so this gives no speedup over doing so.
+//!
+//! When every signature in the batch is from the same key, the `𝐐` terms
+//! fold into a single `(∑ aᵢ·rᵢ)×𝐐` multiplication as well, leaving `N`
+//! terms in the multi-scalar multiplication: see
+//! [`VerifyingKey::verify_batch`].
+//!
+//! Verifying with [`VerifyingKey::verify_batch`] doesn't allocate, whereas
+//! [`BatchVerifier`] stores the queued signatures on the heap and requires
+//! the `alloc` feature.
+//!
+//! [`RecoveryId`]: crate::recoverable::RecoveryId
+//! [`multiscalar_mul_vartime`]: crate::hazmat::multiscalar_mul_vartime
+//! [`VerifyingKey::verify_batch`]: crate::VerifyingKey::verify_batch
+
+use crate::{
+    hazmat::{check_subgroup, multiscalar_mul_vartime, CurveArithmetic, DigestPrimitive},
+    recoverable, Error, ErrorKind,
+};
+use core::{borrow::Borrow, fmt, ops::Add};
+use elliptic_curve::{
+    consts::U1,
+    generic_array::ArrayLength,
+    subtle::CtOption,
+    weierstrass::{
+        point::{CompressedPointSize, UncompressedPoint, UncompressedPointSize},
+        public_key::FromPublicKey,
+    },
+    ElementBytes,
+};
+use signature::{
+    digest::Digest,
+    rand_core::{CryptoRng, RngCore},
+};
+
+#[cfg(feature = "alloc")]
+use {
+    crate::recoverable::RecoveryId,
+    alloc::vec::Vec,
+    elliptic_curve::{point::Generator, weierstrass::public_key::PublicKey},
+};
+
+/// Batch verifier for recoverable ECDSA signatures.
+///
+/// Signatures are added to the batch using [`BatchVerifier::queue`] (or
+/// [`BatchVerifier::queue_signature`] for plain signatures), and then
+/// verified all at once using [`BatchVerifier::verify`].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub struct BatchVerifier<C>
+where
+    C: CurveArithmetic + DigestPrimitive,
+    C::AffinePoint: FromPublicKey<C>,
+    C::Digest: Digest<OutputSize = C::ElementSize>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPoint<C>: From<C::AffinePoint>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    recoverable::SignatureSize<C>: ArrayLength<u8>,
+{
+    entries: Vec<Entry<C>>,
+}
+
+/// Public key, prehashed message and signature queued for verification
+struct Entry<C>
+where
+    C: CurveArithmetic,
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    recoverable::SignatureSize<C>: ArrayLength<u8>,
+{
+    public_key: C::AffinePoint,
+    prehash: ElementBytes<C>,
+
+    /// Signature to verify, or `None` if it's already known to be invalid
+    signature: Option<recoverable::Signature<C>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<C> BatchVerifier<C>
+where
+    C: CurveArithmetic + DigestPrimitive,
+    C::AffinePoint: FromPublicKey<C>,
+    C::Digest: Digest<OutputSize = C::ElementSize>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPoint<C>: From<C::AffinePoint>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    recoverable::SignatureSize<C>: ArrayLength<u8>,
+{
+    /// Create a new, empty batch verifier
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Add a signature over the given message to the batch.
+    ///
+    /// Returns an error if the public key is not a valid curve point, or is
+    /// outside the subgroup generated by the base point.
+    pub fn queue(
+        &mut self,
+        public_key: &PublicKey<C>,
+        msg: &[u8],
+        signature: &recoverable::Signature<C>,
+    ) -> Result<(), Error> {
+        let public_key = C::AffinePoint::from_public_key(public_key);
+
+        if public_key.is_none().into() {
+            return Err(ErrorKind::InvalidPoint.into());
+        }
+
+        check_subgroup::<C>(&public_key.unwrap())?;
+
+        self.entries.push(Entry {
+            public_key: public_key.unwrap(),
+            prehash: C::Digest::digest(msg),
+            signature: Some(signature.clone()),
+        });
+
+        Ok(())
+    }
+
+    /// Add a plain (non-recoverable) signature over the given message to the
+    /// batch.
+    ///
+    /// The [`RecoveryId`] of the signature is determined by computing
+    /// `𝐑 = (z·s⁻¹)×𝑮 + (r·s⁻¹)×𝐐`, which costs about as much as verifying
+    /// the signature individually, so batching plain signatures is no faster
+    /// than verifying them one at a time. Prefer [`BatchVerifier::queue`]
+    /// where signers can provide recoverable signatures.
+    ///
+    /// If the signature is invalid, it's still queued, and reported by
+    /// [`BatchVerifier::verify`]. Returns an error if the public key is not
+    /// a valid curve point, or is outside the subgroup generated by the base
+    /// point.
+    pub fn queue_signature(
+        &mut self,
+        public_key: &PublicKey<C>,
+        msg: &[u8],
+        signature: &crate::Signature<C>,
+    ) -> Result<(), Error> {
+        let public_key = C::AffinePoint::from_public_key(public_key);
+
+        if public_key.is_none().into() {
+            return Err(ErrorKind::InvalidPoint.into());
+        }
+
+        let public_key = public_key.unwrap();
+        check_subgroup::<C>(&public_key)?;
+
+        let prehash = C::Digest::digest(msg);
+        let signature = recover_r_point::<C>(&public_key, &prehash, signature)
+            .and_then(|r_point| RecoveryId::from_signature_and_key::<C>(signature, &r_point))
+            .map(|recovery_id| recoverable::Signature::new(signature, recovery_id))
+            .ok();
+
+        self.entries.push(Entry {
+            public_key,
+            prehash,
+            signature,
+        });
+
+        Ok(())
+    }
+
+    /// Number of signatures in the batch
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Is the batch empty?
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Verify all signatures in the batch, using the provided RNG to select
+    /// the random scalars used to combine them.
+    ///
+    /// If the batch fails to verify, each signature is checked individually
+    /// and the returned [`BatchError`] identifies the first invalid one.
+    pub fn verify(&self, rng: impl CryptoRng + RngCore) -> Result<(), BatchError> {
+        verify_entries(|| self.entries.iter(), None, rng)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C> Default for BatchVerifier<C>
+where
+    C: CurveArithmetic + DigestPrimitive,
+    C::AffinePoint: FromPublicKey<C>,
+    C::Digest: Digest<OutputSize = C::ElementSize>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPoint<C>: From<C::AffinePoint>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    recoverable::SignatureSize<C>: ArrayLength<u8>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Error indicating which signature in a batch failed to verify
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BatchError {
+    index: usize,
+}
+
+impl BatchError {
+    /// Index of the first invalid signature, in the order they were queued
+    pub fn index(self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid signature at batch index {}", self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchError {}
+
+impl From<BatchError> for Error {
+    #[cfg(feature = "std")]
+    fn from(err: BatchError) -> Error {
+        Error::from_source(err)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn from(_: BatchError) -> Error {
+        Error::new()
+    }
+}
+
+/// Verify signatures over the given messages which were all produced by the
+/// given public key, using the provided RNG to select the random weights.
+///
+/// If the public key is outside the subgroup generated by the base point,
+/// the first signature is reported as invalid.
+pub(crate) fn verify_same_key<C>(
+    public_key: &C::AffinePoint,
+    batch: &[(&[u8], recoverable::Signature<C>)],
+    rng: impl CryptoRng + RngCore,
+) -> Result<(), BatchError>
+where
+    C: CurveArithmetic + DigestPrimitive,
+    C::AffinePoint: FromPublicKey<C>,
+    C::Digest: Digest<OutputSize = C::ElementSize>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPoint<C>: From<C::AffinePoint>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    recoverable::SignatureSize<C>: ArrayLength<u8>,
+{
+    if !batch.is_empty() && check_subgroup::<C>(public_key).is_err() {
+        return Err(BatchError { index: 0 });
+    }
+
+    let entries = || {
+        batch.iter().map(move |(msg, signature)| Entry {
+            public_key: *public_key,
+            prehash: C::Digest::digest(msg),
+            signature: Some(signature.clone()),
+        })
+    };
+
+    verify_entries(entries, Some(public_key), rng)
+}
+
+/// Verify the entries produced by `entries` as a batch, falling back to
+/// checking them individually to identify the first invalid one if the batch
+/// fails.
+///
+/// The entries are produced (and, for the fallback, reproduced) on demand,
+/// so that verifying doesn't require allocating them all up front.
+///
+/// If `shared_key` is provided, every entry's public key must be equal to it.
+fn verify_entries<C, E, I>(
+    entries: impl Fn() -> I,
+    shared_key: Option<&C::AffinePoint>,
+    mut rng: impl CryptoRng + RngCore,
+) -> Result<(), BatchError>
+where
+    C: CurveArithmetic,
+    E: Borrow<Entry<C>>,
+    I: Iterator<Item = E>,
+    C::AffinePoint: FromPublicKey<C>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPoint<C>: From<C::AffinePoint>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    recoverable::SignatureSize<C>: ArrayLength<u8>,
+{
+    let batch = entries().enumerate().map(|(index, entry)| {
+        let mut bytes = ElementBytes::<C>::default();
+        rng.fill_bytes(&mut bytes);
+        (index, entry, C::reduce_scalar(&bytes))
+    });
+
+    if check(batch, shared_key)? {
+        return Ok(());
+    }
+
+    // Find the first signature which fails to verify by itself
+    let mut one = ElementBytes::<C>::default();
+    *one.last_mut().unwrap() = 1;
+    let one = C::reduce_scalar(&one);
+
+    for (index, entry) in entries().enumerate() {
+        if !check(core::iter::once((index, entry, one)), None)? {
+            return Err(BatchError { index });
+        }
+    }
+
+    // Unreachable unless the random weights were degenerate
+    Err(BatchError { index: 0 })
+}
+
+/// Check the weighted sum of the verification equations for the given
+/// entries is the point at infinity.
+///
+/// The `𝐑` (and, unless `shared_key` is provided, `𝐐`) terms of all entries
+/// are summed with one multi-scalar multiplication. If `shared_key` is
+/// provided, the `𝐐` terms are instead combined into a single multiplication
+/// by it.
+///
+/// Returns an error for entries with invalid scalars or `𝐑` points, which
+/// are rejected before performing any point arithmetic on them.
+fn check<C, E, I>(entries: I, shared_key: Option<&C::AffinePoint>) -> Result<bool, BatchError>
+where
+    C: CurveArithmetic,
+    C::AffinePoint: FromPublicKey<C>,
+    C::ElementSize: Add<U1>,
+    <C::ElementSize as Add>::Output: Add<U1>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPoint<C>: From<C::AffinePoint>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    crate::SignatureSize<C>: ArrayLength<u8> + Add<U1>,
+    recoverable::SignatureSize<C>: ArrayLength<u8>,
+    E: Borrow<Entry<C>>,
+    I: Iterator<Item = (usize, E, C::Scalar)>,
+{
+    let mut generator_scalar = C::Scalar::default();
+    let mut shared_key_scalar = C::Scalar::default();
+    let mut error = None;
+
+    let terms = entries
+        .scan((), |_, (index, entry, weight)| {
+            let entry = entry.borrow();
+            let terms = || -> Result<_, Error> {
+                let signature = entry
+                    .signature
+                    .as_ref()
+                    .ok_or(ErrorKind::VerificationFailed)?;
+                let (r, s) = signature.signature().split_scalars()?;
+                Ok((r, s, signature.r_point()?))
+            };
+
+            let (r, s, r_point) = match terms().map_err(|_| BatchError { index }) {
+                Ok(terms) => terms,
+                Err(err) => {
+                    error = Some(err);
+                    return None;
+                }
+            };
+
+            let z = C::reduce_scalar(&entry.prehash);
+            generator_scalar = C::add_scalars(&generator_scalar, &C::mul_scalars(&weight, &z));
+
+            let q_term = if shared_key.is_some() {
+                shared_key_scalar =
+                    C::add_scalars(&shared_key_scalar, &C::mul_scalars(&weight, &r));
+                None
+            } else {
+                Some((entry.public_key, C::mul_scalars(&weight, &r)))
+            };
+
+            let r_term = (r_point, C::negate_scalar(&C::mul_scalars(&weight, &s)));
+            Some(core::iter::once(r_term).chain(q_term))
+        })
+        .flatten();
+
+    let mut sum = into_option(multiscalar_mul_vartime::<C, _>(terms));
+
+    if let Some(err) = error {
+        return Err(err);
+    }
+
+    if let Some(public_key) = shared_key {
+        let q_term = C::mul_point(public_key, &shared_key_scalar);
+        sum = add_term::<C>(sum, into_option(q_term));
+    }
+
+    let g_term = C::mul_base(&generator_scalar);
+    Ok(add_term::<C>(sum, into_option(g_term)).is_none())
+}
+
+/// Compute the point `𝐑 = (z·s⁻¹)×𝑮 + (r·s⁻¹)×𝐐` for the given plain
+/// signature, returning an error if the signature is invalid, i.e. if `r`
+/// isn't the x-coordinate of `𝐑` reduced modulo `n`.
+#[cfg(feature = "alloc")]
+fn recover_r_point<C>(
+    public_key: &C::AffinePoint,
+    prehash: &ElementBytes<C>,
+    signature: &crate::Signature<C>,
+) -> Result<C::AffinePoint, Error>
+where
+    C: CurveArithmetic,
+    crate::SignatureSize<C>: ArrayLength<u8>,
+{
+    let (r, s) = signature.split_scalars()?;
+    let s_inv = into_option(C::invert_scalar(&s)).ok_or(ErrorKind::ZeroScalar)?;
+    let u1 = C::mul_scalars(&C::reduce_scalar(prehash), &s_inv);
+    let u2 = C::mul_scalars(&r, &s_inv);
+
+    let terms = [(C::AffinePoint::generator(), u1), (*public_key, u2)];
+    let r_point = into_option(multiscalar_mul_vartime::<C, _>(terms.iter().cloned()))
+        .ok_or(ErrorKind::VerificationFailed)?;
+
+    if C::reduce_scalar(&C::x_coordinate(&r_point)).into() == *signature.r() {
+        Ok(r_point)
+    } else {
+        Err(ErrorKind::VerificationFailed.into())
+    }
+}
+
+/// Add two points, where `None` represents the point at infinity
+fn add_term<C: CurveArithmetic>(
+    a: Option<C::AffinePoint>,
+    b: Option<C::AffinePoint>,
+) -> Option<C::AffinePoint> {
+    match (a, b) {
+        (Some(a), Some(b)) => into_option(C::add_points(&a, &b)),
+        (Some(point), None) | (None, Some(point)) => Some(point),
+        (None, None) => None,
+    }
+}
+
+/// Convert a [`CtOption`] into an [`Option`] (in variable time)
+fn into_option<T>(value: CtOption<T>) -> Option<T> {
+    if value.is_some().into() {
+        Some(value.unwrap())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BatchVerifier;
+    use crate::{
+        hazmat::{sign_prehashed, CurveArithmetic},
+        recoverable,
+        test_curves::TestRng,
+        VerifyingKey,
+    };
+    use elliptic_curve::{
+        point::Generator,
+        weierstrass::{point::UncompressedPoint, public_key::PublicKey},
+        ElementBytes,
+    };
+    use k256::{AffinePoint, Secp256k1};
+    use sha2::{Digest, Sha256};
+    use std::vec::Vec;
+
+    type Signature = recoverable::Signature<Secp256k1>;
+
+    fn scalar(n: u8) -> k256::Scalar {
+        let mut bytes = ElementBytes::<Secp256k1>::default();
+        bytes[31] = n;
+        Secp256k1::reduce_scalar(&bytes)
+    }
+
+    /// Generate test keys and recoverable signatures over distinct messages
+    fn signatures(
+        n: u8,
+    ) -> Vec<(
+        PublicKey<Secp256k1>,
+        Vec<u8>,
+        recoverable::Signature<Secp256k1>,
+    )> {
+        (1..=n)
+            .map(|i| {
+                let secret_scalar = scalar(i);
+                let public_key = Secp256k1::mul_point(&AffinePoint::generator(), &secret_scalar);
+                let public_key = PublicKey::from(UncompressedPoint::from(public_key.unwrap()));
+
+                let msg = std::vec![i; 16];
+                let prehash = Sha256::digest(&msg);
+                let signature =
+                    sign_prehashed::<Secp256k1>(&secret_scalar, &scalar(!i), &prehash).unwrap();
+                let signature =
+                    recoverable::Signature::from_trial_recovery(&public_key, &msg, &signature)
+                        .unwrap();
+
+                (public_key, msg, signature)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn verify_valid_batch() {
+        let mut batch = BatchVerifier::<Secp256k1>::new();
+
+        for (public_key, msg, signature) in &signatures(8) {
+            batch.queue(public_key, msg, signature).unwrap();
+        }
+
+        assert_eq!(batch.len(), 8);
+        assert!(batch.verify(TestRng(42)).is_ok());
+    }
+
+    #[test]
+    fn verify_empty_batch() {
+        let batch = BatchVerifier::<Secp256k1>::default();
+        assert!(batch.is_empty());
+        assert!(batch.verify(TestRng(42)).is_ok());
+    }
+
+    #[test]
+    fn report_invalid_index() {
+        let mut batch = BatchVerifier::<Secp256k1>::new();
+
+        for (i, (public_key, msg, signature)) in signatures(8).iter().enumerate() {
+            if i == 5 {
+                batch
+                    .queue(public_key, b"wrong message", signature)
+                    .unwrap();
+            } else {
+                batch.queue(public_key, msg, signature).unwrap();
+            }
+        }
+
+        assert_eq!(batch.verify(TestRng(42)).unwrap_err().index(), 5);
+    }
+
+    #[test]
+    fn reject_wrong_recovery_id() {
+        let mut batch = BatchVerifier::<Secp256k1>::new();
+
+        for (i, (public_key, msg, signature)) in signatures(4).iter().enumerate() {
+            if i == 2 {
+                // Flip the parity of 𝐑: still a valid non-recoverable signature
+                let recovery_id = signature.recovery_id().to_byte() ^ 1;
+                let signature = recoverable::Signature::new(
+                    &signature.signature(),
+                    recoverable::RecoveryId::new(recovery_id).unwrap(),
+                );
+                batch.queue(public_key, msg, &signature).unwrap();
+            } else {
+                batch.queue(public_key, msg, signature).unwrap();
+            }
+        }
+
+        assert_eq!(batch.verify(TestRng(42)).unwrap_err().index(), 2);
+    }
+
+    #[test]
+    fn verify_plain_signatures() {
+        let mut batch = BatchVerifier::<Secp256k1>::new();
+
+        for (i, (public_key, msg, signature)) in signatures(8).iter().enumerate() {
+            if i % 2 == 0 {
+                batch.queue(public_key, msg, signature).unwrap();
+            } else {
+                batch
+                    .queue_signature(public_key, msg, &signature.signature())
+                    .unwrap();
+            }
+        }
+
+        assert!(batch.verify(TestRng(42)).is_ok());
+    }
+
+    #[test]
+    fn report_invalid_plain_signature() {
+        let mut batch = BatchVerifier::<Secp256k1>::new();
+
+        for (i, (public_key, msg, signature)) in signatures(4).iter().enumerate() {
+            let msg: &[u8] = if i == 1 { b"wrong message" } else { msg };
+            batch
+                .queue_signature(public_key, msg, &signature.signature())
+                .unwrap();
+        }
+
+        assert_eq!(batch.len(), 4);
+        assert_eq!(batch.verify(TestRng(42)).unwrap_err().index(), 1);
+    }
+
+    /// Generate recoverable signatures over distinct messages from one key
+    fn same_key_signatures(n: u8) -> (VerifyingKey<Secp256k1>, Vec<(Vec<u8>, Signature)>) {
+        let secret_scalar = scalar(42);
+        let public_key = Secp256k1::mul_point(&AffinePoint::generator(), &secret_scalar);
+        let public_key = PublicKey::from(UncompressedPoint::from(public_key.unwrap()));
+
+        let signatures = (1..=n)
+            .map(|i| {
+                let msg = std::vec![i; 16];
+                let prehash = Sha256::digest(&msg);
+                let signature =
+                    sign_prehashed::<Secp256k1>(&secret_scalar, &scalar(!i), &prehash).unwrap();
+                let signature =
+                    recoverable::Signature::from_trial_recovery(&public_key, &msg, &signature)
+                        .unwrap();
+
+                (msg, signature)
+            })
+            .collect();
+
+        (
+            VerifyingKey::from_public_key(public_key).unwrap(),
+            signatures,
+        )
+    }
+
+    #[test]
+    fn verify_same_key_batch() {
+        let (verifying_key, signatures) = same_key_signatures(8);
+        let batch = signatures
+            .iter()
+            .map(|(msg, signature)| (msg.as_slice(), *signature))
+            .collect::<Vec<_>>();
+
+        assert!(verifying_key.verify_batch(&batch, TestRng(42)).is_ok());
+        assert!(verifying_key.verify_batch(&[], TestRng(42)).is_ok());
+    }
+
+    #[test]
+    fn verify_same_key_batch_reports_invalid_index() {
+        let (verifying_key, same_key) = same_key_signatures(8);
+        let mut batch = same_key
+            .iter()
+            .map(|(msg, signature)| (msg.as_slice(), *signature))
+            .collect::<Vec<_>>();
+        batch[3].0 = b"wrong message";
+
+        let err = verifying_key.verify_batch(&batch, TestRng(42)).unwrap_err();
+        assert_eq!(err.index(), 3);
+
+        // Signatures by a different key are rejected as well
+        let other = signatures(1);
+        let (_, msg, signature) = &other[0];
+        let batch = [(msg.as_slice(), *signature)];
+        let err = verifying_key.verify_batch(&batch, TestRng(42)).unwrap_err();
+        assert_eq!(err.index(), 0);
+    }
+}
@@ -0,0 +1,219 @@
+//! Harness for running [Wycheproof] ECDSA test vectors through the DER and
+//! IEEE P1363 verification paths for P-256 and secp256k1.
+//!
+//! Enabled by the `wycheproof` feature. A subset of the upstream vectors is
+//! always checked: the files in `tests/data` are copied verbatim from the
+//! `testvectors_v1` directory of Wycheproof, keeping at most two test cases
+//! per combination of result and flags (and only the notes for those flags).
+//! Their `numberOfTests` headers still describe the full upstream files.
+//!
+//! The full upstream files are checked by ignored tests, which require
+//! `WYCHEPROOF_DIR` to be set to the `testvectors_v1` directory of a
+//! Wycheproof checkout:
+//!
+//! ```text
+//! WYCHEPROOF_DIR=/path/to/wycheproof/testvectors_v1 cargo test --features wycheproof -- --ignored
+//! ```
+//!
+//! Test cases marked as "acceptable" are run, but either result is allowed.
+//!
+//! [Wycheproof]: https://github.com/google/wycheproof
+
+use crate::{
+    asn1::{MaxOverhead, MaxSize},
+    hazmat::{DigestPrimitive, VerifyPrimitive},
+    verifier::Verifier,
+    Signature,
+};
+use core::ops::Add;
+use elliptic_curve::{
+    consts::U1,
+    generic_array::ArrayLength,
+    weierstrass::{
+        point::{CompressedPointSize, UncompressedPointSize},
+        public_key::FromPublicKey,
+        Curve, PublicKey,
+    },
+    Arithmetic,
+};
+use k256::Secp256k1;
+use p256::NistP256;
+use serde_json::Value;
+use signature::{digest::Digest, Verifier as _};
+use std::{env, format, fs, path::Path, string::String, vec, vec::Vec};
+
+/// Test vector files for P-256
+const P256_FILES: &[&str] = &[
+    "ecdsa_secp256r1_sha256_test.json",
+    "ecdsa_secp256r1_sha256_p1363_test.json",
+];
+
+/// Test vector files for secp256k1
+const K256_FILES: &[&str] = &[
+    "ecdsa_secp256k1_sha256_test.json",
+    "ecdsa_secp256k1_sha256_p1363_test.json",
+];
+
+/// Signature encoding used by a Wycheproof test group
+#[derive(Copy, Clone, Debug)]
+enum Encoding {
+    /// ASN.1 DER (`EcdsaVerify`)
+    Der,
+
+    /// IEEE P1363 `r || s` (`EcdsaP1363Verify`)
+    P1363,
+}
+
+/// Verify a signature over a message with the given SEC1 public key
+type VerifyFn = fn(public_key: &[u8], msg: &[u8], sig: &[u8], encoding: Encoding) -> bool;
+
+/// Decode and verify a signature in the given encoding, returning `false` if
+/// either the key or signature fails to decode
+fn verify<C>(public_key: &[u8], msg: &[u8], sig: &[u8], encoding: Encoding) -> bool
+where
+    C: Curve + Arithmetic + DigestPrimitive,
+    C::AffinePoint: VerifyPrimitive<C> + FromPublicKey<C>,
+    C::Digest: Digest<OutputSize = C::ElementSize>,
+    C::ElementSize: Add + Add<U1> + ArrayLength<u8>,
+    <C::ElementSize as Add>::Output: Add<U1> + Add<MaxOverhead> + ArrayLength<u8>,
+    CompressedPointSize<C>: ArrayLength<u8>,
+    UncompressedPointSize<C>: ArrayLength<u8>,
+    MaxSize<C>: ArrayLength<u8>,
+{
+    let verifier = match PublicKey::<C>::from_bytes(public_key) {
+        Some(public_key) => match Verifier::<C>::new(&public_key) {
+            Ok(verifier) => verifier,
+            Err(_) => return false,
+        },
+        None => return false,
+    };
+
+    let signature = match encoding {
+        Encoding::Der => Signature::<C>::from_der(sig),
+        Encoding::P1363 => Signature::<C>::from_p1363(sig),
+    };
+
+    signature
+        .and_then(|signature| verifier.verify(msg, &signature))
+        .is_ok()
+}
+
+/// Run all of the test groups in the given Wycheproof JSON document,
+/// returning a description of each test case with an unexpected result
+fn run(name: &str, json: &str, verify: VerifyFn) -> Vec<String> {
+    let document: Value = serde_json::from_str(json).expect("invalid test vector JSON");
+    let mut failures = Vec::new();
+
+    for group in document["testGroups"]
+        .as_array()
+        .expect("missing testGroups")
+    {
+        let encoding = match group["type"].as_str() {
+            Some("EcdsaVerify") => Encoding::Der,
+            Some("EcdsaP1363Verify") => Encoding::P1363,
+            other => panic!("{}: unsupported test group type {:?}", name, other),
+        };
+
+        assert_eq!(group["sha"], "SHA-256", "{}: unsupported digest", name);
+
+        // Older files use `key`, newer ones `publicKey`
+        let key = group.get("publicKey").unwrap_or(&group["key"]);
+        let public_key = decode(&key["uncompressed"]);
+
+        for test in group["tests"].as_array().expect("missing tests") {
+            let msg = decode(&test["msg"]);
+            let sig = decode(&test["sig"]);
+
+            let verified = verify(&public_key, &msg, &sig, encoding);
+            let expected = match test["result"].as_str() {
+                Some("valid") => true,
+                Some("invalid") => false,
+                Some("acceptable") => continue,
+                other => panic!("{}: unknown test result {:?}", name, other),
+            };
+
+            if verified != expected {
+                failures.push(format!(
+                    "{} tcId {} ({:?}, {}): expected {}",
+                    name,
+                    test["tcId"],
+                    encoding,
+                    test["comment"].as_str().unwrap_or_default(),
+                    test["result"].as_str().unwrap(),
+                ));
+            }
+        }
+    }
+
+    failures
+}
+
+/// Decode a hex string from a test vector
+fn decode(value: &Value) -> Vec<u8> {
+    let hex = value.as_str().expect("expected hex string").as_bytes();
+    let mut bytes = vec![0u8; hex.len() / 2];
+    crate::hex::decode(hex, &mut bytes).expect("invalid hex in test vector");
+    bytes
+}
+
+/// Run the given vector files from `dir`
+fn run_files(dir: &Path, files: &[&str], verify: VerifyFn) -> Vec<String> {
+    files
+        .iter()
+        .flat_map(|file| {
+            let path = dir.join(file);
+            let json = fs::read_to_string(&path)
+                .unwrap_or_else(|err| panic!("couldn't read {}: {}", path.display(), err));
+            run(file, &json, verify)
+        })
+        .collect()
+}
+
+/// Run the given vector files from the subset in this crate's `tests/data`
+fn run_vendored(files: &[&str], verify: VerifyFn) -> Vec<String> {
+    run_files(
+        &Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data"),
+        files,
+        verify,
+    )
+}
+
+/// Run the given upstream vector files from `WYCHEPROOF_DIR`
+fn run_upstream(files: &[&str], verify: VerifyFn) -> Vec<String> {
+    let dir = env::var_os("WYCHEPROOF_DIR")
+        .expect("WYCHEPROOF_DIR must be set to run the upstream Wycheproof vectors");
+
+    run_files(Path::new(&dir), files, verify)
+}
+
+/// Panic with a list of the failed test cases, if there are any
+fn check(failures: Vec<String>) {
+    assert!(
+        failures.is_empty(),
+        "{} Wycheproof test case(s) failed:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn p256() {
+    check(run_vendored(P256_FILES, verify::<NistP256>));
+}
+
+#[test]
+fn k256() {
+    check(run_vendored(K256_FILES, verify::<Secp256k1>));
+}
+
+#[test]
+#[ignore]
+fn p256_upstream() {
+    check(run_upstream(P256_FILES, verify::<NistP256>));
+}
+
+#[test]
+#[ignore]
+fn k256_upstream() {
+    check(run_upstream(K256_FILES, verify::<Secp256k1>));
+}